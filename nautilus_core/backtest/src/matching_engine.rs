@@ -0,0 +1,1357 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::{
+        book::{BookOrder, OrderBookDelta},
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::{AggressorSide, BookType, LiquiditySide, OrderSide, OrderType, TimeInForce},
+    events::order::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEvent, OrderFilled, OrderRejected,
+        OrderTriggered,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::Instrument,
+    orderbook::{
+        book::OrderBook,
+        queue::{QueueEstimate, QueuePosition},
+    },
+    orders::Order,
+    types::{
+        money::Money,
+        price::{Price, PRICE_MAX, PRICE_MIN},
+        quantity::Quantity,
+    },
+};
+
+/// Provides an order matching engine for a single market.
+///
+/// Orders are matched against an internal [`OrderBook`] which is maintained from
+/// the market data passed to the engine. Generated order events are accumulated
+/// and can be drained with [`OrderMatchingEngine::drain_events`].
+pub struct OrderMatchingEngine {
+    /// The instrument for the matching engine.
+    pub instrument: Instrument,
+    /// The raw integer ID for the venue.
+    pub raw_id: u32,
+    /// The order book type for the matching engine.
+    pub book_type: BookType,
+    /// The account ID for generated events.
+    pub account_id: AccountId,
+    /// If stop orders are rejected on submission if the trigger price is in the market.
+    pub reject_stop_orders: bool,
+    /// If resting limit orders in an `L3_MBO` book are filled by trades at their
+    /// price only once the estimated queue ahead of them has been traded.
+    pub use_queue_position: bool,
+    book: OrderBook,
+    orders_bid: Vec<Order>,
+    orders_ask: Vec<Order>,
+    events: Vec<OrderEvent>,
+    queue_estimates: HashMap<ClientOrderId, QueueEstimate>,
+    last: Option<Price>,
+    ts_now: UnixNanos,
+    order_count: usize,
+    execution_count: usize,
+}
+
+impl OrderMatchingEngine {
+    /// Initializes a new `OrderMatchingEngine` instance.
+    #[must_use]
+    pub fn new(
+        instrument: Instrument,
+        raw_id: u32,
+        book_type: BookType,
+        account_id: AccountId,
+        reject_stop_orders: bool,
+        use_queue_position: bool,
+    ) -> Self {
+        let book = OrderBook::new(instrument.id.clone(), book_type);
+        Self {
+            instrument,
+            raw_id,
+            book_type,
+            account_id,
+            reject_stop_orders,
+            use_queue_position,
+            book,
+            orders_bid: Vec::new(),
+            orders_ask: Vec::new(),
+            events: Vec::new(),
+            queue_estimates: HashMap::new(),
+            last: None,
+            ts_now: 0,
+            order_count: 0,
+            execution_count: 0,
+        }
+    }
+
+    /// Reset the matching engine to its initial state.
+    pub fn reset(&mut self) {
+        self.book.reset();
+        self.orders_bid.clear();
+        self.orders_ask.clear();
+        self.events.clear();
+        self.queue_estimates.clear();
+        self.last = None;
+        self.ts_now = 0;
+        self.order_count = 0;
+        self.execution_count = 0;
+    }
+
+    #[must_use]
+    pub fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.book.best_bid_price()
+    }
+
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.book.best_ask_price()
+    }
+
+    #[must_use]
+    pub fn last_price(&self) -> Option<Price> {
+        self.last
+    }
+
+    #[must_use]
+    pub fn get_open_orders(&self) -> Vec<Order> {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .cloned()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_open_bid_orders(&self) -> &[Order] {
+        &self.orders_bid
+    }
+
+    #[must_use]
+    pub fn get_open_ask_orders(&self) -> &[Order] {
+        &self.orders_ask
+    }
+
+    #[must_use]
+    pub fn order_exists(&self, client_order_id: &ClientOrderId) -> bool {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .any(|o| &o.client_order_id == client_order_id)
+    }
+
+    /// Returns the estimated queue position of the resting limit order with the
+    /// given `client_order_id`, when using queue positions with an `L3_MBO` book.
+    #[must_use]
+    pub fn queue_position(&self, client_order_id: &ClientOrderId) -> Option<QueuePosition> {
+        let mut estimate = self.queue_estimates.get(client_order_id)?.clone();
+        Some(estimate.update(&self.book))
+    }
+
+    /// Drain the accumulated order events in the order they were generated.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    // -- DATA PROCESSING -------------------------------------------------------------------------
+
+    /// Process the given quote tick (only updates the book for `L1_TBBO` books).
+    pub fn process_quote_tick(&mut self, tick: &QuoteTick) {
+        if self.book_type == BookType::L1_TBBO {
+            self.book.update_quote_tick(tick);
+        }
+        self.iterate(tick.ts_init);
+    }
+
+    /// Process the given trade tick (only updates the book for `L1_TBBO` books).
+    ///
+    /// When using queue positions, the trade first fills the resting limit orders at
+    /// its price which the trade size reaches through the queue ahead of them. The
+    /// trade should be processed before the deltas it caused (as with market by order
+    /// feeds), otherwise the queue ahead has already been reduced by the trade.
+    pub fn process_trade_tick(&mut self, tick: &TradeTick) {
+        if self.book_type == BookType::L1_TBBO {
+            self.book.update_trade_tick(tick);
+        }
+        self.last = Some(tick.price);
+        if self.is_queue_modelled() {
+            self.ts_now = tick.ts_init;
+            self.fill_queued_orders(tick);
+        }
+        self.iterate(tick.ts_init);
+    }
+
+    /// Process the given order book delta.
+    pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        let ts_init = delta.ts_init;
+        self.book.apply_delta(delta);
+        self.iterate(ts_init);
+    }
+
+    /// Iterate the matching engine, matching all resting orders against the
+    /// current state of the book.
+    pub fn iterate(&mut self, ts_now: UnixNanos) {
+        self.ts_now = ts_now;
+
+        let mut orders: Vec<Order> = self.orders_bid.drain(..).collect();
+        orders.append(&mut self.orders_ask);
+
+        for order in orders {
+            self.match_order(order);
+        }
+    }
+
+    // -- COMMAND PROCESSING ----------------------------------------------------------------------
+
+    /// Process the given order, which should be in a `SUBMITTED` state.
+    pub fn process_order(&mut self, order: Order) {
+        if order.instrument_id != self.instrument.id {
+            let reason = format!(
+                "{} order instrument ID {} does not match matching engine {}",
+                order.order_type, order.instrument_id, self.instrument.id
+            );
+            self.generate_order_rejected(order, &reason);
+            return;
+        }
+
+        if order.quantity.precision != self.instrument.size_precision {
+            let reason = format!(
+                "invalid order quantity precision for order {}, was {} when {} size precision is {}",
+                order.client_order_id,
+                order.quantity.precision,
+                self.instrument.id,
+                self.instrument.size_precision
+            );
+            self.generate_order_rejected(order, &reason);
+            return;
+        }
+
+        match order.order_type {
+            OrderType::Market => self.process_market_order(order),
+            OrderType::Limit => self.process_limit_order(order),
+            OrderType::StopMarket => self.process_stop_market_order(order),
+            OrderType::StopLimit => self.process_stop_limit_order(order),
+            _ => {
+                let reason = format!("unsupported order type {}", order.order_type);
+                self.generate_order_rejected(order, &reason);
+            }
+        }
+    }
+
+    /// Process a cancel for the order with the given `client_order_id`.
+    pub fn process_cancel(&mut self, client_order_id: &ClientOrderId) {
+        match self.remove_order(client_order_id) {
+            Some(order) => self.generate_order_canceled(order),
+            None => self.generate_order_cancel_rejected(client_order_id),
+        }
+    }
+
+    /// Process a cancel for all open orders, optionally filtered by `side`.
+    pub fn process_cancel_all(&mut self, side: Option<OrderSide>) {
+        let mut orders = Vec::new();
+        if side != Some(OrderSide::Sell) {
+            orders.append(&mut self.orders_bid);
+        }
+        if side != Some(OrderSide::Buy) {
+            orders.append(&mut self.orders_ask);
+        }
+
+        for order in orders {
+            self.generate_order_canceled(order);
+        }
+    }
+
+    fn process_market_order(&mut self, order: Order) {
+        let has_market = match order.side {
+            OrderSide::Buy => self.book.has_ask(),
+            OrderSide::Sell => self.book.has_bid(),
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {}", order.side),
+        };
+        if !has_market {
+            let reason = format!("no market for {}", order.instrument_id);
+            self.generate_order_rejected(order, &reason);
+            return;
+        }
+
+        self.fill_market_order(order);
+    }
+
+    fn process_limit_order(&mut self, order: Order) {
+        let price = order.price.expect("LIMIT order must have a `price`");
+
+        if order.is_post_only && self.is_limit_matched(order.side, price) {
+            let reason = format!(
+                "POST_ONLY {} {} order limit px of {} would have been a TAKER: bid={:?}, ask={:?}",
+                order.order_type,
+                order.side,
+                price,
+                self.best_bid_price(),
+                self.best_ask_price(),
+            );
+            self.generate_order_rejected(order, &reason);
+            return;
+        }
+
+        let order = self.generate_order_accepted(order);
+
+        if self.is_limit_matched(order.side, price) {
+            // Filling as liquidity taker
+            self.fill_limit_order(order, LiquiditySide::Taker);
+        } else if order.time_in_force == TimeInForce::Fok || order.time_in_force == TimeInForce::Ioc
+        {
+            self.generate_order_canceled(order);
+        } else {
+            self.add_order(order);
+        }
+    }
+
+    fn process_stop_market_order(&mut self, order: Order) {
+        let trigger_price = order
+            .trigger_price
+            .expect("STOP_MARKET order must have a `trigger_price`");
+
+        if self.is_stop_triggered(order.side, trigger_price) {
+            if self.reject_stop_orders {
+                let reason = format!(
+                    "{} {} order stop px of {} was in the market: bid={:?}, ask={:?}",
+                    order.order_type,
+                    order.side,
+                    trigger_price,
+                    self.best_bid_price(),
+                    self.best_ask_price(),
+                );
+                self.generate_order_rejected(order, &reason);
+                return;
+            }
+            self.fill_market_order(order);
+            return;
+        }
+
+        let order = self.generate_order_accepted(order);
+        self.add_order(order);
+    }
+
+    fn process_stop_limit_order(&mut self, order: Order) {
+        let price = order.price.expect("STOP_LIMIT order must have a `price`");
+        let trigger_price = order
+            .trigger_price
+            .expect("STOP_LIMIT order must have a `trigger_price`");
+
+        if self.is_stop_triggered(order.side, trigger_price) {
+            if self.reject_stop_orders {
+                let reason = format!(
+                    "{} {} order trigger stop px of {} was in the market: bid={:?}, ask={:?}",
+                    order.order_type,
+                    order.side,
+                    trigger_price,
+                    self.best_bid_price(),
+                    self.best_ask_price(),
+                );
+                self.generate_order_rejected(order, &reason);
+                return;
+            }
+            let order = self.generate_order_accepted(order);
+            let order = self.generate_order_triggered(order);
+
+            // Check if immediately marketable
+            if self.is_limit_matched(order.side, price) {
+                self.fill_limit_order(order, LiquiditySide::Taker);
+            } else {
+                self.add_order(order);
+            }
+            return;
+        }
+
+        let order = self.generate_order_accepted(order);
+        self.add_order(order);
+    }
+
+    // -- MATCHING --------------------------------------------------------------------------------
+
+    /// Returns whether a limit order at `price` on the given `side` would be matched.
+    #[must_use]
+    pub fn is_limit_matched(&self, side: OrderSide, price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self.best_ask_price().map_or(false, |ask| ask <= price),
+            OrderSide::Sell => self.best_bid_price().map_or(false, |bid| bid >= price),
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {side}"),
+        }
+    }
+
+    /// Returns whether a stop order at `trigger_price` on the given `side` would be triggered.
+    #[must_use]
+    pub fn is_stop_triggered(&self, side: OrderSide, trigger_price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self
+                .best_ask_price()
+                .map_or(false, |ask| ask >= trigger_price),
+            OrderSide::Sell => self
+                .best_bid_price()
+                .map_or(false, |bid| bid <= trigger_price),
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {side}"),
+        }
+    }
+
+    fn match_order(&mut self, order: Order) {
+        match order.order_type {
+            OrderType::Limit => self.match_limit_order(order),
+            OrderType::StopMarket => self.match_stop_market_order(order),
+            OrderType::StopLimit => self.match_stop_limit_order(order),
+            _ => panic!("invalid order type for matching, was {}", order.order_type),
+        }
+    }
+
+    fn match_limit_order(&mut self, order: Order) {
+        let price = order.price.expect("LIMIT order must have a `price`");
+        if self.is_limit_matched(order.side, price) {
+            self.fill_limit_order(order, LiquiditySide::Maker);
+        } else {
+            self.add_order(order);
+        }
+    }
+
+    fn match_stop_market_order(&mut self, order: Order) {
+        let trigger_price = order
+            .trigger_price
+            .expect("STOP_MARKET order must have a `trigger_price`");
+        if self.is_stop_triggered(order.side, trigger_price) {
+            // Triggered stop places market order
+            self.fill_market_order(order);
+        } else {
+            self.add_order(order);
+        }
+    }
+
+    fn match_stop_limit_order(&mut self, order: Order) {
+        let price = order.price.expect("STOP_LIMIT order must have a `price`");
+
+        if order.ts_triggered.is_some() {
+            if self.is_limit_matched(order.side, price) {
+                self.fill_limit_order(order, LiquiditySide::Maker);
+            } else {
+                self.add_order(order);
+            }
+            return;
+        }
+
+        let trigger_price = order
+            .trigger_price
+            .expect("STOP_LIMIT order must have a `trigger_price`");
+        if self.is_stop_triggered(order.side, trigger_price) {
+            let order = self.generate_order_triggered(order);
+            if self.is_limit_matched(order.side, price) {
+                self.fill_limit_order(order, LiquiditySide::Taker);
+            } else {
+                self.add_order(order);
+            }
+        } else {
+            self.add_order(order);
+        }
+    }
+
+    // -- FILLS -----------------------------------------------------------------------------------
+
+    /// Returns the projected fills for the given *marketable* order filling
+    /// aggressively into the opposite side of the book.
+    #[must_use]
+    pub fn determine_market_price_and_volume(&self, order: &Order) -> Vec<(Price, Quantity)> {
+        let price = match order.side {
+            OrderSide::Buy => Price::new(PRICE_MAX, self.instrument.price_precision),
+            OrderSide::Sell => Price::new(PRICE_MIN, self.instrument.price_precision),
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {}", order.side),
+        };
+        let book_order = BookOrder::new(order.side, price, order.leaves_qty, 0);
+        self.book.simulate_fills(&book_order)
+    }
+
+    /// Returns the projected fills for the given *limit* order filling
+    /// up to its limit price.
+    #[must_use]
+    pub fn determine_limit_price_and_volume(&self, order: &Order) -> Vec<(Price, Quantity)> {
+        let price = order.price.expect("LIMIT order must have a `price`");
+        let book_order = BookOrder::new(order.side, price, order.leaves_qty, 0);
+        self.book.simulate_fills(&book_order)
+    }
+
+    fn is_queue_modelled(&self) -> bool {
+        self.use_queue_position && self.book_type == BookType::L3_MBO
+    }
+
+    fn fill_queued_orders(&mut self, tick: &TradeTick) {
+        let hit_side = match tick.aggressor_side {
+            AggressorSide::Buyer => Some(OrderSide::Sell),
+            AggressorSide::Seller => Some(OrderSide::Buy),
+            AggressorSide::NoAggressor => None,
+        };
+
+        let mut orders: Vec<Order> = self.orders_bid.drain(..).collect();
+        orders.append(&mut self.orders_ask);
+
+        // Earlier resting orders at the trade price take the trade size first
+        let mut trade_raw = tick.size.raw;
+        for order in orders {
+            if trade_raw == 0 || hit_side.map_or(false, |side| side != order.side) {
+                self.add_order(order);
+                continue;
+            }
+            let size_ahead_raw = match self.queue_estimates.get_mut(&order.client_order_id) {
+                Some(estimate) if estimate.price == tick.price => {
+                    estimate.update(&self.book).size_ahead.raw
+                }
+                _ => {
+                    self.add_order(order);
+                    continue;
+                }
+            };
+
+            let fill_raw = trade_raw
+                .saturating_sub(size_ahead_raw)
+                .min(order.leaves_qty.raw);
+            if fill_raw == 0 {
+                self.add_order(order);
+                continue;
+            }
+            trade_raw -= fill_raw;
+
+            let fill_qty = Quantity::from_raw(fill_raw, self.instrument.size_precision);
+            let order = self.fill_order(order, tick.price, fill_qty, LiquiditySide::Maker);
+            if order.is_open() {
+                self.add_order(order);
+            }
+        }
+    }
+
+    fn fill_market_order(&mut self, order: Order) {
+        let fills = self.determine_market_price_and_volume(&order);
+        self.apply_fills(order, fills, LiquiditySide::Taker);
+    }
+
+    fn fill_limit_order(&mut self, order: Order, liquidity_side: LiquiditySide) {
+        let mut fills = self.determine_limit_price_and_volume(&order);
+        if liquidity_side == LiquiditySide::Maker {
+            // A resting order is filled passively at its limit price
+            let price = order.price.expect("LIMIT order must have a `price`");
+            for fill in &mut fills {
+                fill.0 = price;
+            }
+        }
+        self.apply_fills(order, fills, liquidity_side);
+    }
+
+    fn apply_fills(
+        &mut self,
+        mut order: Order,
+        fills: Vec<(Price, Quantity)>,
+        liquidity_side: LiquiditySide,
+    ) {
+        if fills.is_empty() {
+            if order.is_open() && order.is_passive() {
+                self.add_order(order);
+            }
+            return; // No fills
+        }
+
+        let last_fill_px = fills.last().map(|fill| fill.0);
+
+        for (fill_px, fill_qty) in fills {
+            if order.filled_qty.raw == 0 {
+                if order.time_in_force == TimeInForce::Fok && fill_qty.raw < order.quantity.raw {
+                    // FOK order cannot fill the entire quantity - cancel
+                    self.generate_order_canceled(order);
+                    return;
+                }
+            } else if order.time_in_force == TimeInForce::Ioc {
+                // IOC order has already filled at one price - cancel remaining
+                self.generate_order_canceled(order);
+                return;
+            }
+
+            if fill_qty.raw == 0 {
+                break; // Done
+            }
+
+            order = self.fill_order(order, fill_px, fill_qty, liquidity_side);
+        }
+
+        if order.is_closed() {
+            return;
+        }
+
+        if order.order_type == OrderType::Market || order.order_type == OrderType::StopMarket {
+            if self.book_type == BookType::L1_TBBO && order.time_in_force != TimeInForce::Ioc {
+                // Exhausted simulated book volume (continue aggressive filling into next level)
+                let mut fill_px = last_fill_px.expect("fills were not empty");
+                match order.side {
+                    OrderSide::Buy => fill_px += self.instrument.price_increment,
+                    OrderSide::Sell => fill_px -= self.instrument.price_increment,
+                    OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {}", order.side),
+                }
+                let leaves_qty = order.leaves_qty;
+                self.fill_order(order, fill_px, leaves_qty, liquidity_side);
+            } else {
+                // No more liquidity available for the remaining quantity
+                self.generate_order_canceled(order);
+            }
+            return;
+        }
+
+        if order.time_in_force == TimeInForce::Ioc || order.time_in_force == TimeInForce::Fok {
+            self.generate_order_canceled(order);
+            return;
+        }
+
+        self.add_order(order);
+    }
+
+    fn fill_order(
+        &mut self,
+        order: Order,
+        last_px: Price,
+        last_qty: Quantity,
+        liquidity_side: LiquiditySide,
+    ) -> Order {
+        let commission = self
+            .instrument
+            .calculate_commission(&last_qty, &last_px, liquidity_side);
+        self.generate_order_filled(order, last_px, last_qty, commission, liquidity_side)
+    }
+
+    // -- ORDER MANAGEMENT ------------------------------------------------------------------------
+
+    fn add_order(&mut self, order: Order) {
+        if self.is_queue_modelled() {
+            let is_resting_limit = match order.order_type {
+                OrderType::Limit => true,
+                OrderType::StopLimit => order.ts_triggered.is_some(),
+                _ => false,
+            };
+            if let (true, Some(price)) = (is_resting_limit, order.price) {
+                // The order joins the back of the queue when it first rests
+                self.queue_estimates
+                    .entry(order.client_order_id.clone())
+                    .or_insert_with(|| QueueEstimate::new(&self.book, order.side, price));
+            }
+        }
+
+        match order.side {
+            OrderSide::Buy => self.orders_bid.push(order),
+            OrderSide::Sell => self.orders_ask.push(order),
+            OrderSide::NoOrderSide => panic!("invalid `OrderSide`, was {}", order.side),
+        }
+    }
+
+    fn remove_order(&mut self, client_order_id: &ClientOrderId) -> Option<Order> {
+        if let Some(idx) = self
+            .orders_bid
+            .iter()
+            .position(|o| &o.client_order_id == client_order_id)
+        {
+            return Some(self.orders_bid.remove(idx));
+        }
+        if let Some(idx) = self
+            .orders_ask
+            .iter()
+            .position(|o| &o.client_order_id == client_order_id)
+        {
+            return Some(self.orders_ask.remove(idx));
+        }
+        None
+    }
+
+    // -- IDENTIFIER GENERATORS -------------------------------------------------------------------
+
+    fn generate_venue_order_id(&mut self) -> VenueOrderId {
+        self.order_count += 1;
+        VenueOrderId::new(&format!(
+            "{}-{}-{:03}",
+            self.instrument.id.venue, self.raw_id, self.order_count
+        ))
+    }
+
+    fn generate_trade_id(&mut self) -> TradeId {
+        self.execution_count += 1;
+        TradeId::new(&format!(
+            "{}-{}-{:03}",
+            self.instrument.id.venue, self.raw_id, self.execution_count
+        ))
+    }
+
+    // -- EVENT GENERATORS ------------------------------------------------------------------------
+
+    fn apply_event(&mut self, mut order: Order, event: OrderEvent) -> Order {
+        if let Err(e) = order.apply(event.clone()) {
+            panic!(
+                "error applying event to order {}: {e}",
+                order.client_order_id
+            );
+        }
+        if order.is_closed() {
+            self.queue_estimates.remove(&order.client_order_id);
+        }
+        self.events.push(event);
+        order
+    }
+
+    fn generate_order_rejected(&mut self, order: Order, reason: &str) {
+        let event = OrderEvent::OrderRejected(OrderRejected {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone().unwrap_or_default(),
+            account_id: self.account_id.clone(),
+            reason: reason.to_string(),
+            event_id: UUID4::new(),
+            ts_event: self.ts_now,
+            ts_init: self.ts_now,
+            reconciliation: false,
+        });
+        self.apply_event(order, event);
+    }
+
+    fn generate_order_accepted(&mut self, order: Order) -> Order {
+        let venue_order_id = match &order.venue_order_id {
+            Some(venue_order_id) => venue_order_id.clone(),
+            None => self.generate_venue_order_id(),
+        };
+        let event = OrderEvent::OrderAccepted(OrderAccepted {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id,
+            account_id: self.account_id.clone(),
+            event_id: UUID4::new(),
+            ts_event: self.ts_now,
+            ts_init: self.ts_now,
+            reconciliation: false,
+        });
+        self.apply_event(order, event)
+    }
+
+    fn generate_order_canceled(&mut self, order: Order) {
+        let event = OrderEvent::OrderCanceled(OrderCanceled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone(),
+            account_id: Some(self.account_id.clone()),
+            event_id: UUID4::new(),
+            ts_event: self.ts_now,
+            ts_init: self.ts_now,
+            reconciliation: false,
+        });
+        self.apply_event(order, event);
+    }
+
+    fn generate_order_cancel_rejected(&mut self, client_order_id: &ClientOrderId) {
+        // No order state to apply the event to, so only the event is generated
+        self.events
+            .push(OrderEvent::OrderCancelRejected(OrderCancelRejected {
+                trader_id: Default::default(),
+                strategy_id: Default::default(),
+                instrument_id: self.instrument.id.clone(),
+                client_order_id: client_order_id.clone(),
+                venue_order_id: None,
+                account_id: Some(self.account_id.clone()),
+                reason: Box::new(format!("{client_order_id} not found")),
+                event_id: UUID4::new(),
+                ts_event: self.ts_now,
+                ts_init: self.ts_now,
+                reconciliation: false,
+            }));
+    }
+
+    fn generate_order_triggered(&mut self, order: Order) -> Order {
+        let event = OrderEvent::OrderTriggered(OrderTriggered {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: order.venue_order_id.clone(),
+            account_id: Some(self.account_id.clone()),
+            event_id: UUID4::new(),
+            ts_event: self.ts_now,
+            ts_init: self.ts_now,
+            reconciliation: false,
+        });
+        self.apply_event(order, event)
+    }
+
+    fn generate_order_filled(
+        &mut self,
+        order: Order,
+        last_px: Price,
+        last_qty: Quantity,
+        commission: Money,
+        liquidity_side: LiquiditySide,
+    ) -> Order {
+        let venue_order_id = match &order.venue_order_id {
+            Some(venue_order_id) => venue_order_id.clone(),
+            None => self.generate_venue_order_id(),
+        };
+        let fill = OrderFilled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id,
+            account_id: self.account_id.clone(),
+            trade_id: self.generate_trade_id(),
+            position_id: order.position_id.clone(),
+            order_side: order.side,
+            order_type: order.order_type,
+            last_qty,
+            last_px,
+            currency: self.instrument.quote_currency.clone(),
+            commission,
+            liquidity_side,
+            event_id: UUID4::new(),
+            ts_event: self.ts_now,
+            ts_init: self.ts_now,
+            reconciliation: false,
+        };
+        let event = if last_qty.raw < order.leaves_qty.raw {
+            OrderEvent::OrderPartiallyFilled(fill)
+        } else {
+            OrderEvent::OrderFilled(fill)
+        };
+        self.apply_event(order, event)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        enums::{AssetClass, AssetType, BookAction, OrderStatus},
+        events::order::{OrderInitializedBuilder, OrderSubmittedBuilder},
+        identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+        types::currency::Currency,
+    };
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn audusd_sim() -> Instrument {
+        Instrument {
+            id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            native_symbol: Symbol::new("AUD/USD"),
+            asset_class: AssetClass::FX,
+            asset_type: AssetType::Spot,
+            quote_currency: Currency::from("USD"),
+            base_currency: Some(Currency::from("AUD")),
+            cost_currency: Currency::from("USD"),
+            is_inverse: false,
+            price_precision: 5,
+            size_precision: 0,
+            price_increment: Price::from("0.00001"),
+            size_increment: Quantity::from("1"),
+            multiplier: Quantity::from("1"),
+            lot_size: None,
+            max_quantity: None,
+            min_quantity: None,
+            max_price: None,
+            min_price: None,
+            margin_init: Decimal::ZERO,
+            margin_maint: Decimal::ZERO,
+            maker_fee: Decimal::new(2, 5),
+            taker_fee: Decimal::new(2, 5),
+        }
+    }
+
+    fn create_engine(book_type: BookType) -> OrderMatchingEngine {
+        OrderMatchingEngine::new(
+            audusd_sim(),
+            1,
+            book_type,
+            AccountId::default(),
+            true,
+            false,
+        )
+    }
+
+    fn submitted_order(
+        client_order_id: &str,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: &str,
+        price: Option<&str>,
+        trigger_price: Option<&str>,
+    ) -> Order {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(InstrumentId::from_str("AUD/USD.SIM").unwrap())
+            .client_order_id(ClientOrderId::new(client_order_id))
+            .order_side(side)
+            .order_type(order_type)
+            .quantity(Quantity::from(quantity))
+            .price(price.map(Price::from))
+            .trigger_price(trigger_price.map(Price::from))
+            .time_in_force(TimeInForce::Gtc)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(ClientOrderId::new(client_order_id))
+            .build()
+            .unwrap();
+        let mut order: Order = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order
+    }
+
+    fn quote(bid: &str, ask: &str, ts: UnixNanos) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("100000"),
+            Quantity::from("100000"),
+            ts,
+            ts,
+        )
+    }
+
+    #[test]
+    fn test_market_order_with_no_market_is_rejected() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        let order = submitted_order("O-1", OrderSide::Buy, OrderType::Market, "1000", None, None);
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[test]
+    fn test_market_order_fills_as_taker() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order("O-1", OrderSide::Buy, OrderType::Market, "1000", None, None);
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("0.90010"));
+                assert_eq!(fill.last_qty, Quantity::from("1000"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+                assert_eq!(fill.trade_id, TradeId::new("SIM-1-001"));
+            }
+            _ => panic!("expected `OrderFilled`"),
+        }
+    }
+
+    #[test]
+    fn test_market_order_exhausting_l1_volume_fills_at_next_tick() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::Market,
+            "150000",
+            None,
+            None,
+        );
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 2);
+        match (&events[0], &events[1]) {
+            (OrderEvent::OrderPartiallyFilled(fill1), OrderEvent::OrderFilled(fill2)) => {
+                assert_eq!(fill1.last_px, Price::from("0.90000"));
+                assert_eq!(fill1.last_qty, Quantity::from("100000"));
+                assert_eq!(fill2.last_px, Price::from("0.89999"));
+                assert_eq!(fill2.last_qty, Quantity::from("50000"));
+            }
+            _ => panic!("expected partial fill then fill"),
+        }
+    }
+
+    #[test]
+    fn test_post_only_limit_order_which_would_take_is_rejected() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let mut order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.90010"),
+            None,
+        );
+        order.is_post_only = true;
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[test]
+    fn test_marketable_limit_order_fills_as_taker() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.90020"),
+            None,
+        );
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("0.90010"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+            }
+            _ => panic!("expected `OrderFilled`"),
+        }
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_resting_limit_order_fills_as_maker_when_market_moves() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1000",
+            Some("0.89990"),
+            None,
+        );
+
+        engine.process_order(order);
+        assert_eq!(engine.get_open_bid_orders().len(), 1);
+        assert_eq!(
+            engine.get_open_bid_orders()[0].status,
+            OrderStatus::Accepted
+        );
+
+        engine.process_quote_tick(&quote("0.89970", "0.89980", 2));
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("0.89990"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+                assert_eq!(fill.ts_event, 2);
+            }
+            _ => panic!("expected `OrderFilled`"),
+        }
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[test]
+    fn test_stop_market_order_in_the_market_is_rejected() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::StopMarket,
+            "1000",
+            None,
+            Some("0.90005"),
+        );
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[test]
+    fn test_stop_market_order_triggers_and_fills() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::StopMarket,
+            "1000",
+            None,
+            Some("0.89950"),
+        );
+
+        engine.process_order(order);
+        engine.process_quote_tick(&quote("0.89940", "0.89950", 2));
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("0.89940"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+            }
+            _ => panic!("expected `OrderFilled`"),
+        }
+    }
+
+    #[test]
+    fn test_stop_limit_order_triggers_then_rests() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::StopLimit,
+            "1000",
+            Some("0.90020"),
+            Some("0.90030"),
+        );
+
+        engine.process_order(order);
+        engine.process_quote_tick(&quote("0.90025", "0.90035", 2));
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        assert!(matches!(events[1], OrderEvent::OrderTriggered(_)));
+        assert!(engine.get_open_bid_orders()[0].ts_triggered.is_some());
+
+        engine.process_quote_tick(&quote("0.90010", "0.90015", 3));
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("0.90020"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            _ => panic!("expected `OrderFilled`"),
+        }
+    }
+
+    #[test]
+    fn test_cancel_resting_order() {
+        let mut engine = create_engine(BookType::L1_TBBO);
+        engine.process_quote_tick(&quote("0.90000", "0.90010", 1));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Sell,
+            OrderType::Limit,
+            "1000",
+            Some("0.90100"),
+            None,
+        );
+
+        engine.process_order(order);
+        engine.process_cancel(&ClientOrderId::new("O-1"));
+        engine.process_cancel(&ClientOrderId::new("O-2"));
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], OrderEvent::OrderCanceled(_)));
+        assert!(matches!(events[2], OrderEvent::OrderCancelRejected(_)));
+        assert!(!engine.order_exists(&ClientOrderId::new("O-1")));
+    }
+
+    #[test]
+    fn test_market_order_fills_through_l2_levels() {
+        let mut engine = create_engine(BookType::L2_MBP);
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        for (i, (price, size)) in [("0.90010", "1000"), ("0.90020", "2000")]
+            .into_iter()
+            .enumerate()
+        {
+            engine.process_order_book_delta(OrderBookDelta::new(
+                instrument_id.clone(),
+                BookAction::Add,
+                BookOrder::new(
+                    OrderSide::Sell,
+                    Price::from(price),
+                    Quantity::from(size),
+                    i as u64,
+                ),
+                0,
+                i as u64,
+                1,
+                1,
+            ));
+        }
+        let order = submitted_order("O-1", OrderSide::Buy, OrderType::Market, "2500", None, None);
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 2);
+        match (&events[0], &events[1]) {
+            (OrderEvent::OrderPartiallyFilled(fill1), OrderEvent::OrderFilled(fill2)) => {
+                assert_eq!(fill1.last_px, Price::from("0.90010"));
+                assert_eq!(fill1.last_qty, Quantity::from("1000"));
+                assert_eq!(fill2.last_px, Price::from("0.90020"));
+                assert_eq!(fill2.last_qty, Quantity::from("1500"));
+            }
+            _ => panic!("expected partial fill then fill"),
+        }
+    }
+
+    #[test]
+    fn test_resting_limit_order_fills_after_queue_ahead_trades() {
+        let mut engine = OrderMatchingEngine::new(
+            audusd_sim(),
+            1,
+            BookType::L3_MBO,
+            AccountId::default(),
+            true,
+            true,
+        );
+        let instrument_id = InstrumentId::from_str("AUD/USD.SIM").unwrap();
+        let delta = |action, side, price, size, order_id| {
+            OrderBookDelta::new(
+                instrument_id.clone(),
+                action,
+                BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+                0,
+                order_id,
+                1,
+                1,
+            )
+        };
+        let trade = |size, ts| {
+            TradeTick::new(
+                instrument_id.clone(),
+                Price::from("0.90000"),
+                Quantity::from(size),
+                AggressorSide::Seller,
+                TradeId::new("1"),
+                ts,
+                ts,
+            )
+        };
+        engine.process_order_book_delta(delta(
+            BookAction::Add,
+            OrderSide::Buy,
+            "0.90000",
+            "1000",
+            1,
+        ));
+        engine.process_order_book_delta(delta(
+            BookAction::Add,
+            OrderSide::Buy,
+            "0.90000",
+            "2000",
+            2,
+        ));
+        engine.process_order_book_delta(delta(
+            BookAction::Add,
+            OrderSide::Sell,
+            "0.90010",
+            "1000",
+            3,
+        ));
+        let order = submitted_order(
+            "O-1",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1500",
+            Some("0.90000"),
+            None,
+        );
+        let client_order_id = order.client_order_id.clone();
+        engine.process_order(order);
+
+        assert_eq!(
+            engine.queue_position(&client_order_id),
+            Some(QueuePosition::new(2, Quantity::from("3000")))
+        );
+
+        // Trade does not reach through the queue ahead
+        engine.process_trade_tick(&trade("2000", 2));
+        engine.process_order_book_delta(delta(
+            BookAction::Delete,
+            OrderSide::Buy,
+            "0.90000",
+            "1000",
+            1,
+        ));
+        engine.process_order_book_delta(delta(
+            BookAction::Update,
+            OrderSide::Buy,
+            "0.90000",
+            "1000",
+            2,
+        ));
+
+        assert_eq!(engine.drain_events().len(), 1); // Accepted only
+        assert_eq!(
+            engine.queue_position(&client_order_id),
+            Some(QueuePosition::new(1, Quantity::from("1000")))
+        );
+
+        // Trade fills the remaining queue ahead then part of the order
+        engine.process_trade_tick(&trade("1800", 3));
+
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEvent::OrderPartiallyFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("0.90000"));
+                assert_eq!(fill.last_qty, Quantity::from("800"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+                assert_eq!(fill.ts_event, 3);
+            }
+            _ => panic!("expected `OrderPartiallyFilled`"),
+        }
+        assert_eq!(engine.get_open_bid_orders().len(), 1);
+    }
+
+    #[test]
+    fn test_calculate_commission_with_no_liquidity_side() {
+        let instrument = audusd_sim();
+
+        let commission = instrument.calculate_commission(
+            &Quantity::from("100000"),
+            &Price::from("0.90000"),
+            LiquiditySide::NoLiquiditySide,
+        );
+
+        assert_eq!(commission, Money::new(0.0, Currency::from("USD")));
+    }
+}
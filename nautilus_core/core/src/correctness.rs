@@ -32,6 +32,12 @@ pub fn valid_string(s: &str, desc: &str) {
     }
 }
 
+/// Returns whether string `s` is valid, as checked by [`valid_string`].
+#[must_use]
+pub fn is_valid_string(s: &str) -> bool {
+    !s.is_empty() && !s.as_bytes().iter().all(u8::is_ascii_whitespace) && s.is_ascii()
+}
+
 /// Check string `s` contains pattern `pat`.
 ///
 /// # Panics
@@ -134,6 +140,16 @@ mod tests {
         valid_string(s, "value");
     }
 
+    #[rstest]
+    #[case(" a", true)]
+    #[case("abc", true)]
+    #[case("", false)]
+    #[case("  ", false)]
+    #[case("🦀", false)]
+    fn test_is_valid_string(#[case] s: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_string(s), expected);
+    }
+
     #[rstest]
     #[case("a", "a")]
     fn test_string_contains_when_it_does_contain(#[case] s: &str, #[case] pat: &str) {
@@ -17,6 +17,7 @@ pub mod correctness;
 pub mod cvec;
 pub mod datetime;
 pub mod parsing;
+pub mod stats;
 pub mod string;
 pub mod time;
 pub mod uuid;
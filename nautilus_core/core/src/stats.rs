@@ -0,0 +1,73 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+/// Return the mean of the given `values`, or zero if empty.
+#[must_use]
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Return the (population) standard deviation of the given `values` and
+/// pre-calculated `mean`, or zero if empty.
+#[must_use]
+pub fn std_with_mean(values: &[f64], mean: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let sum_sq: f64 = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+    (sum_sq / values.len() as f64).sqrt()
+}
+
+/// Return the mean absolute deviation of the given `values` and
+/// pre-calculated `mean`, or zero if empty.
+#[must_use]
+pub fn mad_with_mean(values: &[f64], mean: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let sum_abs: f64 = values.iter().map(|v| (v - mean).abs()).sum();
+    sum_abs / values.len() as f64
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_with_empty_values() {
+        assert_eq!(mean(&[]), 0.0);
+        assert_eq!(std_with_mean(&[], 1.0), 0.0);
+        assert_eq!(mad_with_mean(&[], 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_stats_with_values() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mean = mean(&values);
+
+        assert_eq!(mean, 5.0);
+        assert_eq!(std_with_mean(&values, mean), 2.0);
+        assert_eq!(mad_with_mean(&values, mean), 1.5);
+    }
+}
@@ -0,0 +1,277 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{check_period, MovingAverage},
+    ratio::efficiency_ratio::EfficiencyRatio,
+    Indicator,
+};
+
+/// An indicator which calculates an adaptive moving average (AMA) across a
+/// rolling window. Developed by Perry Kaufman, the AMA is a moving average
+/// designed to account for market noise and volatility. The AMA will closely
+/// follow prices when the price swings are relatively small and the noise is
+/// low. The AMA will increase lag when the price swings increase.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct AdaptiveMovingAverage {
+    #[pyo3(get)]
+    pub period_er: usize,
+    #[pyo3(get)]
+    pub period_alpha_fast: usize,
+    #[pyo3(get)]
+    pub period_alpha_slow: usize,
+    #[pyo3(get)]
+    pub price_type: PriceType,
+    #[pyo3(get)]
+    pub alpha_fast: f64,
+    #[pyo3(get)]
+    pub alpha_slow: f64,
+    #[pyo3(get)]
+    pub alpha_diff: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub count: usize,
+    efficiency_ratio: EfficiencyRatio,
+    prior_value: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for AdaptiveMovingAverage {
+    fn name(&self) -> String {
+        stringify!(AdaptiveMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.efficiency_ratio.reset();
+        self.prior_value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MovingAverage for AdaptiveMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+#[pymethods]
+impl AdaptiveMovingAverage {
+    /// # Panics
+    ///
+    /// - If `period_er` is less than 2.
+    /// - If `period_alpha_fast` or `period_alpha_slow` is not positive (> 0).
+    /// - If `period_alpha_slow` is not greater than `period_alpha_fast`.
+    #[must_use]
+    #[new]
+    pub fn new(
+        period_er: usize,
+        period_alpha_fast: usize,
+        period_alpha_slow: usize,
+        price_type: Option<PriceType>,
+    ) -> Self {
+        check_period(period_alpha_fast);
+        check_period(period_alpha_slow);
+        if period_alpha_slow <= period_alpha_fast {
+            panic!(
+                "invalid `period_alpha_slow` for `period_alpha_fast` {period_alpha_fast}, was {period_alpha_slow}"
+            );
+        }
+
+        let alpha_fast = 2.0 / (period_alpha_fast as f64 + 1.0);
+        let alpha_slow = 2.0 / (period_alpha_slow as f64 + 1.0);
+
+        Self {
+            period_er,
+            period_alpha_fast,
+            period_alpha_slow,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            alpha_fast,
+            alpha_slow,
+            alpha_diff: alpha_fast - alpha_slow,
+            value: 0.0,
+            count: 0,
+            efficiency_ratio: EfficiencyRatio::new(period_er),
+            prior_value: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        // Check if this is the initial input (then initialize variables)
+        if !self._has_inputs {
+            self.value = value;
+        }
+
+        self.efficiency_ratio.update_raw(value);
+        self.prior_value = self.value;
+
+        // Calculate smoothing constant (sc)
+        let sc = self
+            .efficiency_ratio
+            .value
+            .mul_add(self.alpha_diff, self.alpha_slow)
+            .powi(2);
+
+        self.value = sc.mul_add(value - self.prior_value, self.prior_value);
+        self.count += 1;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.count >= self.period_er {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ama_initialized() {
+        let ama = AdaptiveMovingAverage::new(10, 2, 30, None);
+
+        assert_eq!(ama.name(), "AdaptiveMovingAverage");
+        assert!((ama.alpha_fast - 2.0 / 3.0).abs() < 1e-12);
+        assert!((ama.alpha_slow - 2.0 / 31.0).abs() < 1e-12);
+        assert!(!ama.has_inputs());
+        assert!(!ama.is_initialized());
+    }
+
+    #[test]
+    fn test_ama_first_input_sets_value() {
+        let mut ama = AdaptiveMovingAverage::new(10, 2, 30, None);
+        ama.update_raw(1.0);
+
+        assert!(ama.has_inputs());
+        assert_eq!(ama.value, 1.0);
+    }
+
+    #[test]
+    fn test_ama_trending_inputs_follow_fast_alpha() {
+        let mut ama = AdaptiveMovingAverage::new(2, 2, 30, None);
+        ama.update_raw(1.0);
+        ama.update_raw(2.0);
+
+        // Efficiency ratio of 1.0 gives sc = alpha_fast^2
+        assert!(ama.is_initialized());
+        assert!((ama.value - (1.0 + 4.0 / 9.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ama_with_slow_period_not_greater_than_fast() {
+        let _ = AdaptiveMovingAverage::new(10, 30, 30, None);
+    }
+
+    #[test]
+    fn test_ama_reset() {
+        let mut ama = AdaptiveMovingAverage::new(10, 2, 30, None);
+        ama.update_raw(1.0);
+
+        ama.reset();
+
+        assert_eq!(ama.count, 0);
+        assert_eq!(ama.value, 0.0);
+        assert!(!ama.has_inputs());
+    }
+}
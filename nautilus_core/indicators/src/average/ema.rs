@@ -22,16 +22,21 @@ use nautilus_model::{
 };
 use pyo3::prelude::*;
 
-use crate::Indicator;
+use crate::{average::MovingAverage, Indicator};
 
 #[repr(C)]
 #[derive(Debug)]
 #[pyclass]
 pub struct ExponentialMovingAverage {
+    #[pyo3(get)]
     pub period: usize,
+    #[pyo3(get)]
     pub price_type: PriceType,
+    #[pyo3(get)]
     pub alpha: f64,
+    #[pyo3(get)]
     pub value: f64,
+    #[pyo3(get)]
     pub count: usize,
     _has_inputs: bool,
     _is_initialized: bool,
@@ -70,6 +75,20 @@ impl Indicator for ExponentialMovingAverage {
     }
 }
 
+impl MovingAverage for ExponentialMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 #[pymethods]
 impl ExponentialMovingAverage {
     #[must_use]
@@ -0,0 +1,235 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{check_period, wma::WeightedMovingAverage, MovingAverage},
+    Indicator,
+};
+
+/// An indicator which calculates a Hull Moving Average (HMA) across a rolling
+/// window. The HMA, developed by Alan Hull, is an extremely fast and smooth
+/// moving average.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct HullMovingAverage {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub price_type: PriceType,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub count: usize,
+    ma1: WeightedMovingAverage,
+    ma2: WeightedMovingAverage,
+    ma3: WeightedMovingAverage,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for HullMovingAverage {
+    fn name(&self) -> String {
+        stringify!(HullMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.ma1.reset();
+        self.ma2.reset();
+        self.ma3.reset();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MovingAverage for HullMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+/// Returns linearly increasing weights `1..=size` normalized to sum to one.
+fn get_weights(size: usize) -> Vec<f64> {
+    let weights: Vec<f64> = (1..=size).map(|w| w as f64).collect();
+    let sum: f64 = weights.iter().sum();
+    weights.iter().map(|w| w / sum).collect()
+}
+
+#[pymethods]
+impl HullMovingAverage {
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        check_period(period);
+
+        // Sub-periods are floored, with a minimum of one for very short periods
+        let period_halved = (period / 2).max(1);
+        let period_sqrt = ((period as f64).sqrt() as usize).max(1);
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            ma1: WeightedMovingAverage::new(period_halved, Some(get_weights(period_halved)), None),
+            ma2: WeightedMovingAverage::new(period, Some(get_weights(period)), None),
+            ma3: WeightedMovingAverage::new(period_sqrt, Some(get_weights(period_sqrt)), None),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        self.ma1.update_raw(value);
+        self.ma2.update_raw(value);
+        self.ma3
+            .update_raw(2.0f64.mul_add(self.ma1.value, -self.ma2.value));
+
+        self.value = self.ma3.value;
+        self.count += 1;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.count >= self.period {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hma_initialized() {
+        let hma = HullMovingAverage::new(10, None);
+
+        assert_eq!(hma.name(), "HullMovingAverage");
+        assert_eq!(hma.ma1.period, 5);
+        assert_eq!(hma.ma2.period, 10);
+        assert_eq!(hma.ma3.period, 3);
+        assert!(!hma.is_initialized());
+    }
+
+    #[test]
+    fn test_get_weights() {
+        assert_eq!(get_weights(4), vec![0.1, 0.2, 0.3, 0.4]);
+    }
+
+    #[test]
+    fn test_hma_constant_input_converges_to_input() {
+        let mut hma = HullMovingAverage::new(10, None);
+        for _ in 0..10 {
+            hma.update_raw(1.0);
+        }
+
+        assert!(hma.is_initialized());
+        assert!((hma.value - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hma_reset() {
+        let mut hma = HullMovingAverage::new(10, None);
+        hma.update_raw(1.0);
+
+        hma.reset();
+
+        assert_eq!(hma.count, 0);
+        assert_eq!(hma.value, 0.0);
+        assert_eq!(hma.ma1.count, 0);
+        assert!(!hma.has_inputs());
+    }
+}
@@ -0,0 +1,222 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod ama;
+pub mod dema;
+pub mod ema;
+pub mod hma;
+pub mod rma;
+pub mod sma;
+pub mod vidya;
+pub mod wma;
+
+use std::fmt::Debug;
+
+use pyo3::prelude::*;
+use strum::{Display, EnumString, FromRepr};
+
+use self::{
+    ama::AdaptiveMovingAverage, dema::DoubleExponentialMovingAverage,
+    ema::ExponentialMovingAverage, hma::HullMovingAverage, rma::WilderMovingAverage,
+    sma::SimpleMovingAverage, vidya::VariableIndexDynamicAverage, wma::WeightedMovingAverage,
+};
+use crate::Indicator;
+
+/// The type of moving average.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[pyclass]
+pub enum MovingAverageType {
+    Simple = 0,
+    Exponential = 1,
+    Weighted = 2,
+    Hull = 3,
+    Adaptive = 4,
+    Wilder = 5,
+    DoubleExponential = 6,
+    VariableIndexDynamic = 7,
+}
+
+/// Provides the common interface for all moving average type indicators.
+pub trait MovingAverage: Indicator + Debug {
+    fn value(&self) -> f64;
+    fn count(&self) -> usize;
+    fn update_raw(&mut self, value: f64);
+}
+
+/// The default fast smoothing period for an `Adaptive` moving average created
+/// by the [`MovingAverageFactory`].
+pub const AMA_DEFAULT_PERIOD_ALPHA_FAST: usize = 2;
+
+/// The default slow smoothing period for an `Adaptive` moving average created
+/// by the [`MovingAverageFactory`].
+pub const AMA_DEFAULT_PERIOD_ALPHA_SLOW: usize = 30;
+
+/// Provides a factory to construct different moving average indicators.
+#[derive(Debug)]
+#[pyclass]
+pub struct MovingAverageFactory;
+
+impl MovingAverageFactory {
+    /// Create a moving average indicator corresponding to the given `ma_type`.
+    ///
+    /// An `Adaptive` moving average uses `period` for its efficiency ratio, with
+    /// the default fast and slow smoothing periods.
+    ///
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `ma_type` is `Adaptive` and `period` is less than 2.
+    #[must_use]
+    pub fn create(
+        period: usize,
+        ma_type: MovingAverageType,
+    ) -> Box<dyn MovingAverage + Send + Sync> {
+        check_period(period);
+
+        match ma_type {
+            MovingAverageType::Simple => Box::new(SimpleMovingAverage::new(period, None)),
+            MovingAverageType::Exponential => Box::new(ExponentialMovingAverage::new(period, None)),
+            MovingAverageType::Weighted => Box::new(WeightedMovingAverage::new(period, None, None)),
+            MovingAverageType::Hull => Box::new(HullMovingAverage::new(period, None)),
+            MovingAverageType::Wilder => Box::new(WilderMovingAverage::new(period, None)),
+            MovingAverageType::DoubleExponential => {
+                Box::new(DoubleExponentialMovingAverage::new(period, None))
+            }
+            MovingAverageType::VariableIndexDynamic => {
+                Box::new(VariableIndexDynamicAverage::new(period, None, None))
+            }
+            MovingAverageType::Adaptive => Box::new(AdaptiveMovingAverage::new(
+                period,
+                AMA_DEFAULT_PERIOD_ALPHA_FAST,
+                AMA_DEFAULT_PERIOD_ALPHA_SLOW,
+                None,
+            )),
+        }
+    }
+}
+
+#[pymethods]
+impl MovingAverageFactory {
+    #[staticmethod]
+    #[pyo3(
+        name = "create",
+        signature = (
+            period,
+            ma_type,
+            period_alpha_fast = AMA_DEFAULT_PERIOD_ALPHA_FAST,
+            period_alpha_slow = AMA_DEFAULT_PERIOD_ALPHA_SLOW,
+        )
+    )]
+    fn create_py(
+        py: Python<'_>,
+        period: usize,
+        ma_type: MovingAverageType,
+        period_alpha_fast: usize,
+        period_alpha_slow: usize,
+    ) -> PyObject {
+        check_period(period);
+
+        match ma_type {
+            MovingAverageType::Simple => SimpleMovingAverage::new(period, None).into_py(py),
+            MovingAverageType::Exponential => {
+                ExponentialMovingAverage::new(period, None).into_py(py)
+            }
+            MovingAverageType::Weighted => {
+                WeightedMovingAverage::new(period, None, None).into_py(py)
+            }
+            MovingAverageType::Hull => HullMovingAverage::new(period, None).into_py(py),
+            MovingAverageType::Wilder => WilderMovingAverage::new(period, None).into_py(py),
+            MovingAverageType::DoubleExponential => {
+                DoubleExponentialMovingAverage::new(period, None).into_py(py)
+            }
+            MovingAverageType::VariableIndexDynamic => {
+                VariableIndexDynamicAverage::new(period, None, None).into_py(py)
+            }
+            MovingAverageType::Adaptive => {
+                AdaptiveMovingAverage::new(period, period_alpha_fast, period_alpha_slow, None)
+                    .into_py(py)
+            }
+        }
+    }
+}
+
+/// Check the `period` for a moving average is positive.
+///
+/// # Panics
+///
+/// - If `period` is zero.
+pub(crate) fn check_period(period: usize) {
+    if period == 0 {
+        panic!("invalid `period` for moving average, was {period}");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factory_creates_each_type() {
+        for (ma_type, name) in [
+            (MovingAverageType::Simple, "SimpleMovingAverage"),
+            (MovingAverageType::Exponential, "ExponentialMovingAverage"),
+            (MovingAverageType::Weighted, "WeightedMovingAverage"),
+            (MovingAverageType::Hull, "HullMovingAverage"),
+            (MovingAverageType::Wilder, "WilderMovingAverage"),
+            (
+                MovingAverageType::DoubleExponential,
+                "DoubleExponentialMovingAverage",
+            ),
+            (
+                MovingAverageType::VariableIndexDynamic,
+                "VariableIndexDynamicAverage",
+            ),
+            (MovingAverageType::Adaptive, "AdaptiveMovingAverage"),
+        ] {
+            let ma = MovingAverageFactory::create(10, ma_type);
+            assert_eq!(ma.name(), name);
+            assert_eq!(ma.count(), 0);
+            assert!(!ma.is_initialized());
+        }
+    }
+
+    #[test]
+    fn test_factory_moving_average_updates_through_trait() {
+        let mut ma = MovingAverageFactory::create(2, MovingAverageType::Simple);
+        ma.update_raw(1.0);
+        ma.update_raw(3.0);
+
+        assert!(ma.is_initialized());
+        assert_eq!(ma.value(), 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_factory_with_zero_period() {
+        let _ = MovingAverageFactory::create(0, MovingAverageType::Simple);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_factory_with_adaptive_type_and_period_one() {
+        let _ = MovingAverageFactory::create(1, MovingAverageType::Adaptive);
+    }
+}
@@ -0,0 +1,211 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{check_period, MovingAverage},
+    Indicator,
+};
+
+/// An indicator which calculates a Wilder's Moving Average (RMA) across a
+/// rolling window, an exponential moving average with `alpha = 1 / period`.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct WilderMovingAverage {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub price_type: PriceType,
+    #[pyo3(get)]
+    pub alpha: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub count: usize,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for WilderMovingAverage {
+    fn name(&self) -> String {
+        stringify!(WilderMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MovingAverage for WilderMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+#[pymethods]
+impl WilderMovingAverage {
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        check_period(period);
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            alpha: 1.0 / period as f64,
+            value: 0.0,
+            count: 0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        if !self._has_inputs {
+            self.value = value;
+        }
+
+        self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
+        self.count += 1;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.count >= self.period {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rma_initialized() {
+        let rma = WilderMovingAverage::new(10, None);
+
+        assert_eq!(rma.name(), "WilderMovingAverage");
+        assert_eq!(rma.alpha, 0.1);
+        assert!(!rma.is_initialized());
+    }
+
+    #[test]
+    fn test_rma_update_raw() {
+        let mut rma = WilderMovingAverage::new(2, None);
+        rma.update_raw(1.0);
+        assert_eq!(rma.value, 1.0);
+
+        rma.update_raw(3.0);
+        assert!(rma.is_initialized());
+        assert_eq!(rma.value, 2.0);
+
+        rma.update_raw(4.0);
+        assert_eq!(rma.value, 3.0);
+    }
+
+    #[test]
+    fn test_rma_reset() {
+        let mut rma = WilderMovingAverage::new(2, None);
+        rma.update_raw(1.0);
+
+        rma.reset();
+
+        assert_eq!(rma.count, 0);
+        assert_eq!(rma.value, 0.0);
+        assert!(!rma.has_inputs());
+    }
+}
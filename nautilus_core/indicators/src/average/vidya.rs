@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{check_period, MovingAverage, MovingAverageType},
+    momentum::cmo::ChandeMomentumOscillator,
+    Indicator,
+};
+
+/// An indicator which calculates a Variable Index Dynamic Average (VIDYA)
+/// across a rolling window, scaling the smoothing factor by the absolute
+/// Chande Momentum Oscillator.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct VariableIndexDynamicAverage {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub price_type: PriceType,
+    #[pyo3(get)]
+    pub alpha: f64,
+    #[pyo3(get)]
+    pub cmo_pct: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub count: usize,
+    cmo: ChandeMomentumOscillator,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for VariableIndexDynamicAverage {
+    fn name(&self) -> String {
+        stringify!(VariableIndexDynamicAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.cmo.reset();
+        self.cmo_pct = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MovingAverage for VariableIndexDynamicAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+#[pymethods]
+impl VariableIndexDynamicAverage {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `cmo_ma_type` is `VariableIndexDynamic`.
+    #[must_use]
+    #[new]
+    pub fn new(
+        period: usize,
+        price_type: Option<PriceType>,
+        cmo_ma_type: Option<MovingAverageType>,
+    ) -> Self {
+        check_period(period);
+        let cmo_ma_type = cmo_ma_type.unwrap_or(MovingAverageType::Simple);
+        if cmo_ma_type == MovingAverageType::VariableIndexDynamic {
+            panic!("invalid `cmo_ma_type` for `VariableIndexDynamicAverage`, was {cmo_ma_type}");
+        }
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            alpha: 2.0 / (period as f64 + 1.0),
+            cmo_pct: 0.0,
+            value: 0.0,
+            count: 0,
+            cmo: ChandeMomentumOscillator::new(period, Some(cmo_ma_type)),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        self.cmo.update_raw(value);
+        self.cmo_pct = (self.cmo.value / 100.0).abs();
+
+        if self._is_initialized {
+            let scaled_alpha = self.alpha * self.cmo_pct;
+            self.value = scaled_alpha.mul_add(value, (1.0 - scaled_alpha) * self.value);
+        }
+
+        // Initialization logic
+        if !self._is_initialized && self.cmo.is_initialized() {
+            self._is_initialized = true;
+        }
+
+        self.count += 1;
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.count >= self.period {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vidya_initialized() {
+        let vidya = VariableIndexDynamicAverage::new(10, None, None);
+
+        assert_eq!(vidya.name(), "VariableIndexDynamicAverage");
+        assert_eq!(vidya.alpha, 2.0 / 11.0);
+        assert_eq!(vidya.value, 0.0);
+        assert!(!vidya.is_initialized());
+    }
+
+    #[test]
+    fn test_vidya_update_raw_after_initialized() {
+        let mut vidya = VariableIndexDynamicAverage::new(3, None, None);
+        for value in [1.0, 2.0, 3.0] {
+            vidya.update_raw(value);
+        }
+        assert!(vidya.is_initialized());
+        assert_eq!(vidya.value, 0.0);
+
+        // Rising inputs give a CMO of 100, so the full alpha is applied
+        vidya.update_raw(4.0);
+        assert_eq!(vidya.cmo_pct, 1.0);
+        assert_eq!(vidya.value, 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vidya_with_variable_index_dynamic_cmo_ma_type() {
+        let _ = VariableIndexDynamicAverage::new(
+            10,
+            None,
+            Some(MovingAverageType::VariableIndexDynamic),
+        );
+    }
+
+    #[test]
+    fn test_vidya_flat_inputs_then_rising() {
+        let mut vidya = VariableIndexDynamicAverage::new(3, None, None);
+        for value in [1.0, 1.0, 1.0, 1.0, 2.0] {
+            vidya.update_raw(value);
+        }
+
+        assert!(vidya.value.is_finite());
+        assert!(vidya.value > 0.0);
+    }
+
+    #[test]
+    fn test_vidya_reset() {
+        let mut vidya = VariableIndexDynamicAverage::new(3, None, None);
+        vidya.update_raw(1.0);
+
+        vidya.reset();
+
+        assert_eq!(vidya.count, 0);
+        assert_eq!(vidya.cmo_pct, 0.0);
+        assert!(!vidya.has_inputs());
+    }
+}
@@ -0,0 +1,272 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{check_period, MovingAverage},
+    Indicator,
+};
+
+/// An indicator which calculates a weighted moving average across a rolling window.
+///
+/// If no `weights` are given then the inputs are equally weighted.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct WeightedMovingAverage {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub weights: Option<Vec<f64>>,
+    #[pyo3(get)]
+    pub price_type: PriceType,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub count: usize,
+    inputs: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for WeightedMovingAverage {
+    fn name(&self) -> String {
+        stringify!(WeightedMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.inputs.clear();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MovingAverage for WeightedMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl WeightedMovingAverage {
+    fn weighted_average(&self) -> f64 {
+        match &self.weights {
+            Some(weights) => {
+                // Use the most recent weights while the window is still filling
+                let weights = &weights[weights.len() - self.inputs.len()..];
+                let sum_weights: f64 = weights.iter().sum();
+                let sum_products: f64 = self
+                    .inputs
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(input, weight)| input * weight)
+                    .sum();
+                sum_products / sum_weights
+            }
+            None => self.inputs.iter().sum::<f64>() / self.inputs.len() as f64,
+        }
+    }
+}
+
+#[pymethods]
+impl WeightedMovingAverage {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `weights` is not of length `period`.
+    /// - If the sum of `weights` is not positive.
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, weights: Option<Vec<f64>>, price_type: Option<PriceType>) -> Self {
+        check_period(period);
+        if let Some(weights) = &weights {
+            if weights.len() != period {
+                panic!(
+                    "invalid `weights` length for `period` {period}, was {}",
+                    weights.len()
+                );
+            }
+            let sum_weights: f64 = weights.iter().sum();
+            if sum_weights <= f64::EPSILON {
+                panic!("invalid `weights`, sum must be positive > {}", f64::EPSILON);
+            }
+        }
+
+        Self {
+            period,
+            weights,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            inputs: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period {
+            self.inputs.pop_front();
+        }
+        self.inputs.push_back(value);
+
+        self.value = self.weighted_average();
+        self.count += 1;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.count >= self.period {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wma_without_weights_is_simple_average() {
+        let mut wma = WeightedMovingAverage::new(3, None, None);
+        for value in [1.0, 2.0, 3.0] {
+            wma.update_raw(value);
+        }
+
+        assert!(wma.is_initialized());
+        assert_eq!(wma.value, 2.0);
+    }
+
+    #[test]
+    fn test_wma_with_weights() {
+        let mut wma = WeightedMovingAverage::new(3, Some(vec![1.0, 2.0, 3.0]), None);
+        wma.update_raw(1.0);
+        assert_eq!(wma.value, 1.0);
+
+        wma.update_raw(2.0);
+        // Uses the last two weights while filling: (1 * 2 + 2 * 3) / 5
+        assert_eq!(wma.value, 1.6);
+
+        wma.update_raw(3.0);
+        wma.update_raw(4.0);
+        // (2 * 1 + 3 * 2 + 4 * 3) / 6
+        assert!((wma.value - 20.0 / 6.0).abs() < 1e-12);
+        assert_eq!(wma.count, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wma_with_weights_length_not_equal_to_period() {
+        let _ = WeightedMovingAverage::new(3, Some(vec![1.0, 2.0]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wma_with_zero_weights() {
+        let _ = WeightedMovingAverage::new(2, Some(vec![0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_wma_reset() {
+        let mut wma = WeightedMovingAverage::new(2, None, None);
+        wma.update_raw(1.0);
+
+        wma.reset();
+
+        assert_eq!(wma.count, 0);
+        assert_eq!(wma.value, 0.0);
+        assert!(!wma.has_inputs());
+    }
+}
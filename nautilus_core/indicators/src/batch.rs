@@ -0,0 +1,567 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+
+#[cfg(feature = "arrow")]
+use arrow::{
+    array::{Array, ArrayRef, BooleanArray, Float64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::data::Data;
+
+use crate::{
+    chain::{HlcIndicator, ValueIndicator},
+    Indicator, MultiOutputIndicator,
+};
+
+/// A named output column and the function which reads it from an indicator.
+pub type OutputSelector<I> = (&'static str, Box<dyn Fn(&I) -> f64>);
+
+/// The output columns of a batch computation, with one row per input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchOutput {
+    pub columns: Vec<(&'static str, Vec<f64>)>,
+    pub initialized: Vec<bool>,
+}
+
+impl BatchOutput {
+    fn with_capacity(names: &[&'static str], rows: usize) -> Self {
+        Self {
+            columns: names
+                .iter()
+                .map(|name| (*name, Vec::with_capacity(rows)))
+                .collect(),
+            initialized: Vec::with_capacity(rows),
+        }
+    }
+
+    fn push_row<I: Indicator>(&mut self, indicator: &I, outputs: &[OutputSelector<I>]) {
+        for ((_, column), (_, select)) in self.columns.iter_mut().zip(outputs) {
+            column.push(select(indicator));
+        }
+        self.initialized.push(indicator.is_initialized());
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.initialized.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.initialized.is_empty()
+    }
+
+    #[must_use]
+    pub fn column(&self, name: &str) -> Option<&[f64]> {
+        self.columns
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, column)| column.as_slice())
+    }
+}
+
+/// Returns a selector for each output of the multi-output indicator type.
+#[must_use]
+pub fn multi_outputs<I: MultiOutputIndicator>(indicator: &I) -> Vec<OutputSelector<I>> {
+    indicator
+        .output_names()
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let select: Box<dyn Fn(&I) -> f64> = Box::new(move |ind: &I| ind.outputs()[i]);
+            (*name, select)
+        })
+        .collect()
+}
+
+/// Computes the `outputs` of the indicator for each of the raw `values`.
+///
+/// The indicator is updated in place exactly as the incremental path would be,
+/// so consecutive calls continue from the previous state.
+pub fn batch_values<I: ValueIndicator>(
+    indicator: &mut I,
+    values: &[f64],
+    outputs: &[OutputSelector<I>],
+) -> BatchOutput {
+    let names: Vec<&'static str> = outputs.iter().map(|(name, _)| *name).collect();
+    let mut batch = BatchOutput::with_capacity(&names, values.len());
+    for value in values {
+        indicator.update_value(*value);
+        batch.push_row(indicator, outputs);
+    }
+    batch
+}
+
+/// Computes the `outputs` of the indicator for each bar of the raw `highs`,
+/// `lows` and `closes`.
+///
+/// # Panics
+///
+/// - If `highs`, `lows` and `closes` are not the same length.
+pub fn batch_hlc<I: HlcIndicator>(
+    indicator: &mut I,
+    highs: &[f64],
+    lows: &[f64],
+    closes: &[f64],
+    outputs: &[OutputSelector<I>],
+) -> BatchOutput {
+    assert!(
+        highs.len() == lows.len() && lows.len() == closes.len(),
+        "batch input lengths differ, highs {}, lows {}, closes {}",
+        highs.len(),
+        lows.len(),
+        closes.len(),
+    );
+    let names: Vec<&'static str> = outputs.iter().map(|(name, _)| *name).collect();
+    let mut batch = BatchOutput::with_capacity(&names, closes.len());
+    for ((high, low), close) in highs.iter().zip(lows).zip(closes) {
+        indicator.update_hlc(*high, *low, *close);
+        batch.push_row(indicator, outputs);
+    }
+    batch
+}
+
+/// Computes the `outputs` of the indicator for each item of `data`, such as the
+/// chunks produced by a `DataBackendSession`.
+///
+/// Quotes, trades and bars are passed to the matching indicator handler. Order
+/// book deltas do not update the indicator, but still produce a row so the
+/// columns stay aligned with `data`.
+pub fn batch_data<I: Indicator>(
+    indicator: &mut I,
+    data: &[Data],
+    outputs: &[OutputSelector<I>],
+) -> BatchOutput {
+    let names: Vec<&'static str> = outputs.iter().map(|(name, _)| *name).collect();
+    let mut batch = BatchOutput::with_capacity(&names, data.len());
+    for item in data {
+        match item {
+            Data::Quote(tick) => indicator.handle_quote_tick(tick),
+            Data::Trade(tick) => indicator.handle_trade_tick(tick),
+            Data::Bar(bar) => indicator.handle_bar(bar),
+            Data::Delta(_) => {}
+        }
+        batch.push_row(indicator, outputs);
+    }
+    batch
+}
+
+/// The name of the initialization flag column in output record batches.
+#[cfg(feature = "arrow")]
+pub const INITIALIZED_COLUMN: &str = "initialized";
+
+/// The names of the bar price columns in input record batches.
+#[cfg(feature = "arrow")]
+pub const HIGH_COLUMN: &str = "high";
+#[cfg(feature = "arrow")]
+pub const LOW_COLUMN: &str = "low";
+#[cfg(feature = "arrow")]
+pub const CLOSE_COLUMN: &str = "close";
+
+#[cfg(feature = "arrow")]
+fn check_no_nulls(values: &Float64Array) -> Result<(), ArrowError> {
+    if values.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "batch input contained {} null values",
+            values.null_count()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "arrow")]
+fn price_column<'a>(bars: &'a RecordBatch, name: &str) -> Result<&'a Float64Array, ArrowError> {
+    let column = bars
+        .column_by_name(name)
+        .ok_or_else(|| ArrowError::SchemaError(format!("batch input has no `{name}` column")))?;
+    let values = column
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "batch input column `{name}` was {}, expected {}",
+                column.data_type(),
+                DataType::Float64
+            ))
+        })?;
+    check_no_nulls(values)?;
+    Ok(values)
+}
+
+/// Computes the `outputs` of the indicator for each value of the array.
+///
+/// # Errors
+///
+/// - If `values` contains nulls.
+/// - If the output record batch cannot be created.
+#[cfg(feature = "arrow")]
+pub fn batch_array<I: ValueIndicator>(
+    indicator: &mut I,
+    values: &Float64Array,
+    outputs: &[OutputSelector<I>],
+) -> Result<RecordBatch, ArrowError> {
+    check_no_nulls(values)?;
+    batch_values(indicator, values.values(), outputs).try_into()
+}
+
+/// Computes the `outputs` of the indicator for each row of the `bars` record
+/// batch, which holds the prices in `high`, `low` and `close` columns. Any
+/// other columns are ignored.
+///
+/// # Errors
+///
+/// - If `bars` has no `high`, `low` or `close` column.
+/// - If a price column is not `Float64` or contains nulls.
+/// - If the output record batch cannot be created.
+#[cfg(feature = "arrow")]
+pub fn batch_bars<I: HlcIndicator>(
+    indicator: &mut I,
+    bars: &RecordBatch,
+    outputs: &[OutputSelector<I>],
+) -> Result<RecordBatch, ArrowError> {
+    let highs = price_column(bars, HIGH_COLUMN)?;
+    let lows = price_column(bars, LOW_COLUMN)?;
+    let closes = price_column(bars, CLOSE_COLUMN)?;
+    batch_hlc(
+        indicator,
+        highs.values(),
+        lows.values(),
+        closes.values(),
+        outputs,
+    )
+    .try_into()
+}
+
+#[cfg(feature = "arrow")]
+impl TryFrom<BatchOutput> for RecordBatch {
+    type Error = ArrowError;
+
+    fn try_from(batch: BatchOutput) -> Result<Self, Self::Error> {
+        let mut fields = Vec::with_capacity(batch.columns.len() + 1);
+        let mut arrays: Vec<ArrayRef> = Vec::with_capacity(batch.columns.len() + 1);
+        for (name, column) in batch.columns {
+            fields.push(Field::new(name, DataType::Float64, false));
+            arrays.push(Arc::new(Float64Array::from(column)));
+        }
+        fields.push(Field::new(INITIALIZED_COLUMN, DataType::Boolean, false));
+        arrays.push(Arc::new(BooleanArray::from(batch.initialized)));
+
+        Self::try_new(Arc::new(Schema::new(fields)), arrays)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        data::{bar::Bar, bar::BarType, tick::TradeTick},
+        enums::AggressorSide,
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+    use crate::{
+        average::ema::ExponentialMovingAverage,
+        momentum::{rsi::RelativeStrengthIndex, stochastics::Stochastics},
+        volatility::{atr::AverageTrueRange, bollinger_bands::BollingerBands},
+    };
+
+    fn bar(high: f64, low: f64, close: f64) -> Data {
+        Data::Bar(Bar {
+            bar_type: BarType::from_str("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL").unwrap(),
+            open: Price::new(close, 5),
+            high: Price::new(high, 5),
+            low: Price::new(low, 5),
+            close: Price::new(close, 5),
+            volume: Quantity::new(1_000_000.0, 0),
+            ts_event: 0,
+            ts_init: 0,
+        })
+    }
+
+    fn trade(price: f64) -> Data {
+        Data::Trade(TradeTick {
+            instrument_id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            price: Price::new(price, 5),
+            size: Quantity::new(1.0, 0),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new("1"),
+            ts_event: 0,
+            ts_init: 0,
+        })
+    }
+
+    #[test]
+    fn test_batch_values_matches_incremental() {
+        let values = [1.0, 2.0, 1.5, 3.0, 2.5, 4.0, 3.5, 5.0];
+        let mut incremental = RelativeStrengthIndex::new(3, None);
+        let mut batched = RelativeStrengthIndex::new(3, None);
+        let outputs: Vec<OutputSelector<RelativeStrengthIndex>> =
+            vec![("value", Box::new(|rsi: &RelativeStrengthIndex| rsi.value))];
+
+        let batch = batch_values(&mut batched, &values, &outputs);
+
+        assert_eq!(batch.len(), values.len());
+        let column = batch.column("value").unwrap();
+        for (i, value) in values.iter().enumerate() {
+            incremental.update_raw(*value);
+            assert_eq!(column[i], incremental.value);
+            assert_eq!(batch.initialized[i], incremental.is_initialized());
+        }
+    }
+
+    #[test]
+    fn test_batch_values_continues_from_previous_state() {
+        let mut ema = ExponentialMovingAverage::new(3, None);
+        let outputs: Vec<OutputSelector<ExponentialMovingAverage>> = vec![(
+            "value",
+            Box::new(|ema: &ExponentialMovingAverage| ema.value),
+        )];
+
+        let first = batch_values(&mut ema, &[1.0, 2.0], &outputs);
+        let second = batch_values(&mut ema, &[3.0], &outputs);
+
+        let mut expected = ExponentialMovingAverage::new(3, None);
+        for value in [1.0, 2.0, 3.0] {
+            expected.update_raw(value);
+        }
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.column("value").unwrap(), &[expected.value]);
+    }
+
+    #[test]
+    fn test_batch_data_multi_output_matches_incremental() {
+        let data = vec![
+            bar(1.00020, 1.00000, 1.00015),
+            trade(1.00012),
+            bar(1.00030, 1.00010, 1.00015),
+            bar(1.00040, 1.00020, 1.00021),
+        ];
+        let mut batched = BollingerBands::new(3, 2.0, None);
+        let outputs = multi_outputs(&batched);
+
+        let batch = batch_data(&mut batched, &data, &outputs);
+
+        let mut incremental = BollingerBands::new(3, 2.0, None);
+        for (i, item) in data.iter().enumerate() {
+            match item {
+                Data::Bar(bar) => incremental.handle_bar(bar),
+                Data::Trade(tick) => incremental.handle_trade_tick(tick),
+                _ => unreachable!(),
+            }
+            assert_eq!(batch.column("upper").unwrap()[i], incremental.upper);
+            assert_eq!(batch.column("middle").unwrap()[i], incremental.middle);
+            assert_eq!(batch.column("lower").unwrap()[i], incremental.lower);
+            assert_eq!(batch.initialized[i], incremental.is_initialized());
+        }
+        assert!(batch.initialized[3]);
+    }
+
+    #[test]
+    fn test_batch_hlc_matches_incremental() {
+        let highs = [1.00020, 1.00030, 1.00040, 1.00035];
+        let lows = [1.00000, 1.00010, 1.00020, 1.00015];
+        let closes = [1.00015, 1.00015, 1.00021, 1.00030];
+        let mut batched = AverageTrueRange::new(2, None, None, None);
+        let outputs: Vec<OutputSelector<AverageTrueRange>> =
+            vec![("value", Box::new(|atr: &AverageTrueRange| atr.value))];
+
+        let batch = batch_hlc(&mut batched, &highs, &lows, &closes, &outputs);
+
+        let mut incremental = AverageTrueRange::new(2, None, None, None);
+        for i in 0..closes.len() {
+            incremental.update_raw(highs[i], lows[i], closes[i]);
+            assert_eq!(batch.column("value").unwrap()[i], incremental.value);
+            assert_eq!(batch.initialized[i], incremental.is_initialized());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "batch input lengths differ")]
+    fn test_batch_hlc_with_different_lengths_panics() {
+        let mut atr = AverageTrueRange::new(2, None, None, None);
+        let outputs: Vec<OutputSelector<AverageTrueRange>> =
+            vec![("value", Box::new(|atr: &AverageTrueRange| atr.value))];
+
+        let _ = batch_hlc(&mut atr, &[1.0, 2.0], &[1.0], &[1.0, 2.0], &outputs);
+    }
+
+    #[test]
+    fn test_batch_empty_input() {
+        let mut ema = ExponentialMovingAverage::new(3, None);
+        let outputs: Vec<OutputSelector<ExponentialMovingAverage>> = vec![(
+            "value",
+            Box::new(|ema: &ExponentialMovingAverage| ema.value),
+        )];
+
+        let batch = batch_data(&mut ema, &[], &outputs);
+
+        assert!(batch.is_empty());
+        assert_eq!(batch.column("value").unwrap(), &[] as &[f64]);
+        assert_eq!(batch.column("unknown"), None);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_batch_array_to_record_batch() {
+        let values = Float64Array::from(vec![1.0, 2.0, 1.5, 3.0]);
+        let mut batched = RelativeStrengthIndex::new(3, None);
+        let outputs: Vec<OutputSelector<RelativeStrengthIndex>> =
+            vec![("value", Box::new(|rsi: &RelativeStrengthIndex| rsi.value))];
+
+        let record_batch = batch_array(&mut batched, &values, &outputs).unwrap();
+
+        let mut incremental = RelativeStrengthIndex::new(3, None);
+        let expected = batch_values(&mut incremental, values.values(), &outputs);
+        let schema = record_batch.schema();
+        assert_eq!(record_batch.num_rows(), 4);
+        assert_eq!(record_batch.num_columns(), 2);
+        assert_eq!(schema.field(0).name(), "value");
+        assert_eq!(schema.field(1).name(), INITIALIZED_COLUMN);
+        let column = record_batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(column.values(), expected.column("value").unwrap());
+        let initialized = record_batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        for (i, flag) in expected.initialized.iter().enumerate() {
+            assert_eq!(initialized.value(i), *flag);
+        }
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_batch_array_with_nulls_returns_error() {
+        let values = Float64Array::from(vec![Some(1.0), None, Some(2.0)]);
+        let mut ema = ExponentialMovingAverage::new(3, None);
+        let outputs: Vec<OutputSelector<ExponentialMovingAverage>> = vec![(
+            "value",
+            Box::new(|ema: &ExponentialMovingAverage| ema.value),
+        )];
+
+        let result = batch_array(&mut ema, &values, &outputs);
+
+        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+        assert_eq!(ema.count, 0);
+    }
+
+    #[cfg(feature = "arrow")]
+    fn bars_record_batch(columns: Vec<(&str, ArrayRef)>) -> RecordBatch {
+        let fields: Vec<Field> = columns
+            .iter()
+            .map(|(name, array)| Field::new(*name, array.data_type().clone(), true))
+            .collect();
+        let arrays = columns.into_iter().map(|(_, array)| array).collect();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).unwrap()
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_batch_bars_matches_batch_hlc() {
+        let highs = vec![1.00020, 1.00030, 1.00040, 1.00035];
+        let lows = vec![1.00000, 1.00010, 1.00020, 1.00015];
+        let closes = vec![1.00015, 1.00015, 1.00021, 1.00030];
+        let bars = bars_record_batch(vec![
+            ("close", Arc::new(Float64Array::from(closes.clone()))),
+            ("open", Arc::new(Float64Array::from(closes.clone()))),
+            ("low", Arc::new(Float64Array::from(lows.clone()))),
+            ("high", Arc::new(Float64Array::from(highs.clone()))),
+        ]);
+        let mut batched = Stochastics::new(2, 2);
+        let outputs = multi_outputs(&batched);
+
+        let record_batch = batch_bars(&mut batched, &bars, &outputs).unwrap();
+
+        let mut incremental = Stochastics::new(2, 2);
+        let expected = batch_hlc(&mut incremental, &highs, &lows, &closes, &outputs);
+        assert_eq!(record_batch.num_rows(), 4);
+        assert_eq!(record_batch.num_columns(), outputs.len() + 1);
+        for (i, (name, _)) in outputs.iter().enumerate() {
+            let column = record_batch
+                .column(i)
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap();
+            assert_eq!(record_batch.schema().field(i).name(), name);
+            assert_eq!(column.values(), expected.column(name).unwrap());
+        }
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_batch_bars_with_missing_column_returns_error() {
+        let bars = bars_record_batch(vec![
+            ("high", Arc::new(Float64Array::from(vec![1.0]))),
+            ("close", Arc::new(Float64Array::from(vec![1.0]))),
+        ]);
+        let mut atr = AverageTrueRange::new(2, None, None, None);
+        let outputs: Vec<OutputSelector<AverageTrueRange>> =
+            vec![("value", Box::new(|atr: &AverageTrueRange| atr.value))];
+
+        let result = batch_bars(&mut atr, &bars, &outputs);
+
+        assert!(matches!(result, Err(ArrowError::SchemaError(_))));
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_batch_bars_with_invalid_column_returns_error() {
+        let bars = bars_record_batch(vec![
+            ("high", Arc::new(Float64Array::from(vec![1.0]))),
+            ("low", Arc::new(BooleanArray::from(vec![true]))),
+            ("close", Arc::new(Float64Array::from(vec![1.0]))),
+        ]);
+        let mut atr = AverageTrueRange::new(2, None, None, None);
+        let outputs: Vec<OutputSelector<AverageTrueRange>> =
+            vec![("value", Box::new(|atr: &AverageTrueRange| atr.value))];
+
+        let result = batch_bars(&mut atr, &bars, &outputs);
+
+        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_batch_bars_with_nulls_returns_error() {
+        let bars = bars_record_batch(vec![
+            ("high", Arc::new(Float64Array::from(vec![Some(1.0), None]))),
+            ("low", Arc::new(Float64Array::from(vec![1.0, 1.0]))),
+            ("close", Arc::new(Float64Array::from(vec![1.0, 1.0]))),
+        ]);
+        let mut atr = AverageTrueRange::new(2, None, None, None);
+        let outputs: Vec<OutputSelector<AverageTrueRange>> =
+            vec![("value", Box::new(|atr: &AverageTrueRange| atr.value))];
+
+        let result = batch_bars(&mut atr, &bars, &outputs);
+
+        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+        assert!(!atr.has_inputs());
+    }
+}
@@ -0,0 +1,488 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Formatter};
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::{exceptions::PyTypeError, prelude::*};
+
+use crate::{
+    average::MovingAverage,
+    momentum::{
+        aroon::AroonOscillator, cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator,
+        macd::MovingAverageConvergenceDivergence, roc::RateOfChange, rsi::RelativeStrengthIndex,
+        stochastics::Stochastics,
+    },
+    ratio::efficiency_ratio::EfficiencyRatio,
+    volatility::{
+        atr::AverageTrueRange, bollinger_bands::BollingerBands, donchian_channel::DonchianChannel,
+        keltner_channel::KeltnerChannel, keltner_position::KeltnerPosition,
+        vhf::VerticalHorizontalFilter, volatility_ratio::VolatilityRatio,
+    },
+    Indicator,
+};
+
+/// Provides the interface for indicators which can be updated with a single raw value.
+pub trait ValueIndicator: Indicator {
+    fn update_value(&mut self, value: f64);
+}
+
+impl<T: MovingAverage> ValueIndicator for T {
+    fn update_value(&mut self, value: f64) {
+        MovingAverage::update_raw(self, value);
+    }
+}
+
+impl ValueIndicator for ChandeMomentumOscillator {
+    fn update_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl ValueIndicator for EfficiencyRatio {
+    fn update_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl ValueIndicator for MovingAverageConvergenceDivergence {
+    fn update_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl ValueIndicator for RateOfChange {
+    fn update_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl ValueIndicator for RelativeStrengthIndex {
+    fn update_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl ValueIndicator for VerticalHorizontalFilter {
+    fn update_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+/// Provides the interface for indicators which can be updated with the raw high,
+/// low and close prices of a bar.
+pub trait HlcIndicator: Indicator {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64);
+}
+
+impl HlcIndicator for AroonOscillator {
+    fn update_hlc(&mut self, high: f64, low: f64, _close: f64) {
+        self.update_raw(high, low);
+    }
+}
+
+impl HlcIndicator for AverageTrueRange {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+impl HlcIndicator for BollingerBands {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+impl HlcIndicator for CommodityChannelIndex {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+impl HlcIndicator for DonchianChannel {
+    fn update_hlc(&mut self, high: f64, low: f64, _close: f64) {
+        self.update_raw(high, low);
+    }
+}
+
+impl HlcIndicator for KeltnerChannel {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+impl HlcIndicator for KeltnerPosition {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+impl HlcIndicator for Stochastics {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+impl HlcIndicator for VolatilityRatio {
+    fn update_hlc(&mut self, high: f64, low: f64, close: f64) {
+        self.update_raw(high, low, close);
+    }
+}
+
+/// An adapter which feeds a selected output of a `source` indicator into a
+/// `target` indicator, for example an EMA of an RSI.
+///
+/// The source is updated by the data handlers, then the target is updated with
+/// the selected output once the source has inputs. Chains implement
+/// [`Indicator`] so they can be used as the source of another chain.
+pub struct IndicatorChain<S, T> {
+    pub source: S,
+    pub target: T,
+    selector: fn(&S) -> f64,
+}
+
+impl<S: Debug, T: Debug> Debug for IndicatorChain<S, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(IndicatorChain))
+            .field("source", &self.source)
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+impl<S: Indicator, T: ValueIndicator> IndicatorChain<S, T> {
+    #[must_use]
+    pub fn new(source: S, target: T, selector: fn(&S) -> f64) -> Self {
+        Self {
+            source,
+            target,
+            selector,
+        }
+    }
+
+    fn update_target(&mut self) {
+        if self.source.has_inputs() {
+            self.target.update_value((self.selector)(&self.source));
+        }
+    }
+}
+
+impl<S: Indicator, T: ValueIndicator> Indicator for IndicatorChain<S, T> {
+    fn name(&self) -> String {
+        format!("{}({})", self.target.name(), self.source.name())
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.source.has_inputs()
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.source.is_initialized() && self.target.is_initialized()
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.source.handle_quote_tick(tick);
+        self.update_target();
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.source.handle_trade_tick(tick);
+        self.update_target();
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.source.handle_bar(bar);
+        self.update_target();
+    }
+
+    fn reset(&mut self) {
+        self.source.reset();
+        self.target.reset();
+    }
+}
+
+impl<S: ValueIndicator, T: ValueIndicator> ValueIndicator for IndicatorChain<S, T> {
+    fn update_value(&mut self, value: f64) {
+        self.source.update_value(value);
+        self.update_target();
+    }
+}
+
+/// Provides an indicator chain for Python, where the `source` and `target`
+/// are any indicators from this module, or other chains. The `output` names the
+/// source attribute passed to the `method` of the target, which defaults to
+/// `update_raw`.
+#[derive(Debug)]
+#[pyclass(name = "IndicatorChain")]
+pub struct PyIndicatorChain {
+    #[pyo3(get)]
+    pub source: PyObject,
+    #[pyo3(get)]
+    pub target: PyObject,
+    #[pyo3(get)]
+    pub output: String,
+    #[pyo3(get)]
+    pub method: String,
+}
+
+impl PyIndicatorChain {
+    fn update_target(&self, py: Python<'_>) -> PyResult<()> {
+        let source = self.source.as_ref(py);
+        if source.call_method0("has_inputs")?.extract::<bool>()? {
+            let value: f64 = source.getattr(self.output.as_str())?.extract()?;
+            self.target
+                .as_ref(py)
+                .call_method1(self.method.as_str(), (value,))?;
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyIndicatorChain {
+    #[new]
+    fn py_new(
+        py: Python<'_>,
+        source: PyObject,
+        target: PyObject,
+        output: Option<String>,
+        method: Option<String>,
+    ) -> PyResult<Self> {
+        let output = output.unwrap_or_else(|| "value".to_string());
+        let method = method.unwrap_or_else(|| "update_raw".to_string());
+        // Fail fast on an unknown output or a target which cannot be updated
+        source.as_ref(py).getattr(output.as_str())?;
+        if !target.as_ref(py).hasattr(method.as_str())? {
+            return Err(PyTypeError::new_err(format!(
+                "target cannot be updated with a single value, has no method `{method}`"
+            )));
+        }
+
+        Ok(Self {
+            source,
+            target,
+            output,
+            method,
+        })
+    }
+
+    #[getter]
+    fn name(&self, py: Python<'_>) -> PyResult<String> {
+        let source: String = self.source.as_ref(py).getattr("name")?.extract()?;
+        let target: String = self.target.as_ref(py).getattr("name")?.extract()?;
+        Ok(format!("{target}({source})"))
+    }
+
+    #[getter]
+    fn value(&self, py: Python<'_>) -> PyResult<f64> {
+        self.target.as_ref(py).getattr("value")?.extract()
+    }
+
+    fn has_inputs(&self, py: Python<'_>) -> PyResult<bool> {
+        self.source.as_ref(py).call_method0("has_inputs")?.extract()
+    }
+
+    fn is_initialized(&self, py: Python<'_>) -> PyResult<bool> {
+        let source: bool = self
+            .source
+            .as_ref(py)
+            .call_method0("is_initialized")?
+            .extract()?;
+        let target: bool = self
+            .target
+            .as_ref(py)
+            .call_method0("is_initialized")?
+            .extract()?;
+        Ok(source && target)
+    }
+
+    fn handle_quote_tick(&self, py: Python<'_>, tick: QuoteTick) -> PyResult<()> {
+        self.source
+            .as_ref(py)
+            .call_method1("handle_quote_tick", (tick,))?;
+        self.update_target(py)
+    }
+
+    fn handle_trade_tick(&self, py: Python<'_>, tick: TradeTick) -> PyResult<()> {
+        self.source
+            .as_ref(py)
+            .call_method1("handle_trade_tick", (tick,))?;
+        self.update_target(py)
+    }
+
+    fn handle_bar(&self, py: Python<'_>, bar: Bar) -> PyResult<()> {
+        self.source.as_ref(py).call_method1("handle_bar", (bar,))?;
+        self.update_target(py)
+    }
+
+    /// Updates the source with the raw `value`, so the chain can be the target
+    /// of another chain.
+    fn update_raw(&self, py: Python<'_>, value: f64) -> PyResult<()> {
+        self.source
+            .as_ref(py)
+            .call_method1("update_raw", (value,))?;
+        self.update_target(py)
+    }
+
+    fn reset(&self, py: Python<'_>) -> PyResult<()> {
+        self.source.as_ref(py).call_method0("reset")?;
+        self.target.as_ref(py).call_method0("reset")?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::average::{ema::ExponentialMovingAverage, sma::SimpleMovingAverage};
+
+    #[test]
+    fn test_chain_ema_of_rsi() {
+        let mut chain = IndicatorChain::new(
+            RelativeStrengthIndex::new(3, None),
+            ExponentialMovingAverage::new(3, None),
+            |rsi| rsi.value,
+        );
+        let mut rsi = RelativeStrengthIndex::new(3, None);
+        let mut ema = ExponentialMovingAverage::new(3, None);
+
+        for close in [1.0, 2.0, 1.5, 3.0, 2.5, 4.0] {
+            chain.update_value(close);
+            rsi.update_raw(close);
+            ema.update_raw(rsi.value);
+        }
+
+        assert_eq!(
+            chain.name(),
+            "ExponentialMovingAverage(RelativeStrengthIndex)"
+        );
+        assert!(chain.has_inputs());
+        assert!(chain.is_initialized());
+        assert_eq!(chain.target.value, ema.value);
+    }
+
+    #[test]
+    fn test_chain_selects_multi_output() {
+        let mut chain = IndicatorChain::new(
+            MovingAverageConvergenceDivergence::new(2, 3, None, None, None),
+            SimpleMovingAverage::new(2, None),
+            |macd| macd.histogram,
+        );
+
+        for close in [1.0, 2.0, 3.0, 5.0, 8.0] {
+            chain.update_value(close);
+        }
+
+        assert_eq!(chain.target.count(), 5);
+        assert!(chain.target.value != 0.0);
+    }
+
+    #[test]
+    fn test_chain_of_chain() {
+        let inner = IndicatorChain::new(
+            RateOfChange::new(2, None),
+            SimpleMovingAverage::new(2, None),
+            |roc| roc.value,
+        );
+        let mut chain =
+            IndicatorChain::new(inner, ExponentialMovingAverage::new(2, None), |inner| {
+                inner.target.value
+            });
+
+        for close in [1.0, 2.0, 4.0, 8.0] {
+            chain.update_value(close);
+        }
+
+        assert!(chain.is_initialized());
+        assert_eq!(chain.source.target.count(), 4);
+    }
+
+    #[test]
+    fn test_py_chain_with_chain_as_target() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let inner = PyIndicatorChain::py_new(
+                py,
+                RateOfChange::new(2, None).into_py(py),
+                SimpleMovingAverage::new(2, None).into_py(py),
+                None,
+                None,
+            )
+            .unwrap();
+            let inner = Py::new(py, inner).unwrap();
+            let chain = PyIndicatorChain::py_new(
+                py,
+                RelativeStrengthIndex::new(2, None).into_py(py),
+                inner.clone_ref(py).into_py(py),
+                None,
+                None,
+            )
+            .unwrap();
+
+            for close in [1.0, 2.0, 1.5, 3.0] {
+                chain.update_raw(py, close).unwrap();
+            }
+
+            let sma = inner.borrow(py).target.clone_ref(py);
+            let count: usize = sma.as_ref(py).getattr("count").unwrap().extract().unwrap();
+            assert_eq!(count, 4);
+            assert_eq!(
+                chain.name(py).unwrap(),
+                "SimpleMovingAverage(RateOfChange)(RelativeStrengthIndex)"
+            );
+        });
+    }
+
+    #[test]
+    fn test_py_chain_with_target_without_method() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let result = PyIndicatorChain::py_new(
+                py,
+                RateOfChange::new(2, None).into_py(py),
+                SimpleMovingAverage::new(2, None).into_py(py),
+                None,
+                Some("update".to_string()),
+            );
+
+            assert!(result.unwrap_err().is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_chain_reset() {
+        let mut chain = IndicatorChain::new(
+            RelativeStrengthIndex::new(3, None),
+            ExponentialMovingAverage::new(3, None),
+            |rsi| rsi.value,
+        );
+        chain.update_value(1.0);
+        chain.update_value(2.0);
+
+        chain.reset();
+
+        assert!(!chain.has_inputs());
+        assert!(!chain.target.has_inputs());
+        assert_eq!(chain.target.value, 0.0);
+    }
+}
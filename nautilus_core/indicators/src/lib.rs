@@ -13,7 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-pub mod ema;
+pub mod average;
+pub mod batch;
+pub mod chain;
+pub mod momentum;
+pub mod ratio;
+pub mod volatility;
+pub mod volume;
+
+use std::collections::HashMap;
 
 use nautilus_model::data::{
     bar::Bar,
@@ -24,7 +32,38 @@ use pyo3::{prelude::*, types::PyModule, Python};
 /// Loaded as nautilus_pyo3.indicators
 #[pymodule]
 pub fn indicators(_: Python<'_>, m: &PyModule) -> PyResult<()> {
-    m.add_class::<ema::ExponentialMovingAverage>()?;
+    m.add_class::<average::MovingAverageType>()?;
+    m.add_class::<average::MovingAverageFactory>()?;
+    m.add_class::<average::ama::AdaptiveMovingAverage>()?;
+    m.add_class::<average::dema::DoubleExponentialMovingAverage>()?;
+    m.add_class::<average::ema::ExponentialMovingAverage>()?;
+    m.add_class::<average::hma::HullMovingAverage>()?;
+    m.add_class::<average::rma::WilderMovingAverage>()?;
+    m.add_class::<average::sma::SimpleMovingAverage>()?;
+    m.add_class::<average::vidya::VariableIndexDynamicAverage>()?;
+    m.add_class::<average::wma::WeightedMovingAverage>()?;
+    m.add_class::<chain::PyIndicatorChain>()?;
+    m.add_class::<momentum::aroon::AroonOscillator>()?;
+    m.add_class::<momentum::cci::CommodityChannelIndex>()?;
+    m.add_class::<momentum::cmo::ChandeMomentumOscillator>()?;
+    m.add_class::<momentum::macd::MovingAverageConvergenceDivergence>()?;
+    m.add_class::<momentum::roc::RateOfChange>()?;
+    m.add_class::<momentum::rsi::RelativeStrengthIndex>()?;
+    m.add_class::<momentum::rvi::RelativeVolatilityIndex>()?;
+    m.add_class::<momentum::stochastics::Stochastics>()?;
+    m.add_class::<ratio::efficiency_ratio::EfficiencyRatio>()?;
+    m.add_class::<volatility::atr::AverageTrueRange>()?;
+    m.add_class::<volatility::bollinger_bands::BollingerBands>()?;
+    m.add_class::<volatility::donchian_channel::DonchianChannel>()?;
+    m.add_class::<volatility::keltner_channel::KeltnerChannel>()?;
+    m.add_class::<volatility::keltner_position::KeltnerPosition>()?;
+    m.add_class::<volatility::vhf::VerticalHorizontalFilter>()?;
+    m.add_class::<volatility::volatility_ratio::VolatilityRatio>()?;
+    m.add_class::<volume::kvo::KlingerVolumeOscillator>()?;
+    m.add_class::<volume::obv::OnBalanceVolume>()?;
+    m.add_class::<volume::pressure::Pressure>()?;
+    m.add_class::<volume::vwap::SessionResetMode>()?;
+    m.add_class::<volume::vwap::VolumeWeightedAveragePrice>()?;
     Ok(())
 }
 
@@ -37,3 +76,25 @@ pub trait Indicator {
     fn handle_bar(&mut self, bar: &Bar);
     fn reset(&mut self);
 }
+
+/// Provides named outputs for indicators which produce more than one value.
+pub trait MultiOutputIndicator: Indicator {
+    /// Returns the output names, in the same order as [`MultiOutputIndicator::outputs`].
+    fn output_names(&self) -> &'static [&'static str];
+    fn outputs(&self) -> Vec<f64>;
+
+    fn output(&self, name: &str) -> Option<f64> {
+        self.output_names()
+            .iter()
+            .position(|n| *n == name)
+            .map(|i| self.outputs()[i])
+    }
+
+    fn output_map(&self) -> HashMap<&'static str, f64> {
+        self.output_names()
+            .iter()
+            .copied()
+            .zip(self.outputs())
+            .collect()
+    }
+}
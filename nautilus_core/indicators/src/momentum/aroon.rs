@@ -0,0 +1,286 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::prelude::*;
+
+use crate::{Indicator, MultiOutputIndicator};
+
+/// The Aroon Oscillator calculates the Aroon Up and Aroon Down indicators to
+/// determine if an instrument is trending, and the strength of the trend.
+///
+/// Quote ticks are handled as (ask, bid) and trade ticks as a zero-range
+/// (price, price) update.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct AroonOscillator {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub aroon_up: f64,
+    #[pyo3(get)]
+    pub aroon_down: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    high_inputs: VecDeque<f64>,
+    low_inputs: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for AroonOscillator {
+    fn name(&self) -> String {
+        stringify!(AroonOscillator).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw((&tick.ask).into(), (&tick.bid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into())
+    }
+
+    fn reset(&mut self) {
+        self.high_inputs.clear();
+        self.low_inputs.clear();
+        self.aroon_up = 0.0;
+        self.aroon_down = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+/// Return the index of the first occurrence of the extreme value, where
+/// `is_better(a, b)` returns true if `a` should replace the current extreme `b`.
+fn arg_extreme(values: &VecDeque<f64>, is_better: fn(f64, f64) -> bool) -> usize {
+    let mut index = 0;
+    for (i, value) in values.iter().enumerate() {
+        if is_better(*value, values[index]) {
+            index = i;
+        }
+    }
+    index
+}
+
+impl MultiOutputIndicator for AroonOscillator {
+    fn output_names(&self) -> &'static [&'static str] {
+        &["aroon_up", "aroon_down", "value"]
+    }
+
+    fn outputs(&self) -> Vec<f64> {
+        vec![self.aroon_up, self.aroon_down, self.value]
+    }
+}
+
+#[pymethods]
+impl AroonOscillator {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize) -> Self {
+        if period == 0 {
+            panic!("invalid `period` for `AroonOscillator`, was {period}");
+        }
+
+        Self {
+            period,
+            aroon_up: 0.0,
+            aroon_down: 0.0,
+            value: 0.0,
+            high_inputs: VecDeque::with_capacity(period + 1),
+            low_inputs: VecDeque::with_capacity(period + 1),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "output_names")]
+    fn output_names_py(&self) -> Vec<&'static str> {
+        self.output_names().to_vec()
+    }
+
+    #[pyo3(name = "outputs")]
+    fn outputs_py(&self) -> HashMap<&'static str, f64> {
+        self.output_map()
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        // Most recent inputs are held at the front
+        if self.high_inputs.len() == self.period + 1 {
+            self.high_inputs.pop_back();
+            self.low_inputs.pop_back();
+        }
+        self.high_inputs.push_front(high);
+        self.low_inputs.push_front(low);
+
+        let periods_from_hh = arg_extreme(&self.high_inputs, |a, b| a > b) as f64;
+        let periods_from_ll = arg_extreme(&self.low_inputs, |a, b| a < b) as f64;
+
+        self.aroon_up = 100.0 * (1.0 - periods_from_hh / self.period as f64);
+        self.aroon_down = 100.0 * (1.0 - periods_from_ll / self.period as f64);
+        self.value = self.aroon_up - self.aroon_down;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.high_inputs.len() > self.period {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aroon_initialized() {
+        let aroon = AroonOscillator::new(10);
+
+        assert_eq!(aroon.name(), "AroonOscillator");
+        assert_eq!(aroon.period, 10);
+        assert!(!aroon.is_initialized());
+    }
+
+    #[test]
+    fn test_aroon_initialized_with_required_inputs() {
+        let mut aroon = AroonOscillator::new(10);
+        for _ in 0..20 {
+            aroon.update_raw(110.08, 109.61);
+        }
+
+        assert!(aroon.is_initialized());
+    }
+
+    #[test]
+    fn test_aroon_value_with_one_input() {
+        let mut aroon = AroonOscillator::new(10);
+        aroon.update_raw(110.08, 109.61);
+
+        assert_eq!(aroon.aroon_up, 100.0);
+        assert_eq!(aroon.aroon_down, 100.0);
+        assert_eq!(aroon.value, 0.0);
+    }
+
+    #[test]
+    fn test_aroon_value_with_twenty_inputs() {
+        let mut aroon = AroonOscillator::new(10);
+        for (high, low) in [
+            (110.08, 109.61),
+            (110.15, 109.91),
+            (110.1, 109.73),
+            (110.06, 109.77),
+            (110.29, 109.88),
+            (110.53, 110.29),
+            (110.61, 110.26),
+            (110.28, 110.17),
+            (110.3, 110.0),
+            (110.25, 110.01),
+            (110.25, 109.81),
+            (109.92, 109.71),
+            (110.21, 109.84),
+            (110.08, 109.95),
+            (110.2, 109.96),
+            (110.16, 109.95),
+            (109.99, 109.75),
+            (110.2, 109.73),
+            (110.1, 109.81),
+            (110.04, 109.96),
+        ] {
+            aroon.update_raw(high, low);
+        }
+
+        assert_eq!(aroon.aroon_up, 9.999_999_999_999_998);
+        assert_eq!(aroon.aroon_down, 19.999_999_999_999_996);
+        assert_eq!(aroon.value, -9.999_999_999_999_998);
+    }
+
+    #[test]
+    fn test_aroon_reset() {
+        let mut aroon = AroonOscillator::new(10);
+        aroon.update_raw(110.08, 109.61);
+
+        aroon.reset();
+
+        assert_eq!(aroon.aroon_up, 0.0);
+        assert_eq!(aroon.aroon_down, 0.0);
+        assert_eq!(aroon.value, 0.0);
+        assert!(!aroon.has_inputs());
+    }
+}
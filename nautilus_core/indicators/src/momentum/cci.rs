@@ -0,0 +1,238 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_core::stats::mad_with_mean;
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// An indicator which calculates the Commodity Channel Index (CCI), measuring
+/// the difference between the current typical price and its moving average
+/// scaled by the mean absolute deviation.
+///
+/// Quote ticks are handled as (ask, bid, mid) and trade ticks as a zero-range
+/// (price, price, price) update.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct CommodityChannelIndex {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub scalar: f64,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub value: f64,
+    prices: VecDeque<f64>,
+    ma: Box<dyn MovingAverage + Send + Sync>,
+    mad: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for CommodityChannelIndex {
+    fn name(&self) -> String {
+        stringify!(CommodityChannelIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.prices.clear();
+        self.ma.reset();
+        self.mad = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl CommodityChannelIndex {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, scalar: Option<f64>, ma_type: Option<MovingAverageType>) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+
+        Self {
+            period,
+            scalar: scalar.unwrap_or(0.015),
+            ma_type,
+            value: 0.0,
+            prices: VecDeque::with_capacity(period),
+            ma: MovingAverageFactory::create(period, ma_type),
+            mad: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical_price = (high + low + close) / 3.0;
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(typical_price);
+        self.ma.update_raw(typical_price);
+        self.mad = mad_with_mean(self.prices.make_contiguous(), self.ma.value());
+
+        if self.ma.is_initialized() {
+            self.value = (typical_price - self.ma.value()) / (self.scalar * self.mad);
+        }
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.ma.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cci_initialized() {
+        let cci = CommodityChannelIndex::new(10, None, None);
+
+        assert_eq!(cci.name(), "CommodityChannelIndex");
+        assert_eq!(cci.scalar, 0.015);
+        assert_eq!(cci.mad, 0.0);
+        assert_eq!(cci.value, 0.0);
+        assert!(!cci.is_initialized());
+    }
+
+    #[test]
+    fn test_cci_value_with_one_input() {
+        let mut cci = CommodityChannelIndex::new(10, None, None);
+        cci.update_raw(0.18000, 0.01001, 0.13810);
+
+        assert_eq!(cci.mad, 0.0);
+        assert_eq!(cci.value, 0.0);
+    }
+
+    #[test]
+    fn test_cci_value_with_ten_inputs() {
+        let mut cci = CommodityChannelIndex::new(10, None, None);
+        cci.update_raw(0.18000, 0.01001, 0.13810);
+        cci.update_raw(0.14499, 0.136, 0.14131);
+        cci.update_raw(0.155, 0.13945, 0.15);
+        cci.update_raw(0.17, 0.1468, 0.15829);
+        cci.update_raw(0.172, 0.15712, 0.15938);
+        cci.update_raw(0.15937, 0.14352, 0.14564);
+        cci.update_raw(0.15171, 0.14571, 0.148);
+        cci.update_raw(0.15699, 0.148, 0.15456);
+        cci.update_raw(0.15547, 0.14894, 0.15029);
+        cci.update_raw(0.15199, 0.14908, 0.15181);
+
+        assert!(cci.is_initialized());
+        assert!((cci.mad - 0.008_899_733_333_333_352).abs() < 1e-12);
+        assert!((cci.value - 27.284_213_259_823_147).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cci_reset() {
+        let mut cci = CommodityChannelIndex::new(10, None, None);
+        cci.update_raw(0.18000, 0.01001, 0.13810);
+
+        cci.reset();
+
+        assert_eq!(cci.mad, 0.0);
+        assert_eq!(cci.value, 0.0);
+        assert!(!cci.has_inputs());
+    }
+}
@@ -0,0 +1,260 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// An indicator which calculates the Chande Momentum Oscillator (CMO) across a
+/// rolling window of average gains and losses.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct ChandeMomentumOscillator {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub value: f64,
+    average_gain: Box<dyn MovingAverage + Send + Sync>,
+    average_loss: Box<dyn MovingAverage + Send + Sync>,
+    previous_close: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for ChandeMomentumOscillator {
+    fn name(&self) -> String {
+        stringify!(ChandeMomentumOscillator).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(PriceType::Mid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.average_gain.reset();
+        self.average_loss.reset();
+        self.previous_close = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl ChandeMomentumOscillator {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Wilder);
+
+        Self {
+            period,
+            ma_type,
+            value: 0.0,
+            average_gain: MovingAverageFactory::create(period, ma_type),
+            average_loss: MovingAverageFactory::create(period, ma_type),
+            previous_close: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        // Check if first input
+        if !self._has_inputs {
+            self._has_inputs = true;
+            self.previous_close = close;
+        }
+
+        let gain = close - self.previous_close;
+        if gain > 0.0 {
+            self.average_gain.update_raw(gain);
+            self.average_loss.update_raw(0.0);
+        } else if gain < 0.0 {
+            self.average_gain.update_raw(0.0);
+            self.average_loss.update_raw(-gain);
+        } else {
+            self.average_gain.update_raw(0.0);
+            self.average_loss.update_raw(0.0);
+        }
+
+        // Initialization logic
+        if !self._is_initialized
+            && self.average_gain.is_initialized()
+            && self.average_loss.is_initialized()
+        {
+            self._is_initialized = true;
+        }
+
+        if self._is_initialized {
+            let gain = self.average_gain.value();
+            let loss = self.average_loss.value();
+            // Flat inputs have no gains or losses
+            self.value = if gain + loss == 0.0 {
+                0.0
+            } else {
+                100.0 * (gain - loss) / (gain + loss)
+            };
+        }
+
+        self.previous_close = close;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmo_initialized() {
+        let cmo = ChandeMomentumOscillator::new(10, None);
+
+        assert_eq!(cmo.name(), "ChandeMomentumOscillator");
+        assert_eq!(cmo.ma_type, MovingAverageType::Wilder);
+        assert_eq!(cmo.value, 0.0);
+        assert!(!cmo.is_initialized());
+    }
+
+    #[test]
+    fn test_cmo_rising_inputs() {
+        let mut cmo = ChandeMomentumOscillator::new(3, Some(MovingAverageType::Simple));
+        for close in [1.0, 2.0, 3.0, 4.0] {
+            cmo.update_raw(close);
+        }
+
+        assert!(cmo.is_initialized());
+        assert_eq!(cmo.value, 100.0);
+    }
+
+    #[test]
+    fn test_cmo_mixed_inputs() {
+        let mut cmo = ChandeMomentumOscillator::new(3, Some(MovingAverageType::Simple));
+        for close in [1.0, 3.0, 2.0] {
+            cmo.update_raw(close);
+        }
+
+        // Average gain = 2 / 3, average loss = 1 / 3
+        assert!((cmo.value - 100.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cmo_value_with_various_inputs() {
+        let mut cmo = ChandeMomentumOscillator::new(10, None);
+        for close in [
+            109.93, 110.0, 109.77, 109.96, 110.29, 110.53, 110.27, 110.21, 110.06, 110.19, 109.83,
+            109.9, 110.0, 110.03, 110.13, 109.95, 109.75, 110.15, 109.9, 110.04,
+        ] {
+            cmo.update_raw(close);
+        }
+
+        assert!((cmo.value - 2.089_629_456_238_705_4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cmo_flat_inputs() {
+        let mut cmo = ChandeMomentumOscillator::new(3, None);
+        for _ in 0..5 {
+            cmo.update_raw(1.0);
+        }
+
+        assert!(cmo.is_initialized());
+        assert_eq!(cmo.value, 0.0);
+    }
+
+    #[test]
+    fn test_cmo_reset() {
+        let mut cmo = ChandeMomentumOscillator::new(3, None);
+        cmo.update_raw(1.0);
+        cmo.update_raw(2.0);
+
+        cmo.reset();
+
+        assert_eq!(cmo.value, 0.0);
+        assert!(!cmo.has_inputs());
+        assert!(!cmo.is_initialized());
+    }
+}
@@ -0,0 +1,309 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator, MultiOutputIndicator,
+};
+
+/// An indicator which calculates the difference between two moving averages.
+/// Different moving average types can be selected for the inner calculation.
+///
+/// The `signal` line is a moving average of `value` with `signal_period`, which
+/// starts updating once the indicator is initialized. The `histogram` is the
+/// difference between `value` and `signal`.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct MovingAverageConvergenceDivergence {
+    #[pyo3(get)]
+    pub fast_period: usize,
+    #[pyo3(get)]
+    pub slow_period: usize,
+    #[pyo3(get)]
+    pub signal_period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub price_type: PriceType,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub signal: f64,
+    #[pyo3(get)]
+    pub histogram: f64,
+    fast_ma: Box<dyn MovingAverage + Send + Sync>,
+    slow_ma: Box<dyn MovingAverage + Send + Sync>,
+    signal_ma: Box<dyn MovingAverage + Send + Sync>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for MovingAverageConvergenceDivergence {
+    fn name(&self) -> String {
+        stringify!(MovingAverageConvergenceDivergence).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(self.price_type).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.signal = 0.0;
+        self.histogram = 0.0;
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MultiOutputIndicator for MovingAverageConvergenceDivergence {
+    fn output_names(&self) -> &'static [&'static str] {
+        &["value", "signal", "histogram"]
+    }
+
+    fn outputs(&self) -> Vec<f64> {
+        vec![self.value, self.signal, self.histogram]
+    }
+}
+
+#[pymethods]
+impl MovingAverageConvergenceDivergence {
+    /// # Panics
+    ///
+    /// - If `fast_period`, `slow_period` or `signal_period` is not positive (> 0).
+    /// - If `slow_period` is not greater than `fast_period`.
+    #[must_use]
+    #[new]
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        ma_type: Option<MovingAverageType>,
+        price_type: Option<PriceType>,
+        signal_period: Option<usize>,
+    ) -> Self {
+        if slow_period <= fast_period {
+            panic!("invalid `slow_period` for `fast_period` {fast_period}, was {slow_period}");
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+        let signal_period = signal_period.unwrap_or(9);
+
+        Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            ma_type,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            signal: 0.0,
+            histogram: 0.0,
+            fast_ma: MovingAverageFactory::create(fast_period, ma_type),
+            slow_ma: MovingAverageFactory::create(slow_period, ma_type),
+            signal_ma: MovingAverageFactory::create(signal_period, ma_type),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "output_names")]
+    fn output_names_py(&self) -> Vec<&'static str> {
+        self.output_names().to_vec()
+    }
+
+    #[pyo3(name = "outputs")]
+    fn outputs_py(&self) -> HashMap<&'static str, f64> {
+        self.output_map()
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        self.fast_ma.update_raw(close);
+        self.slow_ma.update_raw(close);
+        self.value = self.fast_ma.value() - self.slow_ma.value();
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.fast_ma.is_initialized() && self.slow_ma.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+
+        if self._is_initialized {
+            self.signal_ma.update_raw(self.value);
+            self.signal = self.signal_ma.value();
+            self.histogram = self.value - self.signal;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macd_initialized() {
+        let macd = MovingAverageConvergenceDivergence::new(3, 10, None, None, None);
+
+        assert_eq!(macd.name(), "MovingAverageConvergenceDivergence");
+        assert_eq!(macd.ma_type, MovingAverageType::Exponential);
+        assert_eq!(macd.price_type, PriceType::Last);
+        assert!(!macd.is_initialized());
+    }
+
+    #[test]
+    fn test_macd_value_with_one_input() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 10, None, None, None);
+        macd.update_raw(1.0);
+
+        assert!(macd.has_inputs());
+        assert_eq!(macd.value, 0.0);
+    }
+
+    #[test]
+    fn test_macd_value_with_three_inputs() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 10, None, None, None);
+        for close in [1.0, 2.0, 3.0] {
+            macd.update_raw(close);
+        }
+
+        assert!((macd.value - 0.737_603_305_785_124_3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_macd_value_with_more_inputs() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 10, None, None, None);
+        for i in 1..=16 {
+            macd.update_raw(f64::from(i));
+        }
+
+        assert!(macd.is_initialized());
+        assert!((macd.value - 3.278_231_367_312_290_7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_macd_signal_and_histogram() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 10, None, None, Some(3));
+        for i in 1..=9 {
+            macd.update_raw(f64::from(i));
+        }
+
+        // Signal only updates once initialized
+        assert_eq!(macd.signal, 0.0);
+
+        for i in 10..=16 {
+            macd.update_raw(f64::from(i));
+        }
+
+        assert_eq!(macd.signal_period, 3);
+        assert!(macd.signal > 0.0);
+        assert_eq!(macd.histogram, macd.value - macd.signal);
+        assert_eq!(macd.output_names(), &["value", "signal", "histogram"]);
+        assert_eq!(
+            macd.outputs(),
+            vec![macd.value, macd.signal, macd.histogram]
+        );
+        assert_eq!(macd.output("signal"), Some(macd.signal));
+        assert_eq!(macd.output("unknown"), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_macd_with_slow_period_not_greater_than_fast() {
+        let _ = MovingAverageConvergenceDivergence::new(10, 10, None, None, None);
+    }
+
+    #[test]
+    fn test_macd_reset() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 10, None, None, None);
+        macd.update_raw(1.00020);
+        macd.update_raw(1.00030);
+
+        macd.reset();
+
+        assert_eq!(macd.value, 0.0);
+        assert!(!macd.has_inputs());
+        assert!(!macd.is_initialized());
+    }
+}
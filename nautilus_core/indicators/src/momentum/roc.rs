@@ -0,0 +1,243 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::Indicator;
+
+/// An indicator which calculates the rate of change of price over a defined
+/// period. The return output can be simple or log.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct RateOfChange {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub use_log: bool,
+    #[pyo3(get)]
+    pub value: f64,
+    prices: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for RateOfChange {
+    fn name(&self) -> String {
+        stringify!(RateOfChange).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(PriceType::Mid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.prices.clear();
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl RateOfChange {
+    /// # Panics
+    ///
+    /// - If `period` is not greater than 1.
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, use_log: Option<bool>) -> Self {
+        if period <= 1 {
+            panic!("invalid `period` for `RateOfChange`, was {period} (must be > 1)");
+        }
+
+        Self {
+            period,
+            use_log: use_log.unwrap_or(false),
+            value: 0.0,
+            prices: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, price: f64) {
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(price);
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.prices.len() >= self.period {
+                self._is_initialized = true;
+            }
+        }
+
+        let first = self.prices[0];
+        self.value = if self.use_log {
+            (price / first).ln()
+        } else {
+            (price - first) / first
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOISY_INPUTS: [f64; 7] = [
+        1.00000, 1.00010, 1.00008, 1.00007, 1.00012, 1.00005, 1.00015,
+    ];
+
+    #[test]
+    fn test_roc_initialized() {
+        let roc = RateOfChange::new(3, None);
+
+        assert_eq!(roc.name(), "RateOfChange");
+        assert_eq!(roc.period, 3);
+        assert!(!roc.use_log);
+        assert!(!roc.is_initialized());
+    }
+
+    #[test]
+    fn test_roc_value_with_one_input() {
+        let mut roc = RateOfChange::new(3, None);
+        roc.update_raw(1.0);
+
+        assert!(roc.has_inputs());
+        assert_eq!(roc.value, 0.0);
+    }
+
+    #[test]
+    fn test_roc_value_with_efficient_higher_inputs() {
+        let mut roc = RateOfChange::new(3, None);
+        let mut price = 1.0;
+        for _ in 0..10 {
+            price += 0.1;
+            roc.update_raw(price);
+        }
+
+        assert!(roc.is_initialized());
+        assert!((roc.value - 0.111_111_111_111_111_16).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_roc_value_with_noisy_inputs() {
+        let mut roc = RateOfChange::new(3, None);
+        for price in NOISY_INPUTS {
+            roc.update_raw(price);
+        }
+
+        assert!((roc.value - 2.999_640_043_214_468_3e-05).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_roc_log_value_with_noisy_inputs() {
+        let mut roc = RateOfChange::new(3, Some(true));
+        for price in NOISY_INPUTS {
+            roc.update_raw(price);
+        }
+
+        assert!((roc.value - 2.999_595_054_919_663e-05).abs() < 1e-15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_roc_with_period_of_one() {
+        let _ = RateOfChange::new(1, None);
+    }
+
+    #[test]
+    fn test_roc_reset() {
+        let mut roc = RateOfChange::new(3, None);
+        for _ in 0..3 {
+            roc.update_raw(1.0);
+        }
+
+        roc.reset();
+
+        assert_eq!(roc.value, 0.0);
+        assert!(!roc.has_inputs());
+        assert!(!roc.is_initialized());
+    }
+}
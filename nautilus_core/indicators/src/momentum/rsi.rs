@@ -0,0 +1,263 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// An indicator which calculates a relative strength index (RSI) across a
+/// rolling window, normalized to the range [0, 1].
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct RelativeStrengthIndex {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub value: f64,
+    rsi_max: f64,
+    average_gain: Box<dyn MovingAverage + Send + Sync>,
+    average_loss: Box<dyn MovingAverage + Send + Sync>,
+    last_value: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for RelativeStrengthIndex {
+    fn name(&self) -> String {
+        stringify!(RelativeStrengthIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(PriceType::Mid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.average_gain.reset();
+        self.average_loss.reset();
+        self.last_value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl RelativeStrengthIndex {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Self {
+            period,
+            ma_type,
+            value: 0.0,
+            rsi_max: 1.0,
+            average_gain: MovingAverageFactory::create(period, ma_type),
+            average_loss: MovingAverageFactory::create(period, ma_type),
+            last_value: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        // Check if first input
+        if !self._has_inputs {
+            self.last_value = value;
+            self._has_inputs = true;
+        }
+
+        let gain = value - self.last_value;
+        if gain > 0.0 {
+            self.average_gain.update_raw(gain);
+            self.average_loss.update_raw(0.0);
+        } else if gain < 0.0 {
+            self.average_loss.update_raw(-gain);
+            self.average_gain.update_raw(0.0);
+        } else {
+            self.average_gain.update_raw(0.0);
+            self.average_loss.update_raw(0.0);
+        }
+
+        // Initialization logic
+        if !self._is_initialized
+            && self.average_gain.is_initialized()
+            && self.average_loss.is_initialized()
+        {
+            self._is_initialized = true;
+        }
+
+        if self.average_loss.value() == 0.0 {
+            self.value = self.rsi_max;
+            return;
+        }
+
+        let rs = self.average_gain.value() / self.average_loss.value();
+        self.value = self.rsi_max - (self.rsi_max / (1.0 + rs));
+        self.last_value = value;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsi_initialized() {
+        let rsi = RelativeStrengthIndex::new(10, None);
+
+        assert_eq!(rsi.name(), "RelativeStrengthIndex");
+        assert_eq!(rsi.ma_type, MovingAverageType::Exponential);
+        assert!(!rsi.has_inputs());
+        assert!(!rsi.is_initialized());
+    }
+
+    #[test]
+    fn test_rsi_initialized_with_required_inputs() {
+        let mut rsi = RelativeStrengthIndex::new(10, None);
+        for i in 1..=10 {
+            rsi.update_raw(f64::from(i));
+        }
+
+        assert!(rsi.is_initialized());
+    }
+
+    #[test]
+    fn test_rsi_value_with_all_higher_inputs() {
+        let mut rsi = RelativeStrengthIndex::new(10, None);
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            rsi.update_raw(value);
+        }
+
+        assert_eq!(rsi.value, 1.0);
+    }
+
+    #[test]
+    fn test_rsi_value_with_all_lower_inputs() {
+        let mut rsi = RelativeStrengthIndex::new(10, None);
+        for value in [3.0, 2.0, 1.0, 0.5] {
+            rsi.update_raw(value);
+        }
+
+        assert_eq!(rsi.value, 0.0);
+    }
+
+    #[test]
+    fn test_rsi_value_with_various_inputs() {
+        let mut rsi = RelativeStrengthIndex::new(10, None);
+        for value in [3.0, 2.0, 5.0, 6.0, 7.0, 6.0] {
+            rsi.update_raw(value);
+        }
+
+        assert!((rsi.value - 0.683_736_332_582_526_5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rsi_value_with_more_various_inputs() {
+        let mut rsi = RelativeStrengthIndex::new(10, None);
+        for value in [3.0, 2.0, 5.0, 6.0, 7.0, 6.0, 6.0, 7.0] {
+            rsi.update_raw(value);
+        }
+
+        assert!((rsi.value - 0.761_534_466_766_272_5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rsi_reset() {
+        let mut rsi = RelativeStrengthIndex::new(10, None);
+        rsi.update_raw(1.00020);
+        rsi.update_raw(1.00030);
+        rsi.update_raw(1.00050);
+
+        rsi.reset();
+
+        assert_eq!(rsi.value, 0.0);
+        assert!(!rsi.has_inputs());
+        assert!(!rsi.is_initialized());
+    }
+}
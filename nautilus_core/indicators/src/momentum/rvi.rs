@@ -0,0 +1,269 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_core::stats::std_with_mean;
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// The Relative Volatility Index (RVI) was created in 1993 and revised in 1995.
+/// Instead of adding up price changes like RSI based on price direction, the RVI
+/// adds up standard deviations based on price direction.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct RelativeVolatilityIndex {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub scalar: f64,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub value: f64,
+    prices: VecDeque<f64>,
+    ma: Box<dyn MovingAverage + Send + Sync>,
+    pos_ma: Box<dyn MovingAverage + Send + Sync>,
+    neg_ma: Box<dyn MovingAverage + Send + Sync>,
+    previous_close: f64,
+    std: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for RelativeVolatilityIndex {
+    fn name(&self) -> String {
+        stringify!(RelativeVolatilityIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(PriceType::Mid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.prices.clear();
+        self.ma.reset();
+        self.pos_ma.reset();
+        self.neg_ma.reset();
+        self.previous_close = 0.0;
+        self.std = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl RelativeVolatilityIndex {
+    /// # Panics
+    ///
+    /// - If `period` is less than 2.
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, scalar: Option<f64>, ma_type: Option<MovingAverageType>) -> Self {
+        // The sample standard deviation requires at least two prices
+        if period < 2 {
+            panic!("invalid `period` for `RelativeVolatilityIndex`, was {period} (must be >= 2)");
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Self {
+            period,
+            scalar: scalar.unwrap_or(100.0),
+            ma_type,
+            value: 0.0,
+            prices: VecDeque::with_capacity(period),
+            ma: MovingAverageFactory::create(period, MovingAverageType::Simple),
+            pos_ma: MovingAverageFactory::create(period, ma_type),
+            neg_ma: MovingAverageFactory::create(period, ma_type),
+            previous_close: 0.0,
+            std: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(close);
+        self.ma.update_raw(close);
+
+        // Sample standard deviation of the window
+        let period = self.period as f64;
+        self.std = std_with_mean(self.prices.make_contiguous(), self.ma.value());
+        self.std = self.std * period.sqrt() / (period - 1.0).sqrt();
+
+        if self.ma.is_initialized() {
+            if close > self.previous_close {
+                self.pos_ma.update_raw(self.std);
+                self.neg_ma.update_raw(0.0);
+            } else if close < self.previous_close {
+                self.pos_ma.update_raw(0.0);
+                self.neg_ma.update_raw(self.std);
+            } else {
+                self.pos_ma.update_raw(0.0);
+                self.neg_ma.update_raw(0.0);
+            }
+
+            self.value = self.scalar * self.pos_ma.value();
+            self.value /= self.pos_ma.value() + self.neg_ma.value();
+        }
+
+        self.previous_close = close;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.pos_ma.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rvi_initialized() {
+        let rvi = RelativeVolatilityIndex::new(10, None, None);
+
+        assert_eq!(rvi.name(), "RelativeVolatilityIndex");
+        assert_eq!(rvi.scalar, 100.0);
+        assert_eq!(rvi.ma_type, MovingAverageType::Exponential);
+        assert!(!rvi.is_initialized());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rvi_with_period_one() {
+        let _ = RelativeVolatilityIndex::new(1, None, None);
+    }
+
+    #[test]
+    fn test_rvi_initialized_with_required_inputs() {
+        let mut rvi = RelativeVolatilityIndex::new(10, None, None);
+        for i in 0..20 {
+            rvi.update_raw(f64::from(i));
+        }
+
+        assert!(rvi.is_initialized());
+    }
+
+    #[test]
+    fn test_rvi_value_with_one_input() {
+        let mut rvi = RelativeVolatilityIndex::new(10, None, None);
+        rvi.update_raw(1.0);
+
+        assert_eq!(rvi.value, 0.0);
+    }
+
+    #[test]
+    fn test_rvi_value_with_various_inputs() {
+        let mut rvi = RelativeVolatilityIndex::new(10, None, None);
+        for close in [
+            109.93, 110.0, 109.77, 109.96, 110.29, 110.53, 110.27, 110.21, 110.06, 110.19, 109.83,
+            109.9, 110.0, 110.03, 110.13, 109.95, 109.75, 110.15, 109.9, 110.04,
+        ] {
+            rvi.update_raw(close);
+        }
+
+        assert!((rvi.value - 67.244_601_813_744_5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rvi_reset() {
+        let mut rvi = RelativeVolatilityIndex::new(10, None, None);
+        rvi.update_raw(1.00020);
+        rvi.update_raw(1.00030);
+
+        rvi.reset();
+
+        assert_eq!(rvi.value, 0.0);
+        assert!(!rvi.has_inputs());
+        assert!(!rvi.is_initialized());
+    }
+}
@@ -0,0 +1,294 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{Indicator, MultiOutputIndicator};
+
+/// An oscillator which can indicate when an asset may be over bought or over
+/// sold.
+///
+/// Quote ticks are handled as (ask, bid, mid) and trade ticks as a zero-range
+/// (price, price, price) update.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct Stochastics {
+    #[pyo3(get)]
+    pub period_k: usize,
+    #[pyo3(get)]
+    pub period_d: usize,
+    #[pyo3(get)]
+    pub value_k: f64,
+    #[pyo3(get)]
+    pub value_d: f64,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    c_sub_l: VecDeque<f64>,
+    h_sub_l: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for Stochastics {
+    fn name(&self) -> String {
+        stringify!(Stochastics).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+        self.c_sub_l.clear();
+        self.h_sub_l.clear();
+        self.value_k = 0.0;
+        self.value_d = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+/// Push `value` onto the back of `deque`, dropping the oldest value beyond `maxlen`.
+fn push_bounded(deque: &mut VecDeque<f64>, value: f64, maxlen: usize) {
+    if deque.len() == maxlen {
+        deque.pop_front();
+    }
+    deque.push_back(value);
+}
+
+impl MultiOutputIndicator for Stochastics {
+    fn output_names(&self) -> &'static [&'static str] {
+        &["value_k", "value_d"]
+    }
+
+    fn outputs(&self) -> Vec<f64> {
+        vec![self.value_k, self.value_d]
+    }
+}
+
+#[pymethods]
+impl Stochastics {
+    /// # Panics
+    ///
+    /// - If `period_k` or `period_d` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period_k: usize, period_d: usize) -> Self {
+        if period_k == 0 {
+            panic!("invalid `period_k` for `Stochastics`, was {period_k}");
+        }
+        if period_d == 0 {
+            panic!("invalid `period_d` for `Stochastics`, was {period_d}");
+        }
+
+        Self {
+            period_k,
+            period_d,
+            value_k: 0.0,
+            value_d: 0.0,
+            highs: VecDeque::with_capacity(period_k),
+            lows: VecDeque::with_capacity(period_k),
+            c_sub_l: VecDeque::with_capacity(period_d),
+            h_sub_l: VecDeque::with_capacity(period_d),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "output_names")]
+    fn output_names_py(&self) -> Vec<&'static str> {
+        self.output_names().to_vec()
+    }
+
+    #[pyo3(name = "outputs")]
+    fn outputs_py(&self) -> HashMap<&'static str, f64> {
+        self.output_map()
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        // Check if first input
+        if !self._has_inputs {
+            self._has_inputs = true;
+        }
+
+        push_bounded(&mut self.highs, high, self.period_k);
+        push_bounded(&mut self.lows, low, self.period_k);
+
+        // Initialization logic
+        if !self._is_initialized
+            && self.highs.len() == self.period_k
+            && self.lows.len() == self.period_k
+        {
+            self._is_initialized = true;
+        }
+
+        let k_max_high = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        let k_min_low = self.lows.iter().copied().fold(f64::MAX, f64::min);
+
+        push_bounded(&mut self.c_sub_l, close - k_min_low, self.period_d);
+        push_bounded(&mut self.h_sub_l, k_max_high - k_min_low, self.period_d);
+
+        if k_max_high == k_min_low {
+            return; // Divide by zero guard
+        }
+
+        self.value_k = 100.0 * ((close - k_min_low) / (k_max_high - k_min_low));
+        self.value_d =
+            100.0 * (self.c_sub_l.iter().sum::<f64>() / self.h_sub_l.iter().sum::<f64>());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stochastics_initialized() {
+        let stochastics = Stochastics::new(14, 3);
+
+        assert_eq!(stochastics.name(), "Stochastics");
+        assert_eq!(stochastics.period_k, 14);
+        assert_eq!(stochastics.period_d, 3);
+        assert!(!stochastics.is_initialized());
+    }
+
+    #[test]
+    fn test_stochastics_initialized_with_required_inputs() {
+        let mut stochastics = Stochastics::new(14, 3);
+        for _ in 0..14 {
+            stochastics.update_raw(1.00020, 1.00000, 1.00010);
+        }
+
+        assert!(stochastics.is_initialized());
+    }
+
+    #[test]
+    fn test_stochastics_values_with_one_input() {
+        let mut stochastics = Stochastics::new(14, 3);
+        stochastics.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert!((stochastics.value_k - 50.0).abs() < 1e-9);
+        assert!((stochastics.value_d - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stochastics_values_with_all_higher_inputs() {
+        let mut stochastics = Stochastics::new(14, 3);
+        stochastics.update_raw(1.00020, 1.00000, 1.00010);
+        stochastics.update_raw(1.00030, 1.00010, 1.00020);
+        stochastics.update_raw(1.00040, 1.00020, 1.00030);
+        stochastics.update_raw(1.00050, 1.00030, 1.00040);
+
+        assert!((stochastics.value_k - 80.0).abs() < 1e-9);
+        assert!((stochastics.value_d - 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stochastics_values_with_all_lower_inputs() {
+        let mut stochastics = Stochastics::new(14, 3);
+        stochastics.update_raw(1.00050, 1.00030, 1.00040);
+        stochastics.update_raw(1.00040, 1.00020, 1.00030);
+        stochastics.update_raw(1.00030, 1.00010, 1.00020);
+        stochastics.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert!((stochastics.value_k - 20.0).abs() < 1e-9);
+        assert!((stochastics.value_d - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stochastics_reset() {
+        let mut stochastics = Stochastics::new(14, 3);
+        stochastics.update_raw(1.00050, 1.00030, 1.00040);
+
+        stochastics.reset();
+
+        assert_eq!(stochastics.value_k, 0.0);
+        assert_eq!(stochastics.value_d, 0.0);
+        assert!(!stochastics.has_inputs());
+    }
+}
@@ -0,0 +1,228 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::Indicator;
+
+/// An indicator which calculates the efficiency ratio across a rolling window.
+/// The Kaufman Efficiency measures the ratio of the relative market speed in
+/// relation to the volatility, this could be thought of as a proxy for noise.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct EfficiencyRatio {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub value: f64,
+    inputs: VecDeque<f64>,
+    deltas: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for EfficiencyRatio {
+    fn name(&self) -> String {
+        stringify!(EfficiencyRatio).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(PriceType::Mid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.inputs.clear();
+        self.deltas.clear();
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl EfficiencyRatio {
+    /// # Panics
+    ///
+    /// - If `period` is less than 2.
+    #[must_use]
+    #[new]
+    pub fn new(period: usize) -> Self {
+        if period < 2 {
+            panic!("invalid `period` for `EfficiencyRatio`, was {period} (must be >= 2)");
+        }
+
+        Self {
+            period,
+            value: 0.0,
+            inputs: VecDeque::with_capacity(period),
+            deltas: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, price: f64) {
+        if self.inputs.len() == self.period {
+            self.inputs.pop_front();
+        }
+        self.inputs.push_back(price);
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.inputs.len() < 2 {
+                return; // Not enough data
+            } else if self.inputs.len() >= self.period {
+                self._is_initialized = true;
+            }
+        }
+
+        let last = self.inputs[self.inputs.len() - 1];
+        let prior = self.inputs[self.inputs.len() - 2];
+        if self.deltas.len() == self.period {
+            self.deltas.pop_front();
+        }
+        self.deltas.push_back((last - prior).abs());
+
+        let net_diff = (self.inputs[0] - last).abs();
+        let sum_deltas: f64 = self.deltas.iter().sum();
+        self.value = if sum_deltas > 0.0 {
+            net_diff / sum_deltas
+        } else {
+            0.0
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_efficiency_ratio_initialized() {
+        let er = EfficiencyRatio::new(10);
+
+        assert_eq!(er.name(), "EfficiencyRatio");
+        assert_eq!(er.value, 0.0);
+        assert!(!er.has_inputs());
+        assert!(!er.is_initialized());
+    }
+
+    #[test]
+    fn test_efficiency_ratio_trending_inputs() {
+        let mut er = EfficiencyRatio::new(3);
+        for price in [1.0, 2.0, 3.0] {
+            er.update_raw(price);
+        }
+
+        assert!(er.is_initialized());
+        assert_eq!(er.value, 1.0);
+    }
+
+    #[test]
+    fn test_efficiency_ratio_oscillating_inputs() {
+        let mut er = EfficiencyRatio::new(3);
+        for price in [1.0, 2.0, 1.0] {
+            er.update_raw(price);
+        }
+
+        assert_eq!(er.value, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_efficiency_ratio_with_period_less_than_two() {
+        let _ = EfficiencyRatio::new(1);
+    }
+
+    #[test]
+    fn test_efficiency_ratio_reset() {
+        let mut er = EfficiencyRatio::new(3);
+        er.update_raw(1.0);
+        er.update_raw(2.0);
+
+        er.reset();
+
+        assert_eq!(er.value, 0.0);
+        assert!(!er.has_inputs());
+        assert!(!er.is_initialized());
+    }
+}
@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// An indicator which calculates the average true range across a rolling
+/// window. Different moving average types can be selected for the inner
+/// calculation.
+///
+/// Quote ticks are handled as (ask, bid, mid) and trade ticks as a zero-range
+/// (price, price, price) update.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct AverageTrueRange {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub use_previous: bool,
+    #[pyo3(get)]
+    pub value_floor: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    ma: Box<dyn MovingAverage + Send + Sync>,
+    previous_close: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for AverageTrueRange {
+    fn name(&self) -> String {
+        stringify!(AverageTrueRange).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.previous_close = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl AverageTrueRange {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `value_floor` is negative.
+    #[must_use]
+    #[new]
+    pub fn new(
+        period: usize,
+        ma_type: Option<MovingAverageType>,
+        use_previous: Option<bool>,
+        value_floor: Option<f64>,
+    ) -> Self {
+        let value_floor = value_floor.unwrap_or(0.0);
+        if value_floor < 0.0 {
+            panic!("invalid `value_floor` for `AverageTrueRange`, was {value_floor}");
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+
+        Self {
+            period,
+            ma_type,
+            use_previous: use_previous.unwrap_or(true),
+            value_floor,
+            value: 0.0,
+            ma: MovingAverageFactory::create(period, ma_type),
+            previous_close: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if self.use_previous {
+            if !self._has_inputs {
+                self.previous_close = close;
+            }
+            self.ma
+                .update_raw(self.previous_close.max(high) - low.min(self.previous_close));
+            self.previous_close = close;
+        } else {
+            self.ma.update_raw(high - low);
+        }
+
+        // Floor the value
+        self.value = if self.value_floor == 0.0 || self.value_floor < self.ma.value() {
+            self.ma.value()
+        } else {
+            self.value_floor
+        };
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.ma.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atr_initialized() {
+        let atr = AverageTrueRange::new(10, None, None, None);
+
+        assert_eq!(atr.name(), "AverageTrueRange");
+        assert_eq!(atr.ma_type, MovingAverageType::Simple);
+        assert!(atr.use_previous);
+        assert_eq!(atr.value_floor, 0.0);
+        assert!(!atr.is_initialized());
+    }
+
+    #[test]
+    fn test_atr_initialized_with_required_inputs() {
+        let mut atr = AverageTrueRange::new(10, None, None, None);
+        for _ in 0..10 {
+            atr.update_raw(1.0, 1.0, 1.0);
+        }
+
+        assert!(atr.is_initialized());
+    }
+
+    #[test]
+    fn test_atr_value_with_one_input() {
+        let mut atr = AverageTrueRange::new(10, None, None, None);
+        atr.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert!((atr.value - 0.00020).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_atr_value_with_close_on_high() {
+        let mut atr = AverageTrueRange::new(10, None, None, None);
+        let mut high = 1.00010;
+        let mut low = 1.00000;
+        for _ in 0..1000 {
+            high += 0.00010;
+            low += 0.00010;
+            atr.update_raw(high, low, high);
+        }
+
+        assert!((atr.value - 0.00010).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_atr_floored_value() {
+        let mut atr = AverageTrueRange::new(10, None, None, Some(0.00005));
+        for _ in 0..20 {
+            atr.update_raw(1.0, 1.0, 1.0);
+        }
+
+        assert_eq!(atr.value, 0.00005);
+    }
+
+    #[test]
+    fn test_atr_without_previous_close() {
+        let mut atr = AverageTrueRange::new(2, None, Some(false), None);
+        atr.update_raw(1.00020, 1.00000, 1.00010);
+        atr.update_raw(1.00050, 1.00040, 1.00045);
+
+        // (0.00020 + 0.00010) / 2, ignoring the gap between bars
+        assert!((atr.value - 0.00015).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_atr_with_negative_value_floor() {
+        let _ = AverageTrueRange::new(10, None, None, Some(-1.0));
+    }
+
+    #[test]
+    fn test_atr_reset() {
+        let mut atr = AverageTrueRange::new(10, None, None, None);
+        for _ in 0..1000 {
+            atr.update_raw(1.00010, 1.00000, 1.00005);
+        }
+
+        atr.reset();
+
+        assert_eq!(atr.value, 0.0);
+        assert!(!atr.has_inputs());
+        assert!(!atr.is_initialized());
+    }
+}
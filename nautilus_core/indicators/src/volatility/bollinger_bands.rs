@@ -0,0 +1,297 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_core::stats::std_with_mean;
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator, MultiOutputIndicator,
+};
+
+/// A Bollinger Band® is a technical analysis tool defined by a set of
+/// trend lines plotted two standard deviations (positively and negatively) away
+/// from a simple moving average (SMA) of an instruments price, which can be
+/// adjusted to user preferences.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct BollingerBands {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub k: f64,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub upper: f64,
+    #[pyo3(get)]
+    pub middle: f64,
+    #[pyo3(get)]
+    pub lower: f64,
+    ma: Box<dyn MovingAverage + Send + Sync>,
+    prices: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for BollingerBands {
+    fn name(&self) -> String {
+        stringify!(BollingerBands).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.prices.clear();
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MultiOutputIndicator for BollingerBands {
+    fn output_names(&self) -> &'static [&'static str] {
+        &["upper", "middle", "lower"]
+    }
+
+    fn outputs(&self) -> Vec<f64> {
+        vec![self.upper, self.middle, self.lower]
+    }
+}
+
+#[pymethods]
+impl BollingerBands {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `k` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, k: f64, ma_type: Option<MovingAverageType>) -> Self {
+        if k <= 0.0 {
+            panic!("invalid `k` for `BollingerBands`, was {k}");
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+
+        Self {
+            period,
+            k,
+            ma_type,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            ma: MovingAverageFactory::create(period, ma_type),
+            prices: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "output_names")]
+    fn output_names_py(&self) -> Vec<&'static str> {
+        self.output_names().to_vec()
+    }
+
+    #[pyo3(name = "outputs")]
+    fn outputs_py(&self) -> HashMap<&'static str, f64> {
+        self.output_map()
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical = (high + low + close) / 3.0;
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(typical);
+        self.ma.update_raw(typical);
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.prices.len() >= self.period {
+                self._is_initialized = true;
+            }
+        }
+
+        let mean = self.ma.value();
+        let std = std_with_mean(self.prices.make_contiguous(), mean);
+
+        self.upper = self.k.mul_add(std, mean);
+        self.middle = mean;
+        self.lower = self.k.mul_add(-std, mean);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bollinger_bands_initialized() {
+        let bb = BollingerBands::new(20, 2.0, None);
+
+        assert_eq!(bb.name(), "BollingerBands");
+        assert_eq!(bb.period, 20);
+        assert_eq!(bb.k, 2.0);
+        assert_eq!(bb.upper, 0.0);
+        assert_eq!(bb.middle, 0.0);
+        assert_eq!(bb.lower, 0.0);
+        assert!(!bb.is_initialized());
+    }
+
+    #[test]
+    fn test_bollinger_bands_initialized_with_required_inputs() {
+        let mut bb = BollingerBands::new(5, 2.0, None);
+        for _ in 0..5 {
+            bb.update_raw(1.0, 1.0, 1.0);
+        }
+
+        assert!(bb.is_initialized());
+    }
+
+    #[test]
+    fn test_bollinger_bands_value_with_one_input() {
+        let mut bb = BollingerBands::new(20, 2.0, None);
+        bb.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert!((bb.upper - 1.00010).abs() < 1e-12);
+        assert!((bb.middle - 1.00010).abs() < 1e-12);
+        assert!((bb.lower - 1.00010).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bollinger_bands_value_with_three_inputs() {
+        let mut bb = BollingerBands::new(20, 2.0, None);
+        bb.update_raw(1.00020, 1.00000, 1.00015);
+        bb.update_raw(1.00030, 1.00010, 1.00015);
+        bb.update_raw(1.00040, 1.00020, 1.00021);
+
+        assert!((bb.upper - 1.000_315_550_639_038_4).abs() < 1e-12);
+        assert!((bb.middle - 1.000_190_000_000_000_1).abs() < 1e-12);
+        assert!((bb.lower - 1.000_064_449_360_961_8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bollinger_bands_outputs() {
+        let mut bb = BollingerBands::new(20, 2.0, None);
+        bb.update_raw(1.00020, 1.00000, 1.00015);
+        bb.update_raw(1.00030, 1.00010, 1.00015);
+
+        let outputs = bb.output_map();
+
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs["upper"], bb.upper);
+        assert_eq!(outputs["middle"], bb.middle);
+        assert_eq!(outputs["lower"], bb.lower);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bollinger_bands_with_non_positive_k() {
+        let _ = BollingerBands::new(20, 0.0, None);
+    }
+
+    #[test]
+    fn test_bollinger_bands_reset() {
+        let mut bb = BollingerBands::new(5, 2.0, None);
+        for _ in 0..5 {
+            bb.update_raw(1.0, 1.0, 1.0);
+        }
+
+        bb.reset();
+
+        assert_eq!(bb.upper, 0.0);
+        assert_eq!(bb.middle, 0.0);
+        assert_eq!(bb.lower, 0.0);
+        assert!(!bb.has_inputs());
+        assert!(!bb.is_initialized());
+    }
+}
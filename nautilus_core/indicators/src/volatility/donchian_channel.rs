@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::prelude::*;
+
+use crate::{Indicator, MultiOutputIndicator};
+
+/// Donchian Channels are three lines generated by moving average calculations
+/// that comprise an indicator formed by upper and lower bands around a
+/// mid-range or median band. The upper band marks the highest price of an
+/// instrument while the lower band marks the lowest price of an instrument,
+/// and the area between the upper and lower bands represents the Donchian
+/// Channel.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct DonchianChannel {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub upper: f64,
+    #[pyo3(get)]
+    pub middle: f64,
+    #[pyo3(get)]
+    pub lower: f64,
+    upper_prices: VecDeque<f64>,
+    lower_prices: VecDeque<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for DonchianChannel {
+    fn name(&self) -> String {
+        stringify!(DonchianChannel).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw((&tick.ask).into(), (&tick.bid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into())
+    }
+
+    fn reset(&mut self) {
+        self.upper_prices.clear();
+        self.lower_prices.clear();
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MultiOutputIndicator for DonchianChannel {
+    fn output_names(&self) -> &'static [&'static str] {
+        &["upper", "middle", "lower"]
+    }
+
+    fn outputs(&self) -> Vec<f64> {
+        vec![self.upper, self.middle, self.lower]
+    }
+}
+
+#[pymethods]
+impl DonchianChannel {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize) -> Self {
+        if period == 0 {
+            panic!("invalid `period` for `DonchianChannel`, was {period}");
+        }
+
+        Self {
+            period,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            upper_prices: VecDeque::with_capacity(period),
+            lower_prices: VecDeque::with_capacity(period),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "output_names")]
+    fn output_names_py(&self) -> Vec<&'static str> {
+        self.output_names().to_vec()
+    }
+
+    #[pyo3(name = "outputs")]
+    fn outputs_py(&self) -> HashMap<&'static str, f64> {
+        self.output_map()
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if self.upper_prices.len() == self.period {
+            self.upper_prices.pop_front();
+            self.lower_prices.pop_front();
+        }
+        self.upper_prices.push_back(high);
+        self.lower_prices.push_back(low);
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.upper_prices.len() >= self.period {
+                self._is_initialized = true;
+            }
+        }
+
+        self.upper = self.upper_prices.iter().copied().fold(f64::MIN, f64::max);
+        self.lower = self.lower_prices.iter().copied().fold(f64::MAX, f64::min);
+        self.middle = (self.upper + self.lower) / 2.0;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_donchian_channel_initialized() {
+        let dc = DonchianChannel::new(10);
+
+        assert_eq!(dc.name(), "DonchianChannel");
+        assert_eq!(dc.period, 10);
+        assert!(!dc.is_initialized());
+    }
+
+    #[test]
+    fn test_donchian_channel_initialized_with_required_inputs() {
+        let mut dc = DonchianChannel::new(10);
+        for _ in 0..10 {
+            dc.update_raw(1.0, 1.0);
+        }
+
+        assert!(dc.is_initialized());
+    }
+
+    #[test]
+    fn test_donchian_channel_value_with_one_input() {
+        let mut dc = DonchianChannel::new(10);
+        dc.update_raw(1.00020, 1.00000);
+
+        assert_eq!(dc.upper, 1.00020);
+        assert!((dc.middle - 1.00010).abs() < 1e-12);
+        assert_eq!(dc.lower, 1.00000);
+    }
+
+    #[test]
+    fn test_donchian_channel_value_with_three_inputs() {
+        let mut dc = DonchianChannel::new(10);
+        dc.update_raw(1.00020, 1.00000);
+        dc.update_raw(1.00030, 1.00010);
+        dc.update_raw(1.00040, 1.00020);
+
+        assert_eq!(dc.upper, 1.00040);
+        assert!((dc.middle - 1.00020).abs() < 1e-12);
+        assert_eq!(dc.lower, 1.00000);
+    }
+
+    #[test]
+    fn test_donchian_channel_rolls_window() {
+        let mut dc = DonchianChannel::new(2);
+        dc.update_raw(1.00040, 1.00000);
+        dc.update_raw(1.00030, 1.00010);
+        dc.update_raw(1.00020, 1.00015);
+
+        assert_eq!(dc.upper, 1.00030);
+        assert_eq!(dc.lower, 1.00010);
+    }
+
+    #[test]
+    fn test_donchian_channel_reset() {
+        let mut dc = DonchianChannel::new(10);
+        dc.update_raw(1.00020, 1.00000);
+
+        dc.reset();
+
+        assert_eq!(dc.upper, 0.0);
+        assert_eq!(dc.middle, 0.0);
+        assert_eq!(dc.lower, 0.0);
+        assert!(!dc.has_inputs());
+    }
+}
@@ -0,0 +1,287 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    volatility::atr::AverageTrueRange,
+    Indicator, MultiOutputIndicator,
+};
+
+/// The Keltner channel is a volatility based envelope set above and below a
+/// central moving average. Traditionally the middle band is an EMA based on the
+/// typical price (high + low + close) / 3, the upper band is the middle band
+/// plus the ATR. The lower band is the middle band minus the ATR.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct KeltnerChannel {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub k_multiplier: f64,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub upper: f64,
+    #[pyo3(get)]
+    pub middle: f64,
+    #[pyo3(get)]
+    pub lower: f64,
+    ma: Box<dyn MovingAverage + Send + Sync>,
+    atr: AverageTrueRange,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for KeltnerChannel {
+    fn name(&self) -> String {
+        stringify!(KeltnerChannel).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.atr.reset();
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl MultiOutputIndicator for KeltnerChannel {
+    fn output_names(&self) -> &'static [&'static str] {
+        &["upper", "middle", "lower"]
+    }
+
+    fn outputs(&self) -> Vec<f64> {
+        vec![self.upper, self.middle, self.lower]
+    }
+}
+
+#[pymethods]
+impl KeltnerChannel {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `k_multiplier` is not positive (> 0).
+    /// - If `atr_floor` is negative.
+    #[must_use]
+    #[new]
+    pub fn new(
+        period: usize,
+        k_multiplier: f64,
+        ma_type: Option<MovingAverageType>,
+        ma_type_atr: Option<MovingAverageType>,
+        use_previous: Option<bool>,
+        atr_floor: Option<f64>,
+    ) -> Self {
+        if k_multiplier <= 0.0 {
+            panic!("invalid `k_multiplier` for `KeltnerChannel`, was {k_multiplier}");
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+        let ma_type_atr = ma_type_atr.unwrap_or(MovingAverageType::Simple);
+
+        Self {
+            period,
+            k_multiplier,
+            ma_type,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            ma: MovingAverageFactory::create(period, ma_type),
+            atr: AverageTrueRange::new(period, Some(ma_type_atr), use_previous, atr_floor),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "output_names")]
+    fn output_names_py(&self) -> Vec<&'static str> {
+        self.output_names().to_vec()
+    }
+
+    #[pyo3(name = "outputs")]
+    fn outputs_py(&self) -> HashMap<&'static str, f64> {
+        self.output_map()
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical_price = (high + low + close) / 3.0;
+        self.ma.update_raw(typical_price);
+        self.atr.update_raw(high, low, close);
+
+        let middle = self.ma.value();
+        let width = self.atr.value * self.k_multiplier;
+        self.upper = middle + width;
+        self.middle = middle;
+        self.lower = middle - width;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.ma.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keltner_channel() -> KeltnerChannel {
+        KeltnerChannel::new(
+            10,
+            2.5,
+            Some(MovingAverageType::Exponential),
+            Some(MovingAverageType::Simple),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_keltner_channel_initialized() {
+        let kc = keltner_channel();
+
+        assert_eq!(kc.name(), "KeltnerChannel");
+        assert_eq!(kc.period, 10);
+        assert_eq!(kc.k_multiplier, 2.5);
+        assert!(!kc.is_initialized());
+    }
+
+    #[test]
+    fn test_keltner_channel_initialized_with_required_inputs() {
+        let mut kc = keltner_channel();
+        for _ in 0..10 {
+            kc.update_raw(1.00020, 1.00000, 1.00010);
+        }
+
+        assert!(kc.is_initialized());
+    }
+
+    #[test]
+    fn test_keltner_channel_value_with_one_input() {
+        let mut kc = keltner_channel();
+        kc.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert!((kc.upper - 1.0006).abs() < 1e-12);
+        assert!((kc.middle - 1.0001).abs() < 1e-12);
+        assert!((kc.lower - 0.9996).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_keltner_channel_value_with_three_inputs() {
+        let mut kc = keltner_channel();
+        kc.update_raw(1.00020, 1.00000, 1.00010);
+        kc.update_raw(1.00030, 1.00010, 1.00020);
+        kc.update_raw(1.00040, 1.00020, 1.00030);
+
+        assert!((kc.upper - 1.000_651_239_669_421_2).abs() < 1e-12);
+        assert!((kc.middle - 1.000_151_239_669_421_2).abs() < 1e-12);
+        assert!((kc.lower - 0.999_651_239_669_421_3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_keltner_channel_reset() {
+        let mut kc = keltner_channel();
+        kc.update_raw(1.00020, 1.00000, 1.00010);
+
+        kc.reset();
+
+        assert_eq!(kc.upper, 0.0);
+        assert_eq!(kc.middle, 0.0);
+        assert_eq!(kc.lower, 0.0);
+        assert!(!kc.has_inputs());
+    }
+}
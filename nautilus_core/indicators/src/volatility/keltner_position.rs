@@ -0,0 +1,249 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{average::MovingAverageType, volatility::keltner_channel::KeltnerChannel, Indicator};
+
+/// An indicator which calculates the relative position of the given price
+/// within a defined Keltner channel. This provides a measure of the relative
+/// 'extension' of a market from the mean, as a multiple of volatility.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct KeltnerPosition {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub k_multiplier: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    kc: KeltnerChannel,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for KeltnerPosition {
+    fn name(&self) -> String {
+        stringify!(KeltnerPosition).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.kc.reset();
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl KeltnerPosition {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `k_multiplier` is not positive (> 0).
+    /// - If `atr_floor` is negative.
+    #[must_use]
+    #[new]
+    pub fn new(
+        period: usize,
+        k_multiplier: f64,
+        ma_type: Option<MovingAverageType>,
+        ma_type_atr: Option<MovingAverageType>,
+        use_previous: Option<bool>,
+        atr_floor: Option<f64>,
+    ) -> Self {
+        Self {
+            period,
+            k_multiplier,
+            value: 0.0,
+            kc: KeltnerChannel::new(
+                period,
+                k_multiplier,
+                ma_type,
+                ma_type_atr,
+                use_previous,
+                atr_floor,
+            ),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.kc.update_raw(high, low, close);
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.kc.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+
+        let k_width = (self.kc.upper - self.kc.lower) / 2.0;
+        self.value = if k_width > 0.0 {
+            (close - self.kc.middle) / k_width
+        } else {
+            0.0
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keltner_position() -> KeltnerPosition {
+        KeltnerPosition::new(10, 2.5, None, None, None, None)
+    }
+
+    #[test]
+    fn test_keltner_position_initialized() {
+        let kp = keltner_position();
+
+        assert_eq!(kp.name(), "KeltnerPosition");
+        assert_eq!(kp.period, 10);
+        assert_eq!(kp.k_multiplier, 2.5);
+        assert!(!kp.is_initialized());
+    }
+
+    #[test]
+    fn test_keltner_position_value_with_one_input() {
+        let mut kp = keltner_position();
+        kp.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert!(kp.value.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keltner_position_value_with_zero_width() {
+        let mut kp = keltner_position();
+        for _ in 0..10 {
+            kp.update_raw(1.0, 1.0, 1.0);
+        }
+
+        assert!(kp.is_initialized());
+        assert_eq!(kp.value, 0.0);
+    }
+
+    #[test]
+    fn test_keltner_position_value_with_three_inputs() {
+        let mut kp = keltner_position();
+        kp.update_raw(1.00020, 1.00000, 1.00010);
+        kp.update_raw(1.00030, 1.00010, 1.00020);
+        kp.update_raw(1.00040, 1.00020, 1.00030);
+
+        assert!((kp.value - 0.297_520_661_157_545_94).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keltner_position_value_with_close_on_high() {
+        let mut kp = keltner_position();
+        let mut high = 1.00010;
+        let mut low = 1.00000;
+        for _ in 0..10 {
+            high += 0.00010;
+            low += 0.00010;
+            kp.update_raw(high, low, high);
+        }
+
+        assert!((kp.value - 1.637_585_941_284_833).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keltner_position_reset() {
+        let mut kp = keltner_position();
+        kp.update_raw(1.00020, 1.00000, 1.00010);
+
+        kp.reset();
+
+        assert_eq!(kp.value, 0.0);
+        assert!(!kp.has_inputs());
+        assert!(!kp.is_initialized());
+    }
+}
@@ -0,0 +1,225 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// The Vertical Horizon Filter (VHF) was created by Adam White to identify
+/// trending and ranging markets.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct VerticalHorizontalFilter {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub value: f64,
+    prices: VecDeque<f64>,
+    ma: Box<dyn MovingAverage + Send + Sync>,
+    previous_close: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for VerticalHorizontalFilter {
+    fn name(&self) -> String {
+        stringify!(VerticalHorizontalFilter).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(tick.extract_price(PriceType::Mid).into())
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.prices.clear();
+        self.ma.reset();
+        self.previous_close = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl VerticalHorizontalFilter {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+
+        Self {
+            period,
+            ma_type,
+            value: 0.0,
+            prices: VecDeque::with_capacity(period),
+            ma: MovingAverageFactory::create(period, ma_type),
+            previous_close: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        if !self._has_inputs {
+            self.previous_close = close;
+        }
+
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(close);
+
+        let max_price = self.prices.iter().copied().fold(f64::MIN, f64::max);
+        let min_price = self.prices.iter().copied().fold(f64::MAX, f64::min);
+
+        self.ma.update_raw((close - self.previous_close).abs());
+        if self._is_initialized {
+            self.value = (max_price - min_price).abs() / self.period as f64 / self.ma.value();
+        }
+
+        self.previous_close = close;
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.ma.is_initialized() && self.prices.len() >= self.period {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vhf_initialized() {
+        let vhf = VerticalHorizontalFilter::new(10, None);
+
+        assert_eq!(vhf.name(), "VerticalHorizontalFilter");
+        assert_eq!(vhf.period, 10);
+        assert_eq!(vhf.value, 0.0);
+        assert!(!vhf.is_initialized());
+    }
+
+    #[test]
+    fn test_vhf_value_with_one_input() {
+        let mut vhf = VerticalHorizontalFilter::new(10, None);
+        vhf.update_raw(56.87);
+
+        assert_eq!(vhf.value, 0.0);
+    }
+
+    #[test]
+    fn test_vhf_value_with_twenty_inputs() {
+        let mut vhf = VerticalHorizontalFilter::new(10, None);
+        for close in [
+            56.87, 56.96, 57.17, 57.54, 57.88, 57.85, 57.86, 57.97, 58.07, 58.04, 57.96, 57.98,
+            58.05, 57.94, 57.99, 58.11, 58.22, 58.19, 58.04, 58.02,
+        ] {
+            vhf.update_raw(close);
+        }
+
+        assert!(vhf.is_initialized());
+        assert!((vhf.value - 0.368_421_052_631_584_87).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vhf_reset() {
+        let mut vhf = VerticalHorizontalFilter::new(10, None);
+        vhf.update_raw(56.87);
+
+        vhf.reset();
+
+        assert_eq!(vhf.value, 0.0);
+        assert!(!vhf.has_inputs());
+        assert!(!vhf.is_initialized());
+    }
+}
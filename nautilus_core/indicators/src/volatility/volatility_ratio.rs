@@ -0,0 +1,243 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        tick::{QuoteTick, TradeTick},
+    },
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{average::MovingAverageType, volatility::atr::AverageTrueRange, Indicator};
+
+/// An indicator which calculates the ratio of different ranges of volatility.
+/// Different moving average types can be selected for the inner ATR
+/// calculations.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct VolatilityRatio {
+    #[pyo3(get)]
+    pub fast_period: usize,
+    #[pyo3(get)]
+    pub slow_period: usize,
+    #[pyo3(get)]
+    pub value: f64,
+    atr_fast: AverageTrueRange,
+    atr_slow: AverageTrueRange,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for VolatilityRatio {
+    fn name(&self) -> String {
+        stringify!(VolatilityRatio).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.update_raw(
+            (&tick.ask).into(),
+            (&tick.bid).into(),
+            tick.extract_price(PriceType::Mid).into(),
+        )
+    }
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into())
+    }
+
+    fn reset(&mut self) {
+        self.atr_fast.reset();
+        self.atr_slow.reset();
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl VolatilityRatio {
+    /// # Panics
+    ///
+    /// - If `fast_period` or `slow_period` is not positive (> 0).
+    /// - If `fast_period` is not less than `slow_period`.
+    /// - If `value_floor` is negative.
+    #[must_use]
+    #[new]
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        ma_type: Option<MovingAverageType>,
+        use_previous: Option<bool>,
+        value_floor: Option<f64>,
+    ) -> Self {
+        if fast_period >= slow_period {
+            panic!("invalid `fast_period` for `slow_period` {slow_period}, was {fast_period}");
+        }
+
+        Self {
+            fast_period,
+            slow_period,
+            value: 0.0,
+            atr_fast: AverageTrueRange::new(fast_period, ma_type, use_previous, value_floor),
+            atr_slow: AverageTrueRange::new(slow_period, ma_type, use_previous, value_floor),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.atr_fast.update_raw(high, low, close);
+        self.atr_slow.update_raw(high, low, close);
+
+        // Guard against divide by zero
+        if self.atr_fast.value > 0.0 {
+            self.value = self.atr_slow.value / self.atr_fast.value;
+        }
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.atr_fast.is_initialized() && self.atr_slow.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volatility_ratio_initialized() {
+        let vr = VolatilityRatio::new(10, 100, None, None, None);
+
+        assert_eq!(vr.name(), "VolatilityRatio");
+        assert_eq!(vr.fast_period, 10);
+        assert_eq!(vr.slow_period, 100);
+        assert!(!vr.is_initialized());
+    }
+
+    #[test]
+    fn test_volatility_ratio_initialized_with_required_inputs() {
+        let mut vr = VolatilityRatio::new(10, 100, None, None, None);
+        for _ in 0..100 {
+            vr.update_raw(1.0, 1.0, 1.0);
+        }
+
+        assert!(vr.is_initialized());
+        assert_eq!(vr.value, 0.0);
+    }
+
+    #[test]
+    fn test_volatility_ratio_value_with_one_input() {
+        let mut vr = VolatilityRatio::new(10, 100, None, None, None);
+        vr.update_raw(1.00020, 1.00000, 1.00010);
+
+        assert_eq!(vr.value, 1.0);
+    }
+
+    #[test]
+    fn test_volatility_ratio_value_with_close_on_low() {
+        let mut vr = VolatilityRatio::new(10, 100, None, None, None);
+        let mut high = 1.00010;
+        let mut low = 1.00000;
+        let mut factor = 0.0;
+        for _ in 0..1000 {
+            high -= 0.00010 + factor;
+            low -= 0.00010 + factor;
+            factor -= 0.00002;
+            vr.update_raw(high, low, low);
+        }
+
+        assert!((vr.value - 0.954_751_131_221_718_8).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_volatility_ratio_with_fast_period_not_less_than_slow() {
+        let _ = VolatilityRatio::new(10, 10, None, None, None);
+    }
+
+    #[test]
+    fn test_volatility_ratio_reset() {
+        let mut vr = VolatilityRatio::new(10, 100, None, None, None);
+        for _ in 0..1000 {
+            vr.update_raw(1.00010, 1.00000, 1.00005);
+        }
+
+        vr.reset();
+
+        assert_eq!(vr.value, 0.0);
+        assert!(!vr.has_inputs());
+        assert!(!vr.is_initialized());
+    }
+}
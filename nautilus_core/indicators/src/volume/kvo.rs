@@ -0,0 +1,285 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    Indicator,
+};
+
+/// This indicator was developed by Stephen J. Klinger. It is designed to predict
+/// price reversals in a market by comparing volume to price.
+///
+/// Trade ticks are handled as (price, price, price, size), and quote ticks are
+/// ignored.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct KlingerVolumeOscillator {
+    #[pyo3(get)]
+    pub fast_period: usize,
+    #[pyo3(get)]
+    pub slow_period: usize,
+    #[pyo3(get)]
+    pub signal_period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub value: f64,
+    fast_ma: Box<dyn MovingAverage + Send + Sync>,
+    slow_ma: Box<dyn MovingAverage + Send + Sync>,
+    signal_ma: Box<dyn MovingAverage + Send + Sync>,
+    hlc3: f64,
+    previous_hlc3: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for KlingerVolumeOscillator {
+    fn name(&self) -> String {
+        stringify!(KlingerVolumeOscillator).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {}
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price, (&tick.size).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        )
+    }
+
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.hlc3 = 0.0;
+        self.previous_hlc3 = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl KlingerVolumeOscillator {
+    /// # Panics
+    ///
+    /// - If any period is not positive (> 0).
+    /// - If `fast_period` is not less than `slow_period`.
+    #[must_use]
+    #[new]
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: Option<MovingAverageType>,
+    ) -> Self {
+        if fast_period >= slow_period {
+            panic!("invalid `fast_period` for `slow_period` {slow_period}, was {fast_period}");
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            ma_type,
+            value: 0.0,
+            fast_ma: MovingAverageFactory::create(fast_period, ma_type),
+            slow_ma: MovingAverageFactory::create(slow_period, ma_type),
+            signal_ma: MovingAverageFactory::create(signal_period, ma_type),
+            hlc3: 0.0,
+            previous_hlc3: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        self.hlc3 = (high + low + close) / 3.0;
+
+        let signed_volume = if self.hlc3 > self.previous_hlc3 {
+            volume
+        } else if self.hlc3 < self.previous_hlc3 {
+            -volume
+        } else {
+            0.0
+        };
+        self.fast_ma.update_raw(signed_volume);
+        self.slow_ma.update_raw(signed_volume);
+
+        if self.slow_ma.is_initialized() {
+            self.signal_ma
+                .update_raw(self.fast_ma.value() - self.slow_ma.value());
+            self.value = self.signal_ma.value();
+        }
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.signal_ma.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+
+        self.previous_hlc3 = self.hlc3;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kvo_initialized() {
+        let kvo = KlingerVolumeOscillator::new(5, 10, 5, None);
+
+        assert_eq!(kvo.name(), "KlingerVolumeOscillator");
+        assert_eq!(kvo.fast_period, 5);
+        assert_eq!(kvo.slow_period, 10);
+        assert_eq!(kvo.signal_period, 5);
+        assert_eq!(kvo.ma_type, MovingAverageType::Exponential);
+        assert!(!kvo.is_initialized());
+    }
+
+    #[test]
+    fn test_kvo_initialized_with_required_inputs() {
+        let mut kvo = KlingerVolumeOscillator::new(5, 10, 5, None);
+        for i in 0..15 {
+            let i = f64::from(i);
+            kvo.update_raw(i, i, i, i);
+        }
+
+        assert!(kvo.is_initialized());
+    }
+
+    #[test]
+    fn test_kvo_value_with_one_input() {
+        let mut kvo = KlingerVolumeOscillator::new(5, 10, 5, None);
+        kvo.update_raw(110.08, 109.61, 109.93, 282.55);
+
+        assert_eq!(kvo.value, 0.0);
+    }
+
+    #[test]
+    fn test_kvo_value_with_all_higher_inputs() {
+        let mut kvo = KlingerVolumeOscillator::new(5, 10, 5, None);
+        for (high, low, close, volume) in [
+            (110.08, 109.61, 109.93, 282.55),
+            (110.15, 109.91, 110.0, 600.66),
+            (110.1, 109.73, 109.77, 195.84),
+            (110.06, 109.77, 109.96, 282.48),
+            (110.29, 109.88, 110.29, 115.83),
+            (110.53, 110.29, 110.53, 921.23),
+            (110.61, 110.26, 110.27, 150.67),
+            (110.28, 110.17, 110.21, 61.29),
+            (110.3, 110.0, 110.06, 166.29),
+            (110.25, 110.01, 110.19, 40.64),
+            (110.25, 109.81, 109.83, 148.38),
+            (109.92, 109.71, 109.9, 124.88),
+            (110.21, 109.84, 110.0, 172.12),
+            (110.08, 109.95, 110.03, 76.51),
+            (110.2, 109.96, 110.13, 147.98),
+            (110.16, 109.95, 109.95, 71.72),
+            (109.99, 109.75, 109.75, 229.87),
+            (110.2, 109.73, 110.15, 414.76),
+            (110.1, 109.81, 109.9, 205.6),
+            (110.04, 109.96, 110.04, 32.95),
+        ] {
+            kvo.update_raw(high, low, close, volume);
+        }
+
+        assert!((kvo.value - -20.530_114_132_019_506).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kvo_with_fast_period_not_less_than_slow() {
+        let _ = KlingerVolumeOscillator::new(10, 5, 5, None);
+    }
+
+    #[test]
+    fn test_kvo_reset() {
+        let mut kvo = KlingerVolumeOscillator::new(5, 10, 5, None);
+        kvo.update_raw(110.08, 109.61, 109.93, 282.55);
+
+        kvo.reset();
+
+        assert_eq!(kvo.value, 0.0);
+        assert!(!kvo.has_inputs());
+        assert!(!kvo.is_initialized());
+    }
+}
@@ -0,0 +1,250 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::prelude::*;
+
+use crate::Indicator;
+
+/// An indicator which calculates the momentum of relative positive or negative
+/// volume. A `period` of zero indicates no window (all inputs are summed).
+///
+/// Bars are signed by close versus open. Trade ticks are signed by price
+/// versus the previous trade price, and quote ticks are ignored.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct OnBalanceVolume {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub value: f64,
+    obv: VecDeque<f64>,
+    previous_trade_price: Option<f64>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for OnBalanceVolume {
+    fn name(&self) -> String {
+        stringify!(OnBalanceVolume).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {}
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        let previous = self.previous_trade_price.unwrap_or(price);
+        self.previous_trade_price = Some(price);
+        self.update_raw(previous, price, (&tick.size).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        )
+    }
+
+    fn reset(&mut self) {
+        self.obv.clear();
+        self.previous_trade_price = None;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl OnBalanceVolume {
+    #[must_use]
+    #[new]
+    pub fn new(period: Option<usize>) -> Self {
+        let period = period.unwrap_or(0);
+
+        Self {
+            period,
+            value: 0.0,
+            obv: VecDeque::with_capacity(period),
+            previous_trade_price: None,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, open: f64, close: f64, volume: f64) {
+        let signed_volume = if close > open {
+            volume
+        } else if close < open {
+            -volume
+        } else {
+            0.0
+        };
+
+        if self.period > 0 && self.obv.len() == self.period {
+            self.obv.pop_front();
+        }
+        self.obv.push_back(signed_volume);
+
+        self.value = self.obv.iter().sum();
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.obv.len() >= self.period.max(1) {
+                self._is_initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_obv_initialized() {
+        let obv = OnBalanceVolume::new(Some(100));
+
+        assert_eq!(obv.name(), "OnBalanceVolume");
+        assert_eq!(obv.period, 100);
+        assert!(!obv.is_initialized());
+    }
+
+    #[test]
+    fn test_obv_initialized_with_required_inputs() {
+        let mut obv = OnBalanceVolume::new(Some(100));
+        for _ in 0..100 {
+            obv.update_raw(1.00000, 1.00010, 10000.0);
+        }
+
+        assert!(obv.is_initialized());
+    }
+
+    #[test]
+    fn test_obv_without_window_initializes_on_first_input() {
+        let mut obv = OnBalanceVolume::new(None);
+        obv.update_raw(1.00000, 1.00010, 10000.0);
+
+        assert!(obv.is_initialized());
+        assert_eq!(obv.value, 10000.0);
+    }
+
+    #[test]
+    fn test_obv_value_with_higher_inputs() {
+        let mut obv = OnBalanceVolume::new(Some(100));
+        for _ in 0..4 {
+            obv.update_raw(1.00000, 1.00010, 10000.0);
+        }
+        obv.update_raw(1.00000, 1.00000, 10000.0);
+        for _ in 0..5 {
+            obv.update_raw(1.00000, 1.00010, 10000.0);
+        }
+
+        assert_eq!(obv.value, 90000.0);
+    }
+
+    #[test]
+    fn test_obv_value_with_lower_inputs() {
+        let mut obv = OnBalanceVolume::new(Some(100));
+        for _ in 0..6 {
+            obv.update_raw(1.00010, 1.00000, 10000.0);
+        }
+        obv.update_raw(1.00010, 1.00010, 10000.0);
+        for _ in 0..3 {
+            obv.update_raw(1.00010, 1.00000, 10000.0);
+        }
+
+        assert_eq!(obv.value, -90000.0);
+    }
+
+    #[test]
+    fn test_obv_rolls_window() {
+        let mut obv = OnBalanceVolume::new(Some(2));
+        obv.update_raw(1.00000, 1.00010, 10000.0);
+        obv.update_raw(1.00010, 1.00000, 5000.0);
+        obv.update_raw(1.00010, 1.00000, 5000.0);
+
+        assert_eq!(obv.value, -10000.0);
+    }
+
+    #[test]
+    fn test_obv_reset() {
+        let mut obv = OnBalanceVolume::new(Some(100));
+        for _ in 0..100 {
+            obv.update_raw(1.00000, 1.00010, 10000.0);
+        }
+
+        obv.reset();
+
+        assert_eq!(obv.value, 0.0);
+        assert!(!obv.has_inputs());
+        assert!(!obv.is_initialized());
+    }
+}
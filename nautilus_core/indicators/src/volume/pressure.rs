@@ -0,0 +1,269 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverage, MovingAverageFactory, MovingAverageType},
+    volatility::atr::AverageTrueRange,
+    Indicator,
+};
+
+/// An indicator which calculates the relative volume (multiple of average volume)
+/// to move the market across a relative range (multiple of ATR).
+///
+/// Trade ticks are handled as (price, price, price, size), and quote ticks are
+/// ignored.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct Pressure {
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub ma_type: MovingAverageType,
+    #[pyo3(get)]
+    pub atr_floor: f64,
+    #[pyo3(get)]
+    pub value: f64,
+    #[pyo3(get)]
+    pub value_cumulative: f64,
+    atr: AverageTrueRange,
+    average_volume: Box<dyn MovingAverage + Send + Sync>,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for Pressure {
+    fn name(&self) -> String {
+        stringify!(Pressure).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {}
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        let price = (&tick.price).into();
+        self.update_raw(price, price, price, (&tick.size).into())
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        )
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.average_volume.reset();
+        self.value = 0.0;
+        self.value_cumulative = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+#[pymethods]
+impl Pressure {
+    /// # Panics
+    ///
+    /// - If `period` is not positive (> 0).
+    /// - If `atr_floor` is negative.
+    #[must_use]
+    #[new]
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>, atr_floor: Option<f64>) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+        let atr_floor = atr_floor.unwrap_or(0.0);
+
+        Self {
+            period,
+            ma_type,
+            atr_floor,
+            value: 0.0,
+            value_cumulative: 0.0,
+            atr: AverageTrueRange::new(
+                period,
+                Some(MovingAverageType::Exponential),
+                None,
+                Some(atr_floor),
+            ),
+            average_volume: MovingAverageFactory::create(period, ma_type),
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        self.atr.update_raw(high, low, close);
+        self.average_volume.update_raw(volume);
+
+        // Initialization logic (do not move this to the bottom as guard against zero will return)
+        if !self._is_initialized {
+            self._has_inputs = true;
+            if self.atr.is_initialized() {
+                self._is_initialized = true;
+            }
+        }
+
+        // Guard against zero values
+        let average_volume = self.average_volume.value();
+        if average_volume == 0.0 || self.atr.value == 0.0 {
+            self.value = 0.0;
+            return;
+        }
+
+        let relative_volume = volume / average_volume;
+        let buy_pressure = ((close - low) / self.atr.value) * relative_volume;
+        let sell_pressure = ((high - close) / self.atr.value) * relative_volume;
+
+        self.value = buy_pressure - sell_pressure;
+        self.value_cumulative += self.value;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pressure_initialized() {
+        let pressure = Pressure::new(10, None, None);
+
+        assert_eq!(pressure.name(), "Pressure");
+        assert_eq!(pressure.period, 10);
+        assert_eq!(pressure.ma_type, MovingAverageType::Exponential);
+        assert_eq!(pressure.atr_floor, 0.0);
+        assert!(!pressure.is_initialized());
+    }
+
+    #[test]
+    fn test_pressure_initialized_with_required_inputs() {
+        let mut pressure = Pressure::new(10, None, None);
+        for _ in 0..10 {
+            pressure.update_raw(1.00000, 1.00000, 1.00000, 1000.0);
+        }
+
+        assert!(pressure.is_initialized());
+    }
+
+    #[test]
+    fn test_pressure_value_with_one_bar() {
+        let mut pressure = Pressure::new(10, None, None);
+        pressure.update_raw(1.00004, 1.00001, 1.00003, 1_000_000.0);
+
+        assert!((pressure.value - 0.333_333_333_328_399).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pressure_value_with_one_flat_input() {
+        let mut pressure = Pressure::new(10, None, None);
+        pressure.update_raw(1.00000, 1.00000, 1.00000, 1000.0);
+
+        assert_eq!(pressure.value, 0.0);
+    }
+
+    #[test]
+    fn test_pressure_values_with_higher_inputs() {
+        let mut pressure = Pressure::new(10, None, None);
+        for i in 1..=10 {
+            let high = 1.0 + f64::from(i) * 0.0001;
+            pressure.update_raw(high, 1.00000, high, 1000.0);
+        }
+
+        assert!((pressure.value - 1.602_726_306_654_311_6).abs() < 1e-9);
+        assert!((pressure.value_cumulative - 17.427_420_446_202_998).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pressure_values_with_all_lower_inputs() {
+        let mut pressure = Pressure::new(10, None, None);
+        for i in 1..=10 {
+            let low = 1.0 - f64::from(i) * 0.0001;
+            pressure.update_raw(1.00000, low, low, 1000.0);
+        }
+
+        assert!((pressure.value - -1.602_726_306_654_309).abs() < 1e-9);
+        assert!((pressure.value_cumulative - -17.427_420_446_203_406).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pressure_reset() {
+        let mut pressure = Pressure::new(10, None, None);
+        for _ in 0..10 {
+            pressure.update_raw(1.00000, 1.00000, 1.00000, 1000.0);
+        }
+
+        pressure.reset();
+
+        assert_eq!(pressure.value, 0.0);
+        assert_eq!(pressure.value_cumulative, 0.0);
+        assert!(!pressure.has_inputs());
+        assert!(!pressure.is_initialized());
+    }
+}
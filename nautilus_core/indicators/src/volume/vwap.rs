@@ -0,0 +1,337 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::data::{
+    bar::Bar,
+    tick::{QuoteTick, TradeTick},
+};
+use pyo3::prelude::*;
+use strum::{Display, EnumString, FromRepr};
+
+use crate::Indicator;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// The session reset mode for a volume weighted average price.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[pyclass]
+pub enum SessionResetMode {
+    /// Reset at each daily session start, `session_offset` nanoseconds after
+    /// UTC midnight.
+    Daily = 0,
+    /// Never reset, the average accumulates over all inputs.
+    Never = 1,
+}
+
+/// An indicator which calculates the volume weighted average price for the
+/// session. With `SessionResetMode::Daily` each session starts `session_offset`
+/// nanoseconds after UTC midnight (0 for the UTC day), and trades and bars are
+/// assigned to a session by their `ts_event`.
+///
+/// Quote ticks carry no traded volume and are ignored.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass]
+pub struct VolumeWeightedAveragePrice {
+    #[pyo3(get)]
+    pub reset_mode: SessionResetMode,
+    #[pyo3(get)]
+    pub session_offset: u64,
+    #[pyo3(get)]
+    pub value: f64,
+    session: Option<u64>,
+    price_volume: f64,
+    volume_total: f64,
+    _has_inputs: bool,
+    _is_initialized: bool,
+}
+
+impl Indicator for VolumeWeightedAveragePrice {
+    fn name(&self) -> String {
+        stringify!(VolumeWeightedAveragePrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self._has_inputs
+    }
+
+    fn is_initialized(&self) -> bool {
+        self._is_initialized
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {}
+
+    fn handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.update_raw((&tick.price).into(), (&tick.size).into(), tick.ts_event)
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into(), (&bar.volume).into(), bar.ts_event)
+    }
+
+    fn reset(&mut self) {
+        self.session = None;
+        self.price_volume = 0.0;
+        self.volume_total = 0.0;
+        self.value = 0.0;
+        self._has_inputs = false;
+        self._is_initialized = false;
+    }
+}
+
+impl VolumeWeightedAveragePrice {
+    fn session_for(&self, ts: UnixNanos) -> u64 {
+        match self.reset_mode {
+            // Shifted by a day so timestamps before the first session start
+            // don't underflow
+            SessionResetMode::Daily => {
+                (ts + NANOSECONDS_IN_DAY - self.session_offset) / NANOSECONDS_IN_DAY
+            }
+            SessionResetMode::Never => 0,
+        }
+    }
+}
+
+#[pymethods]
+impl VolumeWeightedAveragePrice {
+    /// # Panics
+    ///
+    /// - If `session_offset` is not less than a day in nanoseconds.
+    #[must_use]
+    #[new]
+    pub fn new(reset_mode: Option<SessionResetMode>, session_offset: Option<u64>) -> Self {
+        let session_offset = session_offset.unwrap_or(0);
+        if session_offset >= NANOSECONDS_IN_DAY {
+            panic!(
+                "invalid `session_offset` for `VolumeWeightedAveragePrice`, was {session_offset} (must be < {NANOSECONDS_IN_DAY})"
+            );
+        }
+
+        Self {
+            reset_mode: reset_mode.unwrap_or(SessionResetMode::Daily),
+            session_offset,
+            value: 0.0,
+            session: None,
+            price_volume: 0.0,
+            volume_total: 0.0,
+            _has_inputs: false,
+            _is_initialized: false,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    #[must_use]
+    pub fn name_py(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "has_inputs")]
+    fn has_inputs_py(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[pyo3(name = "is_initialized")]
+    fn is_initialized_py(&self) -> bool {
+        self.is_initialized()
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn handle_quote_tick_py(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn handle_trade_tick_py(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn handle_bar_py(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn reset_py(&mut self) {
+        self.reset();
+    }
+
+    pub fn update_raw(&mut self, price: f64, volume: f64, ts: UnixNanos) {
+        // On a new session reset the indicator
+        let session = self.session_for(ts);
+        if self.session != Some(session) {
+            self.reset();
+            self.session = Some(session);
+            self.value = price;
+        }
+
+        // Initialization logic
+        if !self._is_initialized {
+            self._has_inputs = true;
+            self._is_initialized = true;
+        }
+
+        // No weighting for this price (also avoiding divide by zero)
+        if volume == 0.0 {
+            return;
+        }
+
+        self.price_volume += price * volume;
+        self.volume_total += volume;
+        self.value = self.price_volume / self.volume_total;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        data::bar::BarType,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_vwap_initialized() {
+        let vwap = VolumeWeightedAveragePrice::new(None, None);
+
+        assert_eq!(vwap.name(), "VolumeWeightedAveragePrice");
+        assert_eq!(vwap.reset_mode, SessionResetMode::Daily);
+        assert_eq!(vwap.session_offset, 0);
+        assert!(!vwap.is_initialized());
+    }
+
+    #[test]
+    fn test_vwap_value_with_one_input() {
+        let mut vwap = VolumeWeightedAveragePrice::new(None, None);
+        vwap.update_raw(1.00000, 10000.0, 0);
+
+        assert!(vwap.is_initialized());
+        assert_eq!(vwap.value, 1.00000);
+    }
+
+    #[test]
+    fn test_vwap_value_with_higher_inputs() {
+        let mut vwap = VolumeWeightedAveragePrice::new(None, None);
+        for (price, volume) in [
+            (1.00000, 10000.0),
+            (1.00010, 11000.0),
+            (1.00020, 12000.0),
+            (1.00030, 13000.0),
+            (1.00040, 14000.0),
+            (1.00050, 0.0),
+            (1.00060, 16000.0),
+            (1.00070, 17000.0),
+            (1.00080, 18000.0),
+            (1.00090, 19000.0),
+        ] {
+            vwap.update_raw(price, volume, 0);
+        }
+
+        assert!((vwap.value - 1.000_507_692_307_692_3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vwap_new_day_resets_values() {
+        let mut vwap = VolumeWeightedAveragePrice::new(None, None);
+        vwap.update_raw(1.00000, 10000.0, 0);
+        vwap.update_raw(1.00090, 19000.0, NANOSECONDS_IN_DAY - 1);
+        vwap.update_raw(1.00000, 10000.0, NANOSECONDS_IN_DAY);
+
+        assert_eq!(vwap.value, 1.00000);
+    }
+
+    #[test]
+    fn test_vwap_new_day_with_first_volume_zero_returns_price() {
+        let mut vwap = VolumeWeightedAveragePrice::new(None, None);
+        vwap.update_raw(2.00000, 10000.0, 0);
+        vwap.update_raw(1.00000, 0.0, NANOSECONDS_IN_DAY);
+
+        assert_eq!(vwap.value, 1.00000);
+    }
+
+    #[test]
+    fn test_vwap_session_offset_resets_at_session_start() {
+        let offset = NANOSECONDS_IN_DAY / 4;
+        let mut vwap = VolumeWeightedAveragePrice::new(None, Some(offset));
+        vwap.update_raw(2.00000, 10000.0, offset - 1);
+        vwap.update_raw(1.00000, 10000.0, offset);
+        vwap.update_raw(2.00000, 10000.0, NANOSECONDS_IN_DAY);
+
+        assert_eq!(vwap.value, 1.5);
+
+        vwap.update_raw(3.00000, 10000.0, NANOSECONDS_IN_DAY + offset);
+
+        assert_eq!(vwap.value, 3.00000);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `session_offset`")]
+    fn test_vwap_session_offset_of_a_day_panics() {
+        let _ = VolumeWeightedAveragePrice::new(None, Some(NANOSECONDS_IN_DAY));
+    }
+
+    #[test]
+    fn test_vwap_handle_bar_uses_ts_event() {
+        let mut vwap = VolumeWeightedAveragePrice::new(None, None);
+        let mut bar = Bar {
+            bar_type: BarType::from_str("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL").unwrap(),
+            open: Price::from("1.00000"),
+            high: Price::from("1.00000"),
+            low: Price::from("1.00000"),
+            close: Price::from("1.00000"),
+            volume: Quantity::from("10000"),
+            ts_event: NANOSECONDS_IN_DAY - 2,
+            ts_init: NANOSECONDS_IN_DAY - 1,
+        };
+        vwap.handle_bar(&bar);
+        bar.close = Price::from("2.00000");
+        bar.ts_event = NANOSECONDS_IN_DAY - 1;
+        bar.ts_init = NANOSECONDS_IN_DAY;
+        vwap.handle_bar(&bar);
+
+        assert_eq!(vwap.value, 1.5);
+    }
+
+    #[test]
+    fn test_vwap_never_reset_mode_accumulates_across_days() {
+        let mut vwap = VolumeWeightedAveragePrice::new(Some(SessionResetMode::Never), None);
+        vwap.update_raw(2.00000, 10000.0, 0);
+        vwap.update_raw(1.00000, 10000.0, NANOSECONDS_IN_DAY);
+
+        assert_eq!(vwap.value, 1.5);
+    }
+
+    #[test]
+    fn test_vwap_reset() {
+        let mut vwap = VolumeWeightedAveragePrice::new(None, None);
+        vwap.update_raw(1.00000, 10000.0, 0);
+
+        vwap.reset();
+
+        assert_eq!(vwap.value, 0.0);
+        assert!(!vwap.has_inputs());
+        assert!(!vwap.is_initialized());
+    }
+}
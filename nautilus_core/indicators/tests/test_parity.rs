@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+// Checks the indicators against the values of the Cython implementations over
+// the first 1000 GBP/USD bid bars in `tests/test_data`.
+
+use std::fs;
+
+use nautilus_indicators::{
+    average::{vidya::VariableIndexDynamicAverage, MovingAverageType},
+    momentum::{
+        aroon::AroonOscillator, cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator,
+        macd::MovingAverageConvergenceDivergence, roc::RateOfChange, rsi::RelativeStrengthIndex,
+        rvi::RelativeVolatilityIndex, stochastics::Stochastics,
+    },
+};
+
+const BARS_PATH: &str = "../../tests/test_data/fxcm-gbpusd-m1-bid-2012.csv";
+
+// Returns the (high, low, close) of each bar
+fn load_bars(count: usize) -> Vec<(f64, f64, f64)> {
+    fs::read_to_string(BARS_PATH)
+        .unwrap()
+        .lines()
+        .skip(1) // Header
+        .take(count)
+        .map(|line| {
+            let fields: Vec<f64> = line
+                .split(',')
+                .skip(2) // Timestamp and open
+                .map(|field| field.parse().unwrap())
+                .collect();
+            (fields[0], fields[1], fields[2])
+        })
+        .collect()
+}
+
+fn load_closes(count: usize) -> Vec<f64> {
+    load_bars(count)
+        .into_iter()
+        .map(|(_, _, close)| close)
+        .collect()
+}
+
+// Runs the indicator over the inputs and returns its values after each of the
+// `checkpoints` (by index of the input)
+fn values_at<T: Copy, V>(
+    inputs: &[T],
+    checkpoints: &[usize],
+    mut update: impl FnMut(T) -> V,
+) -> Vec<V> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(i, &input)| (i, update(input)))
+        .filter(|(i, _)| checkpoints.contains(i))
+        .map(|(_, value)| value)
+        .collect()
+}
+
+fn assert_values_eq(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, was {actual}"
+        );
+    }
+}
+
+#[test]
+fn test_cmo_parity() {
+    let closes = load_closes(1000);
+    let mut cmo = ChandeMomentumOscillator::new(10, Some(MovingAverageType::Wilder));
+
+    let values = values_at(&closes, &[99, 499, 999], |close| {
+        cmo.update_raw(close);
+        cmo.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            -31.671_091_970_149_714,
+            5.734_592_118_573_298,
+            -15.520_640_595_550_77,
+        ],
+    );
+}
+
+#[test]
+fn test_rvi_parity() {
+    let closes = load_closes(1000);
+    let mut rvi = RelativeVolatilityIndex::new(10, None, None);
+
+    let values = values_at(&closes, &[99, 499, 999], |close| {
+        rvi.update_raw(close);
+        rvi.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            44.398_617_384_116_96,
+            52.496_775_890_886_3,
+            39.194_350_336_908_39,
+        ],
+    );
+}
+
+#[test]
+fn test_vidya_parity() {
+    let closes = load_closes(1000);
+    let mut vidya = VariableIndexDynamicAverage::new(10, None, None);
+
+    let values = values_at(&closes, &[99, 499, 999], |close| {
+        vidya.update_raw(close);
+        vidya.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            1.562_132_226_538_117_2,
+            1.572_983_398_449_659_7,
+            1.585_417_098_814_402_3,
+        ],
+    );
+}
+
+#[test]
+fn test_rsi_parity() {
+    let closes = load_closes(1000);
+    let mut rsi = RelativeStrengthIndex::new(10, None);
+
+    let values = values_at(&closes, &[99, 499, 999], |close| {
+        rsi.update_raw(close);
+        rsi.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            0.292_099_616_666_213_54,
+            0.489_545_473_778_691_3,
+            0.391_538_724_171_323_6,
+        ],
+    );
+}
+
+#[test]
+fn test_macd_parity() {
+    let closes = load_closes(1000);
+    let mut macd = MovingAverageConvergenceDivergence::new(10, 20, None, None, None);
+
+    let values = values_at(&closes, &[99, 499, 999], |close| {
+        macd.update_raw(close);
+        macd.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            -1.043_099_755_408_949_4e-4,
+            1.554_537_542_329_814_7e-4,
+            -1.383_359_163_842_357e-4,
+        ],
+    );
+}
+
+#[test]
+fn test_stochastics_parity() {
+    let bars = load_bars(1000);
+    let mut stochastics = Stochastics::new(10, 3);
+
+    let (values_k, values_d): (Vec<f64>, Vec<f64>) =
+        values_at(&bars, &[99, 499, 999], |(high, low, close)| {
+            stochastics.update_raw(high, low, close);
+            (stochastics.value_k, stochastics.value_d)
+        })
+        .into_iter()
+        .unzip();
+
+    assert_values_eq(
+        &values_k,
+        &[0.0, 47.826_086_956_519_646, 9.482_758_620_676_62],
+    );
+    assert_values_eq(
+        &values_d,
+        &[
+            11.656_441_717_785_896,
+            63.214_285_714_281_495,
+            9.917_355_371_893_546,
+        ],
+    );
+}
+
+#[test]
+fn test_cci_parity() {
+    let bars = load_bars(1000);
+    let mut cci = CommodityChannelIndex::new(10, None, None);
+
+    let values = values_at(&bars, &[99, 499, 999], |(high, low, close)| {
+        cci.update_raw(high, low, close);
+        cci.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            -107.155_142_758_876_02,
+            26.960_784_313_675_074,
+            -98.562_949_078_394_35,
+        ],
+    );
+}
+
+#[test]
+fn test_roc_parity() {
+    let closes = load_closes(1000);
+    let mut roc = RateOfChange::new(10, None);
+
+    let values = values_at(&closes, &[99, 499, 999], |close| {
+        roc.update_raw(close);
+        roc.value
+    });
+
+    assert_values_eq(
+        &values,
+        &[
+            -2.855_511_136_493_822e-4,
+            -1.906_638_278_939_559e-5,
+            -4.099_110_177_775_504_3e-4,
+        ],
+    );
+}
+
+#[test]
+fn test_aroon_parity() {
+    let bars = load_bars(1000);
+    let mut aroon = AroonOscillator::new(10);
+
+    let (values_up, values_down): (Vec<f64>, Vec<f64>) =
+        values_at(&bars, &[99, 499, 999], |(high, low, _)| {
+            aroon.update_raw(high, low);
+            (aroon.aroon_up, aroon.aroon_down)
+        })
+        .into_iter()
+        .unzip();
+
+    assert_values_eq(
+        &values_up,
+        &[30.000_000_000_000_004, 0.0, 30.000_000_000_000_004],
+    );
+    assert_values_eq(&values_down, &[100.0, 60.0, 100.0]);
+}
@@ -0,0 +1,134 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, BatchSize, Criterion};
+use nautilus_model::{
+    data::book::BookOrder,
+    enums::{BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+
+const NUM_LEVELS: i64 = 200;
+const NUM_UPDATES: usize = 10_000;
+const NEAR_TOUCH_TICKS: u64 = 20;
+const FAR_FROM_TOUCH_TICKS: u64 = 100_000; // Beyond the default `PriceGrid` window
+const TICK_RAW: i64 = 250_000_000; // 0.25
+const MID_RAW: i64 = 1_000_000_000_000; // 1000.00
+
+fn create_book(price_increment: Option<Price>) -> OrderBook {
+    let instrument_id = InstrumentId::from_str("ESZ3.GLBX").unwrap();
+    let mut book = match price_increment {
+        Some(price_increment) => {
+            OrderBook::with_price_increment(instrument_id, BookType::L2_MBP, price_increment)
+        }
+        None => OrderBook::new(instrument_id, BookType::L2_MBP),
+    };
+    for i in 1..=NUM_LEVELS {
+        book.add(order(OrderSide::Buy, MID_RAW - i * TICK_RAW, 10), 0, 0);
+        book.add(order(OrderSide::Sell, MID_RAW + i * TICK_RAW, 10), 0, 0);
+    }
+    book
+}
+
+fn order(side: OrderSide, price_raw: i64, size: u64) -> BookOrder {
+    BookOrder::new(
+        side,
+        Price::from_raw(price_raw, 2),
+        Quantity::from_raw(size * 1_000_000_000, 0),
+        0,
+    )
+}
+
+/// Returns size updates within `max_ticks` of the top of the book, where a zero
+/// size deletes the level and a later update adds it back.
+fn create_updates(max_ticks: u64) -> Vec<BookOrder> {
+    let mut state: u64 = 42;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state >> 33
+    };
+    (0..NUM_UPDATES)
+        .map(|_| {
+            let offset = (next() % max_ticks) as i64 + 1;
+            let size = next() % 10;
+            if next() % 2 == 0 {
+                order(OrderSide::Buy, MID_RAW - offset * TICK_RAW, size)
+            } else {
+                order(OrderSide::Sell, MID_RAW + offset * TICK_RAW, size)
+            }
+        })
+        .collect()
+}
+
+fn apply_updates(book: &mut OrderBook, updates: &[BookOrder]) -> Option<Price> {
+    for update in updates {
+        if update.size.raw == 0 {
+            book.delete(*update, 0, 0);
+        } else {
+            book.update(*update, 0, 0);
+        }
+        black_box(book.best_bid_price());
+        black_box(book.best_ask_price());
+    }
+    book.best_bid_price()
+}
+
+pub fn criterion_orderbook_benchmark(c: &mut Criterion) {
+    let price_increment = Price::from_raw(TICK_RAW, 2);
+
+    for (group_name, max_ticks) in [
+        ("orderbook_l2_updates", NEAR_TOUCH_TICKS),
+        ("orderbook_l2_far_updates", FAR_FROM_TOUCH_TICKS),
+    ] {
+        let updates = create_updates(max_ticks);
+        let mut group = c.benchmark_group(group_name);
+        group.bench_function("btree_ladder", |b| {
+            b.iter_batched(
+                || create_book(None),
+                |mut book| apply_updates(&mut book, &updates),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_function("price_grid_ladder", |b| {
+            b.iter_batched(
+                || create_book(Some(price_increment)),
+                |mut book| apply_updates(&mut book, &updates),
+                BatchSize::LargeInput,
+            )
+        });
+        group.finish();
+    }
+
+    let mut group = c.benchmark_group("orderbook_level_lookup");
+    for (name, book) in [
+        ("btree_ladder", create_book(None)),
+        ("price_grid_ladder", create_book(Some(price_increment))),
+    ] {
+        let price = Price::from_raw(MID_RAW - 50 * TICK_RAW, 2);
+        group.bench_function(name, |b| {
+            b.iter(|| book.level(black_box(OrderSide::Buy), black_box(price)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_orderbook_benchmark);
+criterion::criterion_main!(benches);
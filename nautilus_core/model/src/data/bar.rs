@@ -148,6 +148,8 @@ impl PartialEq for BarType {
     }
 }
 
+impl Eq for BarType {}
+
 impl Hash for BarType {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.spec.hash(state);
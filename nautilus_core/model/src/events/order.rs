@@ -18,7 +18,10 @@ use nautilus_core::{time::UnixNanos, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType},
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
+        TriggerType,
+    },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
@@ -46,6 +49,30 @@ pub enum OrderEvent {
     OrderFilled(OrderFilled),
 }
 
+impl OrderEvent {
+    /// Returns the client order ID of the order the event relates to.
+    #[must_use]
+    pub fn client_order_id(&self) -> &ClientOrderId {
+        match self {
+            Self::OrderInitialized(e) => &e.client_order_id,
+            Self::OrderDenied(e) => &e.client_order_id,
+            Self::OrderSubmitted(e) => &e.client_order_id,
+            Self::OrderAccepted(e) => &e.client_order_id,
+            Self::OrderRejected(e) => &e.client_order_id,
+            Self::OrderCanceled(e) => &e.client_order_id,
+            Self::OrderExpired(e) => &e.client_order_id,
+            Self::OrderTriggered(e) => &e.client_order_id,
+            Self::OrderPendingUpdate(e) => &e.client_order_id,
+            Self::OrderPendingCancel(e) => &e.client_order_id,
+            Self::OrderModifyRejected(e) => &e.client_order_id,
+            Self::OrderCancelRejected(e) => &e.client_order_id,
+            Self::OrderUpdated(e) => &e.client_order_id,
+            Self::OrderPartiallyFilled(e) => &e.client_order_id,
+            Self::OrderFilled(e) => &e.client_order_id,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq, Debug, Builder, Serialize, Deserialize)]
 #[builder(default)]
@@ -69,7 +96,7 @@ pub struct OrderInitialized {
     pub display_qty: Option<Quantity>,
     pub limit_offset: Option<Price>,
     pub trailing_offset: Option<Price>,
-    pub trailing_offset_type: Option<TriggerType>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
     pub emulation_trigger: Option<TriggerType>,
     pub contingency_type: Option<ContingencyType>,
     pub order_list_id: Option<OrderListId>,
@@ -50,7 +50,7 @@ use crate::identifiers::{
 //     display_qty: *const Quantity,
 //     limit_offset: *const Price,
 //     trailing_offset: *const Price,
-//     trailing_offset_type: *const TriggerType,
+//     trailing_offset_type: *const TrailingOffsetType,
 //     event_id: UUID4,
 //     ts_event: UnixNanos,
 //     ts_init: UnixNanos,
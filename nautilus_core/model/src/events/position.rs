@@ -17,13 +17,16 @@ use nautilus_core::time::{TimedeltaNanos, UnixNanos};
 
 use crate::{
     enums::{OrderSide, PositionSide},
+    events::order::OrderFilled,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId,
     },
+    position::Position,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+#[derive(Clone, PartialEq, Debug)]
 pub enum PositionEvent {
     PositionOpened(PositionOpened),
     PositionChanged(PositionChanged),
@@ -51,6 +54,30 @@ pub struct PositionOpened {
     pub ts_init: UnixNanos,
 }
 
+impl PositionOpened {
+    #[must_use]
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id.clone(),
+            strategy_id: position.strategy_id.clone(),
+            instrument_id: position.instrument_id.clone(),
+            position_id: position.id.clone(),
+            account_id: position.account_id.clone(),
+            opening_order_id: position.opening_order_id.clone(),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency.clone(),
+            avg_px_open: position.avg_px_open,
+            ts_event: position.ts_opened,
+            ts_init,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct PositionChanged {
@@ -78,6 +105,36 @@ pub struct PositionChanged {
     pub ts_init: UnixNanos,
 }
 
+impl PositionChanged {
+    #[must_use]
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id.clone(),
+            strategy_id: position.strategy_id.clone(),
+            instrument_id: position.instrument_id.clone(),
+            position_id: position.id.clone(),
+            account_id: position.account_id.clone(),
+            opening_order_id: position.opening_order_id.clone(),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            peak_quantity: position.peak_qty,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency.clone(),
+            avg_px_open: position.avg_px_open,
+            avg_px_closed: position.avg_px_close.unwrap_or(0.0),
+            realized_return: position.realized_return.unwrap_or(0.0),
+            realized_pnl: realized_pnl(position),
+            unrealized_pnl: position.unrealized_pnl(&fill.last_px),
+            ts_opened: position.ts_opened,
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct PositionClosed {
@@ -107,3 +164,50 @@ pub struct PositionClosed {
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
 }
+
+impl PositionClosed {
+    /// # Panics
+    ///
+    /// If the `position` is not closed.
+    #[must_use]
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        let ts_closed = position.ts_closed.expect("position was not closed");
+        Self {
+            trader_id: position.trader_id.clone(),
+            strategy_id: position.strategy_id.clone(),
+            instrument_id: position.instrument_id.clone(),
+            position_id: position.id.clone(),
+            account_id: position.account_id.clone(),
+            opening_order_id: position.opening_order_id.clone(),
+            closing_order_id: position
+                .closing_order_id
+                .clone()
+                .expect("position was not closed"),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            peak_quantity: position.peak_qty,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency.clone(),
+            avg_px_open: position.avg_px_open,
+            avg_px_closed: position.avg_px_close.unwrap_or(0.0),
+            realized_return: position.realized_return.unwrap_or(0.0),
+            realized_pnl: realized_pnl(position),
+            unrealized_pnl: position.unrealized_pnl(&fill.last_px),
+            duration: position.duration_ns.unwrap_or(0) as TimedeltaNanos,
+            ts_opened: position.ts_opened,
+            ts_closed,
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
+
+fn realized_pnl(position: &Position) -> Money {
+    position
+        .realized_pnl
+        .clone()
+        .unwrap_or_else(|| Money::new(0.0, position.cost_currency.clone()))
+}
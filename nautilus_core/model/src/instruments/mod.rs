@@ -16,14 +16,15 @@
 mod synthetic;
 mod synthetic_api;
 
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 use crate::{
-    enums::{AssetClass, AssetType},
+    enums::{AssetClass, AssetType, LiquiditySide},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
-    types::{currency::Currency, price::Price, quantity::Quantity},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+#[derive(Clone, Debug)]
 pub struct Instrument {
     pub id: InstrumentId,
     pub native_symbol: Symbol,
@@ -48,3 +49,58 @@ pub struct Instrument {
     pub maker_fee: Decimal,
     pub taker_fee: Decimal,
 }
+
+impl Instrument {
+    /// Returns the notional value for the given `quantity` and `price`.
+    ///
+    /// The result will be in quote currency for standard instruments, or base
+    /// currency for inverse instruments (unless `use_quote_for_inverse`).
+    #[must_use]
+    pub fn notional_value(
+        &self,
+        quantity: &Quantity,
+        price: &Price,
+        use_quote_for_inverse: bool,
+    ) -> Money {
+        if self.is_inverse {
+            if use_quote_for_inverse {
+                // Quantity is notional in quote currency
+                return Money::new(quantity.as_f64(), self.quote_currency.clone());
+            }
+            let base_currency = self
+                .base_currency
+                .clone()
+                .expect("inverse instrument must have a `base_currency`");
+            Money::new(
+                quantity.as_f64() * self.multiplier.as_f64() * (1.0 / price.as_f64()),
+                base_currency,
+            )
+        } else {
+            Money::new(
+                quantity.as_f64() * self.multiplier.as_f64() * price.as_f64(),
+                self.quote_currency.clone(),
+            )
+        }
+    }
+
+    /// Returns the commission for a fill of `last_qty` at `last_px` with the
+    /// given `liquidity_side`, based on the instruments maker/taker fees.
+    ///
+    /// A fill with no liquidity side is charged zero commission.
+    #[must_use]
+    pub fn calculate_commission(
+        &self,
+        last_qty: &Quantity,
+        last_px: &Price,
+        liquidity_side: LiquiditySide,
+    ) -> Money {
+        let notional = self.notional_value(last_qty, last_px, false);
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => self.maker_fee,
+            LiquiditySide::Taker => self.taker_fee,
+            LiquiditySide::NoLiquiditySide => Decimal::ZERO,
+        };
+        let commission = notional.as_f64() * fee.to_f64().unwrap_or(0.0);
+        Money::new(commission, notional.currency)
+    }
+}
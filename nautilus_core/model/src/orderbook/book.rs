@@ -13,10 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::HashSet;
+
 use tabled::{settings::Style, Table, Tabled};
 use thiserror::Error;
 
-use super::{ladder::BookPrice, level::Level};
+use super::{
+    depth::{BookDepth, DepthLevel},
+    ladder::BookPrice,
+    level::Level,
+    own::OwnOrderBook,
+    queue::QueuePosition,
+    sequence::{SequenceError, SequenceMode, SequenceStatus, SequenceTracker},
+    snapshot::{BookSnapshot, SnapshotError, SnapshotOrder, SNAPSHOT_VERSION},
+};
 use crate::{
     data::{
         book::{BookOrder, OrderBookDelta},
@@ -36,17 +46,42 @@ pub struct OrderBook {
     pub sequence: u64,
     pub ts_last: u64,
     pub count: u64,
+    sequence_tracker: SequenceTracker,
+    repair_policy: BookRepairPolicy,
+    ts_last_bid: u64,
+    ts_last_ask: u64,
+}
+
+/// The policy for repairing an order book which becomes crossed or locked, such as
+/// when a venue publishes trades and depth on separate channels.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BookRepairPolicy {
+    /// The book is not repaired, and crosses are reported by `check_integrity`.
+    #[default]
+    Off = 0,
+    /// The levels crossing the side with the most recent `ts_event` are removed
+    /// from the other, stale, side.
+    DropStaleSide = 1,
+    /// The levels crossing the side of the latest update applied are removed from
+    /// the other side, regardless of `ts_event`.
+    TrustLatest = 2,
+    /// The levels through the price of each trade tick applied are removed, as
+    /// their liquidity must have been taken.
+    TradeThrough = 3,
 }
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq)]
 pub enum InvalidBookOperation {
     #[error("Invalid book operation: cannot pre-process order for {0} book")]
     PreProcessOrder(BookType),
     #[error("Invalid book operation: cannot add for {0} book")]
     Add(BookType),
+    #[error("Invalid book operation: price {0} is not a multiple of the price increment {1}")]
+    PriceIncrement(Price, Price),
 }
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq)]
 pub enum BookIntegrityError {
     #[error("Invalid book operation: order ID {0} not found")]
     OrderNotFound(u64),
@@ -60,6 +95,16 @@ pub enum BookIntegrityError {
     TooManyLevels(OrderSide, usize),
 }
 
+#[derive(Error, Debug, PartialEq)]
+pub enum OrderBookError {
+    #[error(transparent)]
+    InvalidBookOperation(#[from] InvalidBookOperation),
+    #[error(transparent)]
+    BookIntegrity(#[from] BookIntegrityError),
+    #[error(transparent)]
+    Sequence(#[from] SequenceError),
+}
+
 #[derive(Tabled)]
 struct OrderLevelDisplay {
     bids: String,
@@ -78,39 +123,154 @@ impl OrderBook {
             sequence: 0,
             ts_last: 0,
             count: 0,
+            sequence_tracker: SequenceTracker::new(SequenceMode::Off, 0),
+            repair_policy: BookRepairPolicy::Off,
+            ts_last_bid: 0,
+            ts_last_ask: 0,
         }
     }
 
+    /// Creates a new [`OrderBook`] with ladders backed by a [`PriceGrid`], giving
+    /// O(1) level access for instruments with a tight, known `price_increment`.
+    ///
+    /// Orders added or updated with a price which is not a multiple of the
+    /// `price_increment` are rejected with an [`InvalidBookOperation`].
+    ///
+    /// # Panics
+    ///
+    /// If `price_increment` is not positive (> 0).
+    ///
+    /// [`PriceGrid`]: super::grid::PriceGrid
+    #[must_use]
+    pub fn with_price_increment(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        price_increment: Price,
+    ) -> Self {
+        let mut book = Self::new(instrument_id, book_type);
+        book.bids = Ladder::with_price_increment(OrderSide::Buy, price_increment);
+        book.asks = Ladder::with_price_increment(OrderSide::Sell, price_increment);
+        book
+    }
+
+    /// Returns the price increment of the book ladders, or `None` if they accept
+    /// any price.
+    #[must_use]
+    pub fn price_increment(&self) -> Option<Price> {
+        self.bids.price_increment()
+    }
+
     pub fn reset(&mut self) {
         self.bids.clear();
         self.asks.clear();
         self.sequence = 0;
         self.ts_last = 0;
         self.count = 0;
+        self.sequence_tracker.reset();
+        self.ts_last_bid = 0;
+        self.ts_last_ask = 0;
     }
 
+    /// Sets the `policy` for repairing the book when it becomes crossed or locked.
+    pub fn set_repair_policy(&mut self, policy: BookRepairPolicy) {
+        self.repair_policy = policy;
+    }
+
+    #[must_use]
+    pub fn repair_policy(&self) -> BookRepairPolicy {
+        self.repair_policy
+    }
+
+    /// Sets the sequence validation `mode` for [`OrderBook::apply_delta_sequenced`],
+    /// buffering at most `max_buffered` deltas when in `Buffer` mode.
+    pub fn set_sequence_mode(&mut self, mode: SequenceMode, max_buffered: usize) {
+        self.sequence_tracker.mode = mode;
+        self.sequence_tracker.max_buffered = max_buffered;
+    }
+
+    #[must_use]
+    pub fn sequence_mode(&self) -> SequenceMode {
+        self.sequence_tracker.mode
+    }
+
+    /// Returns the number of buffered deltas waiting for a sequence gap to be filled.
+    #[must_use]
+    pub fn buffered_deltas(&self) -> usize {
+        self.sequence_tracker.buffered()
+    }
+
+    /// Adds the `order` to the book.
+    ///
+    /// # Panics
+    ///
+    /// If the order cannot be added, see [`OrderBook::try_add`].
     pub fn add(&mut self, order: BookOrder, ts_event: u64, sequence: u64) {
+        if let Err(e) = self.try_add(order, ts_event, sequence) {
+            panic!("{e}")
+        }
+    }
+
+    /// Adds the `order` to the book.
+    ///
+    /// # Errors
+    ///
+    /// - If the book is an `L1_TBBO` book.
+    /// - If the order price is not a multiple of the book price increment.
+    /// - If the order side is `NoOrderSide`.
+    pub fn try_add(
+        &mut self,
+        order: BookOrder,
+        ts_event: u64,
+        sequence: u64,
+    ) -> Result<(), OrderBookError> {
         let order = match self.book_type {
             BookType::L3_MBO => order, // No order pre-processing
             BookType::L2_MBP => self.pre_process_order(order),
-            BookType::L1_TBBO => panic!("{}", InvalidBookOperation::Add(self.book_type)),
+            BookType::L1_TBBO => return Err(InvalidBookOperation::Add(self.book_type).into()),
         };
+        self.check_price_increment(&order)?;
 
         match order.side {
             OrderSide::Buy => self.bids.add(order),
             OrderSide::Sell => self.asks.add(order),
-            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            _ => return Err(BookIntegrityError::NoOrderSide.into()),
         }
 
+        self.increment_side(order.side, ts_event);
+        self.repair_crossed(order.side);
         self.increment(ts_event, sequence);
+        Ok(())
     }
 
+    /// Updates the `order` in the book.
+    ///
+    /// # Panics
+    ///
+    /// If the order cannot be updated, see [`OrderBook::try_update`].
     pub fn update(&mut self, order: BookOrder, ts_event: u64, sequence: u64) {
+        if let Err(e) = self.try_update(order, ts_event, sequence) {
+            panic!("{e}")
+        }
+    }
+
+    /// Updates the `order` in the book.
+    ///
+    /// # Errors
+    ///
+    /// - If the order price is not a multiple of the book price increment.
+    /// - If the order side is `NoOrderSide`.
+    pub fn try_update(
+        &mut self,
+        order: BookOrder,
+        ts_event: u64,
+        sequence: u64,
+    ) -> Result<(), OrderBookError> {
+        self.check_price_increment(&order)?;
         let order = match self.book_type {
             BookType::L3_MBO => order, // No order pre-processing
             BookType::L2_MBP => self.pre_process_order(order),
             BookType::L1_TBBO => {
-                self.update_l1(order, ts_event, sequence);
+                self.update_l1(order, ts_event, sequence)?;
                 self.pre_process_order(order)
             }
         };
@@ -118,13 +278,37 @@ impl OrderBook {
         match order.side {
             OrderSide::Buy => self.bids.update(order),
             OrderSide::Sell => self.asks.update(order),
-            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            _ => return Err(BookIntegrityError::NoOrderSide.into()),
         }
 
+        self.increment_side(order.side, ts_event);
+        self.repair_crossed(order.side);
         self.increment(ts_event, sequence);
+        Ok(())
     }
 
+    /// Deletes the `order` from the book.
+    ///
+    /// # Panics
+    ///
+    /// If the order cannot be deleted, see [`OrderBook::try_delete`].
     pub fn delete(&mut self, order: BookOrder, ts_event: u64, sequence: u64) {
+        if let Err(e) = self.try_delete(order, ts_event, sequence) {
+            panic!("{e}")
+        }
+    }
+
+    /// Deletes the `order` from the book.
+    ///
+    /// # Errors
+    ///
+    /// If the order side is `NoOrderSide`.
+    pub fn try_delete(
+        &mut self,
+        order: BookOrder,
+        ts_event: u64,
+        sequence: u64,
+    ) -> Result<(), OrderBookError> {
         let order = match self.book_type {
             BookType::L3_MBO => order, // No order pre-processing
             BookType::L2_MBP => self.pre_process_order(order),
@@ -134,10 +318,12 @@ impl OrderBook {
         match order.side {
             OrderSide::Buy => self.bids.delete(order),
             OrderSide::Sell => self.asks.delete(order),
-            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            _ => return Err(BookIntegrityError::NoOrderSide.into()),
         }
 
+        self.increment_side(order.side, ts_event);
         self.increment(ts_event, sequence);
+        Ok(())
     }
 
     pub fn clear(&mut self, ts_event: u64, sequence: u64) {
@@ -156,13 +342,71 @@ impl OrderBook {
         self.increment(ts_event, sequence);
     }
 
+    /// Applies the `delta` to the book.
+    ///
+    /// # Panics
+    ///
+    /// If the delta cannot be applied, see [`OrderBook::try_apply_delta`].
     pub fn apply_delta(&mut self, delta: OrderBookDelta) {
+        if let Err(e) = self.try_apply_delta(delta) {
+            panic!("{e}")
+        }
+    }
+
+    /// Applies the `delta` to the book.
+    ///
+    /// # Errors
+    ///
+    /// If the delta order cannot be added, updated or deleted.
+    pub fn try_apply_delta(&mut self, delta: OrderBookDelta) -> Result<(), OrderBookError> {
         match delta.action {
-            BookAction::Add => self.add(delta.order, delta.ts_event, delta.sequence),
-            BookAction::Update => self.update(delta.order, delta.ts_event, delta.sequence),
-            BookAction::Delete => self.delete(delta.order, delta.ts_event, delta.sequence),
-            BookAction::Clear => self.clear(delta.ts_event, delta.sequence),
+            BookAction::Add => self.try_add(delta.order, delta.ts_event, delta.sequence),
+            BookAction::Update => self.try_update(delta.order, delta.ts_event, delta.sequence),
+            BookAction::Delete => self.try_delete(delta.order, delta.ts_event, delta.sequence),
+            BookAction::Clear => {
+                self.clear(delta.ts_event, delta.sequence);
+                Ok(())
+            }
+        }
+    }
+
+    /// Applies the delta after validating its sequence according to the sequence mode.
+    ///
+    /// In `Buffer` mode a delta ahead of the next sequence is held until the gap is
+    /// filled, then the buffered deltas are applied in order. A held delta returns
+    /// [`SequenceStatus::Buffered`] with the sequence still expected, as the book
+    /// does not yet reflect it.
+    ///
+    /// # Errors
+    ///
+    /// - If the delta is out of sequence (and cannot be buffered). The book is left
+    ///   unchanged by the delta, and the caller should rebuild it from a snapshot
+    ///   (starting with a `Clear` delta) on a gap or a full buffer.
+    /// - If the delta, or a buffered delta, cannot be applied.
+    pub fn apply_delta_sequenced(
+        &mut self,
+        delta: OrderBookDelta,
+    ) -> Result<SequenceStatus, OrderBookError> {
+        match self.sequence_tracker.check(&delta) {
+            Ok(()) => {}
+            Err(SequenceError::Gap { expected, .. })
+                if self.sequence_tracker.mode == SequenceMode::Buffer =>
+            {
+                self.sequence_tracker.buffer(delta)?;
+                return Ok(SequenceStatus::Buffered { expected });
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        self.sequence_tracker.accept(&delta);
+        self.try_apply_delta(delta)?;
+
+        while let Some(next) = self.sequence_tracker.pop_next() {
+            self.sequence_tracker.accept(&next);
+            self.try_apply_delta(next)?;
         }
+
+        Ok(SequenceStatus::Applied)
     }
 
     pub fn has_bid(&self) -> bool {
@@ -201,6 +445,23 @@ impl OrderBook {
         }
     }
 
+    /// Returns a quote of the best bid and ask, with the aggregated size of each
+    /// top level, or `None` if either side of the book is empty.
+    #[must_use]
+    pub fn top_quote(&self, ts_event: u64, ts_init: u64) -> Option<QuoteTick> {
+        let bid = self.bids.top()?;
+        let ask = self.asks.top()?;
+        Some(QuoteTick::new(
+            self.instrument_id.clone(),
+            bid.price.value,
+            ask.price.value,
+            bid.size(),
+            ask.size(),
+            ts_event,
+            ts_init,
+        ))
+    }
+
     pub fn spread(&self) -> Option<f64> {
         match (self.best_ask_price(), self.best_bid_price()) {
             (Some(ask), Some(bid)) => Some(ask.as_f64() - bid.as_f64()),
@@ -215,14 +476,368 @@ impl OrderBook {
         }
     }
 
+    /// Returns the volume imbalance of the top `num_levels` levels, in the range
+    /// [-1, 1], where a positive value indicates more bid volume.
+    #[must_use]
+    pub fn imbalance(&self, num_levels: usize) -> Option<f64> {
+        let bid_volume = self.bids.top_volume(num_levels);
+        let ask_volume = self.asks.top_volume(num_levels);
+        let total_volume = bid_volume + ask_volume;
+        if total_volume == 0.0 {
+            return None;
+        }
+        Some((bid_volume - ask_volume) / total_volume)
+    }
+
+    /// Returns the top of book prices weighted by the opposite side sizes, which
+    /// leans towards the side more likely to trade next.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        self.weighted_mid(1)
+    }
+
+    /// Returns the volume weighted average prices of the top `num_levels` levels
+    /// of each side, weighted by the opposite side volumes.
+    #[must_use]
+    pub fn weighted_mid(&self, num_levels: usize) -> Option<f64> {
+        match (
+            self.bids.top_vwap(num_levels),
+            self.asks.top_vwap(num_levels),
+        ) {
+            (Some(bid), Some(ask)) => {
+                let bid_volume = self.bids.top_volume(num_levels);
+                let ask_volume = self.asks.top_volume(num_levels);
+                Some(bid.mul_add(ask_volume, ask * bid_volume) / (bid_volume + ask_volume))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the volume weighted average price to fill a market order of `size`
+    /// on the given `side`, or `None` if the book cannot fill the entire size.
+    #[must_use]
+    pub fn vwap_to_fill(&self, side: OrderSide, size: Quantity) -> Option<f64> {
+        match side {
+            OrderSide::Buy => self.asks.vwap_to_fill(size),
+            OrderSide::Sell => self.bids.vwap_to_fill(size),
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        }
+    }
+
+    /// Returns the level at the given `price` on the given `side`, if any.
+    #[must_use]
+    pub fn level(&self, side: OrderSide, price: Price) -> Option<&Level> {
+        match side {
+            OrderSide::Buy => self.bids.level(price),
+            OrderSide::Sell => self.asks.level(price),
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        }
+    }
+
+    /// Returns the position of the order with the given `order_id` in its level
+    /// queue, or `None` if the order is not in the book.
+    ///
+    /// Only `L3_MBO` books hold individual orders, so this is always `None` for
+    /// other book types.
+    #[must_use]
+    pub fn queue_position(&self, order_id: u64) -> Option<QueuePosition> {
+        if self.book_type != BookType::L3_MBO {
+            return None;
+        }
+        self.bids
+            .queue_position(order_id)
+            .or_else(|| self.asks.queue_position(order_id))
+    }
+
+    /// Returns the aggregated depth of the top `num_levels` levels of each side.
+    #[must_use]
+    pub fn depth(&self, num_levels: usize) -> BookDepth {
+        BookDepth {
+            instrument_id: self.instrument_id.clone(),
+            bids: self.bids.depth(num_levels),
+            asks: self.asks.depth(num_levels),
+            sequence: self.sequence,
+            ts_last: self.ts_last,
+        }
+    }
+
+    #[must_use]
+    pub fn bid_volume_to_price(&self, price: Price) -> f64 {
+        self.bids.volume_to_price(price)
+    }
+
+    #[must_use]
+    pub fn ask_volume_to_price(&self, price: Price) -> f64 {
+        self.asks.volume_to_price(price)
+    }
+
+    #[must_use]
+    pub fn bid_price_for_volume(&self, volume: f64) -> Option<Price> {
+        self.bids.price_for_volume(volume)
+    }
+
+    #[must_use]
+    pub fn ask_price_for_volume(&self, volume: f64) -> Option<Price> {
+        self.asks.price_for_volume(volume)
+    }
+
+    #[must_use]
+    pub fn group_bids(&self, group_size: Price, num_levels: usize) -> Vec<DepthLevel> {
+        self.bids.group_by(group_size, num_levels)
+    }
+
+    #[must_use]
+    pub fn group_asks(&self, group_size: Price, num_levels: usize) -> Vec<DepthLevel> {
+        self.asks.group_by(group_size, num_levels)
+    }
+
+    /// Returns an L2_MBP view of the book, where the orders at each price level
+    /// are aggregated into a single order.
+    #[must_use]
+    pub fn to_l2_mbp(&self) -> Self {
+        let mut book = Self::new(self.instrument_id.clone(), BookType::L2_MBP);
+        for level in self.bids.levels.values().chain(self.asks.levels.values()) {
+            let order = book.pre_process_order(BookOrder::new(
+                level.price.side,
+                level.price.value,
+                level.size(),
+                0,
+            ));
+            match order.side {
+                OrderSide::Buy => book.bids.add(order),
+                OrderSide::Sell => book.asks.add(order),
+                _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            }
+        }
+        book.sequence = self.sequence;
+        book.ts_last = self.ts_last;
+        book.count = self.count;
+        book
+    }
+
+    /// Returns an L2_MBP view of the book with the size of our `own` working orders
+    /// subtracted from each price level, leaving the liquidity available to us.
+    ///
+    /// Levels with no size remaining are omitted.
+    #[must_use]
+    pub fn subtract_own(&self, own: &OwnOrderBook) -> Self {
+        let mut book = Self::new(self.instrument_id.clone(), BookType::L2_MBP);
+        for level in self.bids.levels.values().chain(self.asks.levels.values()) {
+            let side = level.price.side;
+            let price = level.price.value;
+            let size = level.size();
+            let own_raw = own.size_at(side, price).map_or(0, |own_size| own_size.raw);
+            if own_raw >= size.raw {
+                continue; // No liquidity available to us
+            }
+
+            let order = book.pre_process_order(BookOrder::new(
+                side,
+                price,
+                Quantity::from_raw(size.raw - own_raw, size.precision),
+                0,
+            ));
+            match order.side {
+                OrderSide::Buy => book.bids.add(order),
+                OrderSide::Sell => book.asks.add(order),
+                _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            }
+        }
+        book.sequence = self.sequence;
+        book.ts_last = self.ts_last;
+        book.count = self.count;
+        book
+    }
+
+    /// Returns a snapshot of the full state of the book.
+    #[must_use]
+    pub fn to_snapshot(&self) -> BookSnapshot {
+        let orders = self
+            .bids
+            .levels
+            .values()
+            .chain(self.asks.levels.values())
+            .flat_map(|level| level.orders.iter().map(SnapshotOrder::from))
+            .collect();
+
+        BookSnapshot {
+            version: SNAPSHOT_VERSION,
+            instrument_id: self.instrument_id.to_string(),
+            book_type: self.book_type as u8,
+            sequence: self.sequence,
+            ts_last: self.ts_last,
+            count: self.count,
+            orders,
+        }
+    }
+
+    /// Restores a book from the `snapshot`, including its `sequence`, `ts_last`
+    /// and `count`.
+    ///
+    /// # Errors
+    ///
+    /// - If the snapshot contains an invalid instrument ID, book type, order side or
+    ///   precision.
+    /// - If the snapshot contains more than one order with the same ID on a side.
+    pub fn from_snapshot(snapshot: &BookSnapshot) -> Result<Self, SnapshotError> {
+        let mut book = Self::new(snapshot.instrument_id()?, snapshot.book_type()?);
+        let mut order_ids = HashSet::with_capacity(snapshot.orders.len());
+        for order in &snapshot.orders {
+            // Orders were pre-processed when added to the snapshotted book
+            let order = BookOrder::try_from(order)?;
+            if !order_ids.insert((order.side as u8, order.order_id)) {
+                return Err(SnapshotError::DuplicateOrderId(order.order_id));
+            }
+            match order.side {
+                OrderSide::Buy => book.bids.add(order),
+                OrderSide::Sell => book.asks.add(order),
+                _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            }
+        }
+        book.sequence = snapshot.sequence;
+        book.ts_last = snapshot.ts_last;
+        book.count = snapshot.count;
+        Ok(book)
+    }
+
+    /// Encodes the full state of the book to compact binary, see [`BookSnapshot`].
+    ///
+    /// # Errors
+    ///
+    /// If the snapshot cannot be encoded.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SnapshotError> {
+        self.to_snapshot().to_bytes()
+    }
+
+    /// Restores a book from bytes encoded with [`OrderBook::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// If the bytes are not a valid snapshot.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        Self::from_snapshot(&BookSnapshot::from_bytes(bytes)?)
+    }
+
+    /// Returns the deltas which rebuild the book: a `Clear` followed by an `Add`
+    /// for each order, from the best price outwards.
+    ///
+    /// The deltas carry the book `sequence` and `ts_last`. An `L1_TBBO` book does
+    /// not support adds, so its top levels are emitted as `Update` deltas instead.
+    #[must_use]
+    pub fn to_deltas(&self, ts_init: u64) -> Vec<OrderBookDelta> {
+        let action = match self.book_type {
+            BookType::L1_TBBO => BookAction::Update,
+            _ => BookAction::Add,
+        };
+        let delta = |action, order| {
+            OrderBookDelta::new(
+                self.instrument_id.clone(),
+                action,
+                order,
+                0,
+                self.sequence,
+                self.ts_last,
+                ts_init,
+            )
+        };
+
+        let clear = BookOrder::new(
+            OrderSide::NoOrderSide,
+            Price::from_raw(0, 0),
+            Quantity::zero(0),
+            0,
+        );
+        let mut deltas = vec![delta(BookAction::Clear, clear)];
+        for level in self.bids.levels.values().chain(self.asks.levels.values()) {
+            deltas.extend(level.orders.iter().map(|order| delta(action, *order)));
+        }
+        deltas
+    }
+
+    /// Builds a book by applying the `deltas` in order, such as those returned by
+    /// [`OrderBook::to_deltas`].
+    ///
+    /// The `count` of the new book is the number of deltas applied.
+    ///
+    /// # Errors
+    ///
+    /// If any delta cannot be applied, see [`OrderBook::try_apply_delta`].
+    pub fn from_deltas(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        deltas: Vec<OrderBookDelta>,
+    ) -> Result<Self, OrderBookError> {
+        let mut book = Self::new(instrument_id, book_type);
+        for delta in deltas {
+            book.try_apply_delta(delta)?;
+        }
+        Ok(book)
+    }
+
+    /// Updates the book with the quote `tick`.
+    ///
+    /// # Panics
+    ///
+    /// If the tick cannot be applied, see [`OrderBook::try_update_quote_tick`].
     pub fn update_quote_tick(&mut self, tick: &QuoteTick) {
-        self.update_bid(BookOrder::from_quote_tick(tick, OrderSide::Buy));
-        self.update_ask(BookOrder::from_quote_tick(tick, OrderSide::Sell));
+        if let Err(e) = self.try_update_quote_tick(tick) {
+            panic!("{e}")
+        }
     }
 
+    /// Updates the book with the quote `tick`, taking its prices as the top of each side.
+    ///
+    /// # Errors
+    ///
+    /// If the bid or ask price is not a multiple of the book price increment, in which
+    /// case the book is left unchanged.
+    pub fn try_update_quote_tick(&mut self, tick: &QuoteTick) -> Result<(), OrderBookError> {
+        let bid = BookOrder::from_quote_tick(tick, OrderSide::Buy);
+        let ask = BookOrder::from_quote_tick(tick, OrderSide::Sell);
+        self.check_price_increment(&bid)?;
+        self.check_price_increment(&ask)?;
+
+        self.update_bid(bid);
+        self.update_ask(ask);
+        Ok(())
+    }
+
+    /// Updates the book with the trade `tick`.
+    ///
+    /// # Panics
+    ///
+    /// If the tick cannot be applied, see [`OrderBook::try_update_trade_tick`].
     pub fn update_trade_tick(&mut self, tick: &TradeTick) {
-        self.update_bid(BookOrder::from_trade_tick(tick, OrderSide::Buy));
+        if let Err(e) = self.try_update_trade_tick(tick) {
+            panic!("{e}")
+        }
+    }
+
+    /// Updates the book with the trade `tick`.
+    ///
+    /// An `L1_TBBO` book, or any book without the `TradeThrough` repair policy,
+    /// takes the trade price as the top of each side. Otherwise the levels through
+    /// the trade price are removed from both sides.
+    ///
+    /// # Errors
+    ///
+    /// If the trade price is not a multiple of the book price increment, in which
+    /// case the book is left unchanged.
+    pub fn try_update_trade_tick(&mut self, tick: &TradeTick) -> Result<(), OrderBookError> {
+        let bid = BookOrder::from_trade_tick(tick, OrderSide::Buy);
+        self.check_price_increment(&bid)?;
+
+        if self.repair_policy == BookRepairPolicy::TradeThrough
+            && self.book_type != BookType::L1_TBBO
+        {
+            self.bids.remove_through(tick.price, false);
+            self.asks.remove_through(tick.price, false);
+            return Ok(());
+        }
+
+        self.update_bid(bid);
         self.update_ask(BookOrder::from_trade_tick(tick, OrderSide::Sell));
+        Ok(())
     }
 
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
@@ -292,24 +907,26 @@ impl OrderBook {
     }
 
     fn check_integrity_l3(&self) -> Result<(), BookIntegrityError> {
-        let top_bid_level = self.bids.top();
-        let top_ask_level = self.asks.top();
-
-        if top_bid_level.is_none() || top_ask_level.is_none() {
-            return Ok(());
-        }
+        self.check_crossed()
+    }
 
-        let best_bid = top_bid_level.unwrap().price;
-        let best_ask = top_ask_level.unwrap().price;
+    fn check_crossed(&self) -> Result<(), BookIntegrityError> {
+        if let (Some(top_bid_level), Some(top_ask_level)) = (self.bids.top(), self.asks.top()) {
+            let best_bid = top_bid_level.price;
+            let best_ask = top_ask_level.price;
 
-        if best_bid >= best_ask {
-            return Err(BookIntegrityError::OrdersCrossed(best_bid, best_ask));
+            // Compare the price values, as book prices are ordered by their side
+            if best_bid.value >= best_ask.value {
+                return Err(BookIntegrityError::OrdersCrossed(best_bid, best_ask));
+            }
         }
 
         Ok(())
     }
 
     fn check_integrity_l2(&self) -> Result<(), BookIntegrityError> {
+        self.check_crossed()?;
+
         for (_, bid_level) in self.bids.levels.iter() {
             let num_orders = bid_level.orders.len();
             if num_orders > 1 {
@@ -350,13 +967,57 @@ impl OrderBook {
         Ok(())
     }
 
+    fn increment_side(&mut self, side: OrderSide, ts_event: u64) {
+        match side {
+            OrderSide::Buy => self.ts_last_bid = ts_event,
+            OrderSide::Sell => self.ts_last_ask = ts_event,
+            _ => {}
+        }
+    }
+
+    /// Removes the levels of the stale side which cross or lock the best price of
+    /// the other side, according to the repair policy.
+    fn repair_crossed(&mut self, updated_side: OrderSide) {
+        let stale_side = match self.repair_policy {
+            BookRepairPolicy::DropStaleSide if self.ts_last_bid < self.ts_last_ask => {
+                OrderSide::Buy
+            }
+            BookRepairPolicy::DropStaleSide if self.ts_last_ask < self.ts_last_bid => {
+                OrderSide::Sell
+            }
+            BookRepairPolicy::DropStaleSide | BookRepairPolicy::TrustLatest => match updated_side {
+                OrderSide::Buy => OrderSide::Sell,
+                _ => OrderSide::Buy,
+            },
+            _ => return,
+        };
+
+        match stale_side {
+            OrderSide::Buy => {
+                if let Some(best_ask_price) = self.best_ask_price() {
+                    self.bids.remove_through(best_ask_price, true);
+                }
+            }
+            _ => {
+                if let Some(best_bid_price) = self.best_bid_price() {
+                    self.asks.remove_through(best_bid_price, true);
+                }
+            }
+        }
+    }
+
     fn increment(&mut self, ts_event: u64, sequence: u64) {
         self.ts_last = ts_event;
         self.sequence = sequence;
         self.count += 1;
     }
 
-    fn update_l1(&mut self, order: BookOrder, ts_event: u64, sequence: u64) {
+    fn update_l1(
+        &mut self,
+        order: BookOrder,
+        ts_event: u64,
+        sequence: u64,
+    ) -> Result<(), BookIntegrityError> {
         // Because of the way we typically get updates from a L1_TBBO order book (bid
         // and ask updates at the same time), its quite probable that the last
         // bid is now the ask price we are trying to insert (or vice versa). We
@@ -377,8 +1038,10 @@ impl OrderBook {
                     }
                 }
             }
-            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            _ => return Err(BookIntegrityError::NoOrderSide),
         }
+
+        Ok(())
     }
 
     fn update_bid(&mut self, order: BookOrder) {
@@ -417,6 +1080,15 @@ impl OrderBook {
         }
     }
 
+    fn check_price_increment(&self, order: &BookOrder) -> Result<(), InvalidBookOperation> {
+        match self.price_increment() {
+            Some(price_increment) if !self.bids.accepts_price(order.price) => Err(
+                InvalidBookOperation::PriceIncrement(order.price, price_increment),
+            ),
+            _ => Ok(()),
+        }
+    }
+
     fn pre_process_order(&self, mut order: BookOrder) -> BookOrder {
         match self.book_type {
             // Because a L1_TBBO only has one level per side, we replace the
@@ -522,6 +1194,85 @@ mod tests {
         assert_eq!(book.best_ask_size(), Some(Quantity::from("2.0")));
         assert_eq!(book.has_ask(), true);
     }
+
+    #[test]
+    fn test_try_add_to_l1_book_returns_error() {
+        let mut book = create_stub_book(BookType::L1_TBBO);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+
+        let result = book.try_add(order, 100, 1);
+
+        assert_eq!(
+            result,
+            Err(InvalidBookOperation::Add(BookType::L1_TBBO).into())
+        );
+        assert_eq!(book.count, 0);
+    }
+
+    #[test]
+    fn test_try_operations_with_no_order_side_return_error() {
+        let order = BookOrder::new(
+            OrderSide::NoOrderSide,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+
+        for book_type in [BookType::L1_TBBO, BookType::L2_MBP, BookType::L3_MBO] {
+            let mut book = create_stub_book(book_type);
+            let error = Err(BookIntegrityError::NoOrderSide.into());
+
+            if book_type != BookType::L1_TBBO {
+                assert_eq!(book.try_add(order, 100, 1), error);
+            }
+            assert_eq!(book.try_update(order, 100, 1), error);
+            assert_eq!(book.try_delete(order, 100, 1), error);
+            assert_eq!(book.count, 0);
+        }
+    }
+
+    #[test]
+    fn test_try_apply_delta() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        let delta = OrderBookDelta::new(
+            book.instrument_id.clone(),
+            BookAction::Add,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            0,
+            1,
+            100,
+            100,
+        );
+
+        book.try_apply_delta(delta).unwrap();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.000")));
+        assert_eq!(book.count, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid book operation: cannot add for L1_TBBO book")]
+    fn test_add_to_l1_book_panics() {
+        let mut book = create_stub_book(BookType::L1_TBBO);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+
+        book.add(order, 100, 1);
+    }
     #[test]
     fn test_spread_with_no_bids_or_asks() {
         let book = create_stub_book(BookType::L3_MBO);
@@ -691,4 +1442,727 @@ mod tests {
         println!("{}", pprint_output);
         assert_eq!(pprint_output, expected_output);
     }
+
+    fn create_stub_l3_book() -> OrderBook {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            100,
+            1,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("2.0"),
+                2,
+            ),
+            200,
+            2,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("0.990"),
+                Quantity::from("3.0"),
+                3,
+            ),
+            300,
+            3,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.010"),
+                Quantity::from("4.0"),
+                4,
+            ),
+            400,
+            4,
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.020"),
+                Quantity::from("5.0"),
+                5,
+            ),
+            500,
+            5,
+        );
+        book
+    }
+
+    #[test]
+    fn test_depth() {
+        let book = create_stub_l3_book();
+
+        let depth = book.depth(1);
+
+        assert_eq!(depth.instrument_id, book.instrument_id);
+        assert_eq!(depth.sequence, 5);
+        assert_eq!(depth.ts_last, 500);
+        assert_eq!(
+            depth.bids,
+            vec![DepthLevel::new(
+                Price::from("1.000"),
+                Quantity::from("3.0"),
+                2
+            )]
+        );
+        assert_eq!(
+            depth.asks,
+            vec![DepthLevel::new(
+                Price::from("1.010"),
+                Quantity::from("4.0"),
+                1
+            )]
+        );
+    }
+
+    #[test]
+    fn test_cumulative_volume() {
+        let book = create_stub_l3_book();
+
+        assert_eq!(book.bid_volume_to_price(Price::from("0.990")), 6.0);
+        assert_eq!(book.ask_volume_to_price(Price::from("1.015")), 4.0);
+        assert_eq!(book.bid_price_for_volume(4.0), Some(Price::from("0.990")));
+        assert_eq!(book.ask_price_for_volume(10.0), None);
+    }
+
+    #[test]
+    fn test_group_bids_and_asks() {
+        let book = create_stub_l3_book();
+
+        let bids = book.group_bids(Price::from("0.05"), 10);
+        let asks = book.group_asks(Price::from("0.05"), 10);
+
+        assert_eq!(
+            bids,
+            vec![
+                DepthLevel::new(Price::from("1.000"), Quantity::from("3.0"), 2),
+                DepthLevel::new(Price::from("0.950"), Quantity::from("3.0"), 1),
+            ]
+        );
+        assert_eq!(
+            asks,
+            vec![DepthLevel::new(
+                Price::from("1.050"),
+                Quantity::from("9.0"),
+                2
+            )]
+        );
+    }
+
+    #[test]
+    fn test_to_l2_mbp() {
+        let book = create_stub_l3_book();
+
+        let l2 = book.to_l2_mbp();
+
+        assert_eq!(l2.book_type, BookType::L2_MBP);
+        assert_eq!(l2.sequence, book.sequence);
+        assert_eq!(l2.count, book.count);
+        assert_eq!(l2.best_bid_price(), Some(Price::from("1.000")));
+        assert_eq!(l2.best_bid_size(), Some(Quantity::from("3.0")));
+        assert_eq!(l2.best_ask_size(), Some(Quantity::from("4.0")));
+        assert_eq!(l2.depth(10).bids.len(), 2);
+        assert!(l2.check_integrity().is_ok());
+    }
+
+    #[test]
+    fn test_analytics_with_no_orders() {
+        let book = create_stub_book(BookType::L2_MBP);
+
+        assert_eq!(book.imbalance(5), None);
+        assert_eq!(book.microprice(), None);
+        assert_eq!(book.weighted_mid(5), None);
+        assert_eq!(
+            book.vwap_to_fill(OrderSide::Buy, Quantity::from("1.0")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_imbalance() {
+        let book = create_stub_l3_book();
+
+        // Bids 3.0 vs asks 4.0 at the top, then 6.0 vs 9.0 over two levels
+        assert!((book.imbalance(1).unwrap() - -1.0 / 7.0).abs() < 1e-12);
+        assert!((book.imbalance(2).unwrap() - -0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_microprice() {
+        let book = create_stub_l3_book();
+
+        // (1.000 * 4.0 + 1.010 * 3.0) / 7.0
+        assert!((book.microprice().unwrap() - 7.03 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weighted_mid() {
+        let book = create_stub_l3_book();
+
+        // Bid VWAP 0.995 over 6.0, ask VWAP (4.04 + 5.10) / 9.0 over 9.0
+        let ask_vwap = 9.14 / 9.0;
+        let expected = (0.995 * 9.0 + ask_vwap * 6.0) / 15.0;
+        assert!((book.weighted_mid(2).unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_vwap_to_fill() {
+        let book = create_stub_l3_book();
+
+        let buy_vwap = book.vwap_to_fill(OrderSide::Buy, Quantity::from("6.0"));
+        let sell_vwap = book.vwap_to_fill(OrderSide::Sell, Quantity::from("2.0"));
+
+        // Buy 4.0 @ 1.010 and 2.0 @ 1.020
+        assert!((buy_vwap.unwrap() - 6.08 / 6.0).abs() < 1e-12);
+        assert_eq!(sell_vwap, Some(1.0));
+        assert_eq!(
+            book.vwap_to_fill(OrderSide::Sell, Quantity::from("7.0")),
+            None
+        );
+    }
+
+    fn create_delta(action: BookAction, price: &str, size: &str, sequence: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap(),
+            action,
+            BookOrder::new(OrderSide::Buy, Price::from(price), Quantity::from(size), 0),
+            0,
+            sequence,
+            sequence * 100,
+            sequence * 100,
+        )
+    }
+
+    #[test]
+    fn test_queue_position() {
+        let book = create_stub_l3_book();
+
+        assert_eq!(
+            book.queue_position(1),
+            Some(QueuePosition::new(0, Quantity::from("0.0")))
+        );
+        assert_eq!(
+            book.queue_position(2),
+            Some(QueuePosition::new(1, Quantity::from("1.0")))
+        );
+        assert_eq!(book.queue_position(99), None);
+    }
+
+    #[test]
+    fn test_queue_position_updates_with_deltas() {
+        let mut book = create_stub_l3_book();
+        let order1 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("0.5"),
+            1,
+        );
+
+        book.update(order1, 200, 10);
+
+        assert_eq!(
+            book.queue_position(2),
+            Some(QueuePosition::new(1, Quantity::from("0.5")))
+        );
+
+        book.delete(order1, 300, 11);
+
+        assert_eq!(
+            book.queue_position(2),
+            Some(QueuePosition::new(0, Quantity::from("0.0")))
+        );
+    }
+
+    #[test]
+    fn test_queue_position_when_not_l3_book() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+        book.add(order, 100, 1);
+
+        assert_eq!(book.queue_position(order.price.raw as u64), None);
+    }
+
+    #[test]
+    fn test_apply_delta_sequenced_when_off_accepts_gaps() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.000", "1.0", 1))
+            .unwrap();
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "0.990", "1.0", 5))
+            .unwrap();
+
+        assert_eq!(book.sequence_mode(), SequenceMode::Off);
+        assert_eq!(book.sequence, 5);
+        assert_eq!(book.count, 2);
+    }
+
+    #[test]
+    fn test_apply_delta_sequenced_rejects_out_of_order() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.set_sequence_mode(SequenceMode::Reject, 0);
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.000", "1.0", 1))
+            .unwrap();
+        book.apply_delta_sequenced(create_delta(BookAction::Update, "1.000", "2.0", 2))
+            .unwrap();
+
+        let gap = book.apply_delta_sequenced(create_delta(BookAction::Add, "0.990", "1.0", 4));
+        let duplicate =
+            book.apply_delta_sequenced(create_delta(BookAction::Update, "1.000", "3.0", 2));
+        let regression =
+            book.apply_delta_sequenced(create_delta(BookAction::Update, "1.000", "3.0", 1));
+
+        assert_eq!(
+            gap,
+            Err(SequenceError::Gap {
+                expected: 3,
+                received: 4
+            }
+            .into())
+        );
+        assert_eq!(duplicate, Err(SequenceError::Duplicate(2).into()));
+        assert_eq!(
+            regression,
+            Err(SequenceError::Regression {
+                last: 2,
+                received: 1
+            }
+            .into())
+        );
+        assert_eq!(book.sequence, 2);
+        assert_eq!(book.count, 2);
+        assert_eq!(book.best_bid_size(), Some(Quantity::from("2.0")));
+    }
+
+    #[test]
+    fn test_apply_delta_sequenced_buffers_until_gap_filled() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.set_sequence_mode(SequenceMode::Buffer, 10);
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.000", "1.0", 1))
+            .unwrap();
+
+        let buffered =
+            book.apply_delta_sequenced(create_delta(BookAction::Update, "1.000", "3.0", 3));
+
+        assert_eq!(buffered, Ok(SequenceStatus::Buffered { expected: 2 }));
+        assert_eq!(book.buffered_deltas(), 1);
+        assert_eq!(book.best_bid_size(), Some(Quantity::from("1.0")));
+
+        let applied =
+            book.apply_delta_sequenced(create_delta(BookAction::Update, "1.000", "2.0", 2));
+
+        assert_eq!(applied, Ok(SequenceStatus::Applied));
+        assert_eq!(book.buffered_deltas(), 0);
+        assert_eq!(book.sequence, 3);
+        assert_eq!(book.best_bid_size(), Some(Quantity::from("3.0")));
+    }
+
+    #[test]
+    fn test_apply_delta_sequenced_reports_expected_sequence_while_buffering() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.set_sequence_mode(SequenceMode::Buffer, 10);
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.000", "1.0", 1))
+            .unwrap();
+
+        let first = book.apply_delta_sequenced(create_delta(BookAction::Add, "0.990", "1.0", 4));
+        let second = book.apply_delta_sequenced(create_delta(BookAction::Add, "0.980", "1.0", 3));
+
+        assert_eq!(first, Ok(SequenceStatus::Buffered { expected: 2 }));
+        assert_eq!(second, Ok(SequenceStatus::Buffered { expected: 2 }));
+        assert_eq!(book.sequence, 1);
+        assert_eq!(book.count, 1);
+        assert_eq!(book.buffered_deltas(), 2);
+    }
+
+    #[test]
+    fn test_apply_delta_sequenced_resyncs_from_snapshot() {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.set_sequence_mode(SequenceMode::Buffer, 1);
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.000", "1.0", 1))
+            .unwrap();
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "0.990", "1.0", 3))
+            .unwrap();
+
+        let result = book.apply_delta_sequenced(create_delta(BookAction::Add, "0.980", "1.0", 4));
+
+        assert_eq!(
+            result,
+            Err(SequenceError::BufferFull {
+                expected: 2,
+                buffered: 1
+            }
+            .into())
+        );
+
+        // Rebuild from a snapshot at sequence 10
+        book.apply_delta_sequenced(create_delta(BookAction::Clear, "0", "0", 10))
+            .unwrap();
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.010", "5.0", 10))
+            .unwrap();
+        book.apply_delta_sequenced(create_delta(BookAction::Add, "1.000", "5.0", 10))
+            .unwrap();
+        book.apply_delta_sequenced(create_delta(BookAction::Update, "1.010", "4.0", 11))
+            .unwrap();
+
+        assert_eq!(book.sequence, 11);
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.010")));
+        assert_eq!(book.best_bid_size(), Some(Quantity::from("4.0")));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let book = create_stub_l3_book();
+
+        let restored = OrderBook::from_bytes(&book.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(restored.instrument_id, book.instrument_id);
+        assert_eq!(restored.book_type, BookType::L3_MBO);
+        assert_eq!(restored.sequence, book.sequence);
+        assert_eq!(restored.ts_last, book.ts_last);
+        assert_eq!(restored.count, book.count);
+        assert_eq!(restored.depth(10), book.depth(10));
+        assert_eq!(restored.queue_position(2), book.queue_position(2));
+        assert_eq!(restored.pprint(10), book.pprint(10));
+    }
+
+    #[test]
+    fn test_from_bytes_with_invalid_bytes() {
+        assert!(OrderBook::from_bytes(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_with_invalid_instrument_id() {
+        let mut snapshot = create_stub_l3_book().to_snapshot();
+        snapshot.instrument_id = "X.".to_string();
+
+        let result = OrderBook::from_bytes(&snapshot.to_bytes().unwrap());
+
+        assert!(matches!(result, Err(SnapshotError::InvalidField(_))));
+    }
+
+    #[test]
+    fn test_from_bytes_with_invalid_precision() {
+        let mut snapshot = create_stub_l3_book().to_snapshot();
+        snapshot.orders[0].price_precision = 10;
+
+        let result = OrderBook::from_bytes(&snapshot.to_bytes().unwrap());
+
+        assert!(matches!(result, Err(SnapshotError::InvalidField(_))));
+    }
+
+    #[test]
+    fn test_from_bytes_with_duplicate_order_id() {
+        let mut snapshot = create_stub_l3_book().to_snapshot();
+        let duplicate = snapshot.orders[0].clone();
+        snapshot.orders.push(duplicate);
+
+        let result = OrderBook::from_bytes(&snapshot.to_bytes().unwrap());
+
+        assert!(matches!(result, Err(SnapshotError::DuplicateOrderId(1))));
+    }
+
+    #[test]
+    fn test_to_deltas() {
+        let book = create_stub_l3_book();
+
+        let deltas = book.to_deltas(500);
+
+        assert_eq!(deltas.len(), 6);
+        assert_eq!(deltas[0].action, BookAction::Clear);
+        assert!(deltas[1..].iter().all(|d| d.action == BookAction::Add));
+        assert!(deltas.iter().all(|d| d.sequence == book.sequence
+            && d.ts_event == book.ts_last
+            && d.ts_init == 500));
+        assert_eq!(deltas[1].order.order_id, 1);
+        assert_eq!(deltas[2].order.order_id, 2);
+    }
+
+    #[test]
+    fn test_deltas_round_trip() {
+        let book = create_stub_l3_book();
+
+        let restored = OrderBook::from_deltas(
+            book.instrument_id.clone(),
+            book.book_type,
+            book.to_deltas(0),
+        )
+        .unwrap();
+
+        assert_eq!(restored.sequence, book.sequence);
+        assert_eq!(restored.ts_last, book.ts_last);
+        assert_eq!(restored.count, 6);
+        assert_eq!(restored.depth(10), book.depth(10));
+        assert_eq!(restored.queue_position(2), book.queue_position(2));
+    }
+
+    #[test]
+    fn test_deltas_round_trip_for_l1_book() {
+        let mut book = create_stub_book(BookType::L1_TBBO);
+        book.update_quote_tick(&QuoteTick {
+            instrument_id: book.instrument_id.clone(),
+            bid: Price::from("1.000"),
+            ask: Price::from("1.010"),
+            bid_size: Quantity::from("5.0"),
+            ask_size: Quantity::from("6.0"),
+            ts_event: 100,
+            ts_init: 100,
+        });
+
+        let deltas = book.to_deltas(0);
+        let restored =
+            OrderBook::from_deltas(book.instrument_id.clone(), book.book_type, deltas.clone())
+                .unwrap();
+
+        assert!(deltas[1..].iter().all(|d| d.action == BookAction::Update));
+        assert_eq!(restored.depth(1), book.depth(1));
+    }
+
+    #[test]
+    fn test_from_deltas_with_invalid_delta() {
+        let book = create_stub_l3_book();
+
+        let result = OrderBook::from_deltas(
+            book.instrument_id.clone(),
+            BookType::L1_TBBO,
+            vec![book.to_deltas(0)[1].clone()],
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(InvalidBookOperation::Add(BookType::L1_TBBO).into())
+        );
+    }
+
+    #[test]
+    fn test_price_increment_book_matches_default_book() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut tree = OrderBook::new(instrument_id.clone(), BookType::L3_MBO);
+        let mut grid =
+            OrderBook::with_price_increment(instrument_id, BookType::L3_MBO, Price::from("0.01"));
+        let deltas = create_stub_l3_book().to_deltas(0);
+        let moved = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("0.950"),
+            Quantity::from("2.0"),
+            2,
+        );
+
+        for book in [&mut tree, &mut grid] {
+            for delta in deltas.clone() {
+                book.apply_delta(delta);
+            }
+            book.update(moved, 400, 10);
+            book.delete(deltas[1].order, 500, 11);
+        }
+
+        assert_eq!(tree.price_increment(), None);
+        assert_eq!(grid.price_increment(), Some(Price::from("0.01")));
+        assert_eq!(grid.depth(10), tree.depth(10));
+        assert_eq!(grid.queue_position(2), tree.queue_position(2));
+        assert_eq!(grid.pprint(10), tree.pprint(10));
+    }
+
+    #[test]
+    fn test_price_increment_book_rejects_off_grid_price() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut book =
+            OrderBook::with_price_increment(instrument_id, BookType::L2_MBP, Price::from("0.01"));
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.005"),
+            Quantity::from("1.0"),
+            1,
+        );
+
+        let result = book.try_add(order, 100, 1);
+
+        assert_eq!(
+            result,
+            Err(
+                InvalidBookOperation::PriceIncrement(Price::from("1.005"), Price::from("0.01"))
+                    .into()
+            )
+        );
+        assert_eq!(book.try_update(order, 100, 1), result);
+        assert!(!book.has_bid());
+        assert_eq!(book.count, 0);
+    }
+
+    #[test]
+    fn test_price_increment_book_rejects_off_grid_ticks() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut book = OrderBook::with_price_increment(
+            instrument_id.clone(),
+            BookType::L1_TBBO,
+            Price::from("0.01"),
+        );
+        let quote = QuoteTick::new(
+            instrument_id.clone(),
+            Price::from("1.000"),
+            Price::from("1.015"),
+            Quantity::from("1.0"),
+            Quantity::from("1.0"),
+            0,
+            0,
+        );
+        let trade = TradeTick::new(
+            instrument_id,
+            Price::from("1.005"),
+            Quantity::from("1.0"),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            0,
+            0,
+        );
+
+        let quote_result = book.try_update_quote_tick(&quote);
+        let trade_result = book.try_update_trade_tick(&trade);
+
+        assert_eq!(
+            quote_result,
+            Err(
+                InvalidBookOperation::PriceIncrement(Price::from("1.015"), Price::from("0.01"))
+                    .into()
+            )
+        );
+        assert_eq!(
+            trade_result,
+            Err(
+                InvalidBookOperation::PriceIncrement(Price::from("1.005"), Price::from("0.01"))
+                    .into()
+            )
+        );
+        assert!(!book.has_bid());
+        assert!(!book.has_ask());
+    }
+
+    #[test]
+    #[should_panic(expected = "not a multiple of the price increment")]
+    fn test_update_quote_tick_with_off_grid_price() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut book = OrderBook::with_price_increment(
+            instrument_id.clone(),
+            BookType::L1_TBBO,
+            Price::from("0.01"),
+        );
+        book.update_quote_tick(&QuoteTick::new(
+            instrument_id,
+            Price::from("1.005"),
+            Price::from("1.010"),
+            Quantity::from("1.0"),
+            Quantity::from("1.0"),
+            0,
+            0,
+        ));
+    }
+
+    fn create_crossing_l2_book(policy: BookRepairPolicy) -> OrderBook {
+        let mut book = create_stub_book(BookType::L2_MBP);
+        book.set_repair_policy(policy);
+        for (side, price, ts_event) in [
+            (OrderSide::Buy, "0.990", 100),
+            (OrderSide::Buy, "1.000", 100),
+            (OrderSide::Sell, "1.010", 100),
+            (OrderSide::Sell, "1.020", 100),
+        ] {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from("1.0"), 0);
+            book.add(order, ts_event, 1);
+        }
+        book
+    }
+
+    fn bid_at(price: &str) -> BookOrder {
+        BookOrder::new(OrderSide::Buy, Price::from(price), Quantity::from("1.0"), 0)
+    }
+
+    #[test]
+    fn test_check_integrity_when_crossed_or_locked() {
+        let mut book = create_crossing_l2_book(BookRepairPolicy::Off);
+
+        assert_eq!(book.repair_policy(), BookRepairPolicy::Off);
+        assert!(book.check_integrity().is_ok());
+        assert!(create_stub_l3_book().check_integrity().is_ok());
+
+        book.add(bid_at("1.010"), 200, 2);
+
+        assert!(matches!(
+            book.check_integrity(),
+            Err(BookIntegrityError::OrdersCrossed(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_trust_latest_removes_crossed_levels_from_other_side() {
+        let mut book = create_crossing_l2_book(BookRepairPolicy::TrustLatest);
+
+        book.add(bid_at("1.015"), 50, 2); // Earlier `ts_event` but latest applied
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.015")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("1.020")));
+        assert_eq!(book.depth(10).asks.len(), 1);
+        assert!(book.check_integrity().is_ok());
+    }
+
+    #[test]
+    fn test_drop_stale_side_removes_crossed_levels_by_ts_event() {
+        let mut book = create_crossing_l2_book(BookRepairPolicy::DropStaleSide);
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.030"),
+                Quantity::from("1.0"),
+                0,
+            ),
+            300,
+            2,
+        );
+
+        // The bid side is older, so the late crossing bid is removed
+        book.add(bid_at("1.010"), 200, 3);
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.000")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("1.010")));
+
+        // A bid newer than the ask side removes the locked ask instead
+        book.add(bid_at("1.010"), 400, 4);
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.010")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("1.020")));
+        assert!(book.check_integrity().is_ok());
+    }
+
+    #[test]
+    fn test_trade_through_removes_levels_through_trade_price() {
+        let mut book = create_crossing_l2_book(BookRepairPolicy::TradeThrough);
+        let trade = TradeTick {
+            instrument_id: book.instrument_id.clone(),
+            price: Price::from("1.020"),
+            size: Quantity::from("1.0"),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new("123456789"),
+            ts_event: 200,
+            ts_init: 200,
+        };
+
+        book.update_trade_tick(&trade);
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.000")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("1.020")));
+        assert_eq!(book.depth(10).bids.len(), 2);
+        assert_eq!(book.depth(10).asks.len(), 1);
+    }
 }
@@ -14,19 +14,20 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    cell::RefCell,
     ffi::c_char,
     ops::{Deref, DerefMut},
 };
 
 use nautilus_core::{cvec::CVec, string::str_to_cstr};
 
-use super::book::OrderBook;
+use super::book::{OrderBook, OrderBookError};
 use crate::{
     data::{
         book::{BookOrder, OrderBookDelta},
         tick::{QuoteTick, TradeTick},
     },
-    enums::BookType,
+    enums::{BookType, OrderSide},
     identifiers::instrument_id::InstrumentId,
     types::{price::Price, quantity::Quantity},
 };
@@ -57,6 +58,49 @@ impl DerefMut for OrderBook_API {
     }
 }
 
+/// The error code returned by the [`OrderBook`] C API operations which can fail,
+/// so that a malformed update is reported to the caller rather than aborting.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OrderBookErrorCode {
+    /// The operation succeeded.
+    Ok = 0,
+    /// The operation is not valid for the book type.
+    InvalidBookOperation = 1,
+    /// The operation would break (or found broken) the integrity of the book.
+    BookIntegrityError = 2,
+    /// The delta was out of sequence.
+    SequenceError = 3,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Converts the result of an [`OrderBook`] operation to its error code, keeping the
+/// message of an error for [`orderbook_last_error`].
+impl From<Result<(), OrderBookError>> for OrderBookErrorCode {
+    fn from(result: Result<(), OrderBookError>) -> Self {
+        let e = match result {
+            Ok(()) => return Self::Ok,
+            Err(e) => e,
+        };
+        LAST_ERROR.with(|last| *last.borrow_mut() = e.to_string());
+        match e {
+            OrderBookError::InvalidBookOperation(_) => Self::InvalidBookOperation,
+            OrderBookError::BookIntegrity(_) => Self::BookIntegrityError,
+            OrderBookError::Sequence(_) => Self::SequenceError,
+        }
+    }
+}
+
+/// Returns the message of the last error returned by an [`OrderBook`] C API
+/// operation on the calling thread (empty if none), as a C string pointer.
+#[no_mangle]
+pub extern "C" fn orderbook_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| str_to_cstr(&last.borrow()))
+}
+
 #[no_mangle]
 pub extern "C" fn orderbook_new(instrument_id: InstrumentId, book_type: BookType) -> OrderBook_API {
     OrderBook_API(Box::new(OrderBook::new(instrument_id, book_type)))
@@ -103,8 +147,8 @@ pub extern "C" fn orderbook_add(
     order: BookOrder,
     ts_event: u64,
     sequence: u64,
-) {
-    book.add(order, ts_event, sequence)
+) -> OrderBookErrorCode {
+    book.try_add(order, ts_event, sequence).into()
 }
 
 #[no_mangle]
@@ -113,8 +157,8 @@ pub extern "C" fn orderbook_update(
     order: BookOrder,
     ts_event: u64,
     sequence: u64,
-) {
-    book.update(order, ts_event, sequence)
+) -> OrderBookErrorCode {
+    book.try_update(order, ts_event, sequence).into()
 }
 
 #[no_mangle]
@@ -123,8 +167,8 @@ pub extern "C" fn orderbook_delete(
     order: BookOrder,
     ts_event: u64,
     sequence: u64,
-) {
-    book.delete(order, ts_event, sequence)
+) -> OrderBookErrorCode {
+    book.try_delete(order, ts_event, sequence).into()
 }
 
 #[no_mangle]
@@ -143,8 +187,11 @@ pub extern "C" fn orderbook_clear_asks(book: &mut OrderBook_API, ts_event: u64,
 }
 
 #[no_mangle]
-pub extern "C" fn orderbook_apply_delta(book: &mut OrderBook_API, delta: OrderBookDelta) {
-    book.apply_delta(delta)
+pub extern "C" fn orderbook_apply_delta(
+    book: &mut OrderBook_API,
+    delta: OrderBookDelta,
+) -> OrderBookErrorCode {
+    book.try_apply_delta(delta).into()
 }
 
 #[no_mangle]
@@ -193,14 +240,56 @@ pub extern "C" fn orderbook_midpoint(book: &mut OrderBook_API) -> f64 {
         .expect("Error: Unable to calculate `midpoint` (no bid or ask)")
 }
 
+/// Returns the order book imbalance of the top `num_levels` levels per side, or NaN
+/// if the book has no orders.
+#[no_mangle]
+pub extern "C" fn orderbook_imbalance(book: &OrderBook_API, num_levels: usize) -> f64 {
+    book.imbalance(num_levels).unwrap_or(f64::NAN)
+}
+
+/// Returns the top of book prices weighted by the opposite side sizes, or NaN if
+/// the book has no bid or no ask.
 #[no_mangle]
-pub extern "C" fn orderbook_update_quote_tick(book: &mut OrderBook_API, tick: &QuoteTick) {
-    book.update_quote_tick(tick);
+pub extern "C" fn orderbook_microprice(book: &OrderBook_API) -> f64 {
+    book.microprice().unwrap_or(f64::NAN)
 }
 
+/// Returns the volume weighted average prices of the top `num_levels` levels per
+/// side weighted by the opposite side volumes, or NaN if the book has no bid or no ask.
 #[no_mangle]
-pub extern "C" fn orderbook_update_trade_tick(book: &mut OrderBook_API, tick: &TradeTick) {
-    book.update_trade_tick(tick);
+pub extern "C" fn orderbook_weighted_mid(book: &OrderBook_API, num_levels: usize) -> f64 {
+    book.weighted_mid(num_levels).unwrap_or(f64::NAN)
+}
+
+/// Returns the volume weighted average price to fill a market order of `size` on the
+/// given `side`, or NaN if the side is `NoOrderSide` or the book cannot fill the
+/// entire size.
+#[no_mangle]
+pub extern "C" fn orderbook_vwap_to_fill(
+    book: &OrderBook_API,
+    side: OrderSide,
+    size: Quantity,
+) -> f64 {
+    match side {
+        OrderSide::NoOrderSide => f64::NAN,
+        _ => book.vwap_to_fill(side, size).unwrap_or(f64::NAN),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_update_quote_tick(
+    book: &mut OrderBook_API,
+    tick: &QuoteTick,
+) -> OrderBookErrorCode {
+    book.try_update_quote_tick(tick).into()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_update_trade_tick(
+    book: &mut OrderBook_API,
+    tick: &TradeTick,
+) -> OrderBookErrorCode {
+    book.try_update_trade_tick(tick).into()
 }
 
 #[no_mangle]
@@ -209,8 +298,8 @@ pub extern "C" fn orderbook_simulate_fills(book: &OrderBook_API, order: BookOrde
 }
 
 #[no_mangle]
-pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) {
-    book.check_integrity().unwrap()
+pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) -> OrderBookErrorCode {
+    book.check_integrity().map_err(OrderBookError::from).into()
 }
 
 // TODO: This struct implementation potentially leaks memory
@@ -232,3 +321,94 @@ pub extern "C" fn orderbook_pprint_to_cstr(
 ) -> *const c_char {
     str_to_cstr(&book.pprint(num_levels))
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::string::{cstr_drop, cstr_to_string};
+
+    use super::*;
+
+    fn create_stub_book() -> OrderBook_API {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        orderbook_new(instrument_id, BookType::L2_MBP)
+    }
+
+    #[test]
+    fn test_analytics_with_empty_book_return_nan() {
+        let book = create_stub_book();
+
+        assert!(orderbook_imbalance(&book, 1).is_nan());
+        assert!(orderbook_microprice(&book).is_nan());
+        assert!(orderbook_weighted_mid(&book, 1).is_nan());
+    }
+
+    #[test]
+    fn test_analytics_with_one_sided_book() {
+        let mut book = create_stub_book();
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            0,
+            1,
+        );
+
+        assert_eq!(orderbook_imbalance(&book, 1), 1.0);
+        assert!(orderbook_microprice(&book).is_nan());
+        assert!(orderbook_weighted_mid(&book, 1).is_nan());
+    }
+
+    #[test]
+    fn test_vwap_to_fill_with_no_order_side_returns_nan() {
+        let mut book = create_stub_book();
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.000"),
+                Quantity::from("1.0"),
+                1,
+            ),
+            0,
+            1,
+        );
+
+        assert_eq!(
+            orderbook_vwap_to_fill(&book, OrderSide::Buy, Quantity::from("1.0")),
+            1.0
+        );
+        assert!(
+            orderbook_vwap_to_fill(&book, OrderSide::NoOrderSide, Quantity::from("1.0")).is_nan()
+        );
+    }
+
+    #[test]
+    fn test_last_error_has_message_of_failed_operation() {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut book = orderbook_new(instrument_id, BookType::L1_TBBO);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+
+        let code = orderbook_add(&mut book, order, 0, 1);
+
+        assert_eq!(code, OrderBookErrorCode::InvalidBookOperation);
+        let ptr = orderbook_last_error();
+        let message = unsafe { cstr_to_string(ptr) };
+        unsafe { cstr_drop(ptr) };
+        assert_eq!(
+            message,
+            "Invalid book operation: cannot add for L1_TBBO book"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    identifiers::instrument_id::InstrumentId,
+    orderbook::level::Level,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents the aggregated size of the orders at a single price level.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DepthLevel {
+    pub price: Price,
+    pub size: Quantity,
+    pub order_count: usize,
+}
+
+impl DepthLevel {
+    #[must_use]
+    pub fn new(price: Price, size: Quantity, order_count: usize) -> Self {
+        Self {
+            price,
+            size,
+            order_count,
+        }
+    }
+}
+
+impl From<&Level> for DepthLevel {
+    fn from(level: &Level) -> Self {
+        Self::new(level.price.value, level.size(), level.len())
+    }
+}
+
+/// Represents the top levels of each side of an order book, ordered from the
+/// best price outwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookDepth {
+    pub instrument_id: InstrumentId,
+    pub bids: Vec<DepthLevel>,
+    pub asks: Vec<DepthLevel>,
+    pub sequence: u64,
+    pub ts_last: u64,
+}
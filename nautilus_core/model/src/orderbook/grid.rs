@@ -0,0 +1,520 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{btree_map, vec_deque, BTreeMap, VecDeque},
+    iter::{Chain, Flatten},
+};
+
+use super::book::BookIntegrityError;
+use crate::{
+    enums::OrderSide,
+    orderbook::{ladder::BookPrice, level::Level},
+    types::price::Price,
+};
+
+/// The default maximum number of ticks spanned by the ring buffer of a [`PriceGrid`].
+pub const PRICE_GRID_MAX_TICKS: usize = 10_000;
+
+/// The iterator over the levels of a [`PriceGrid`], from the best price outwards.
+pub type PriceGridValues<'a> =
+    Chain<Flatten<vec_deque::Iter<'a, Option<Level>>>, btree_map::Values<'a, BookPrice, Level>>;
+
+/// Stores the levels of one side of an order book in a ring buffer indexed by tick
+/// offset from the best price, for O(1) level access on dense price grids.
+///
+/// The buffer spans from the best level with no empty slots at either end, and is
+/// bounded to `max_ticks` ticks. Levels further than `max_ticks` from the best price
+/// are held in an ordered overflow map, so sparse levels far from the touch do not
+/// grow the buffer. All prices must be a multiple of the `price_increment`.
+#[derive(Clone, Debug)]
+pub struct PriceGrid {
+    pub side: OrderSide,
+    pub price_increment: Price,
+    pub max_ticks: usize,
+    origin: i64,
+    slots: VecDeque<Option<Level>>,
+    overflow: BTreeMap<BookPrice, Level>,
+    len: usize,
+}
+
+impl PriceGrid {
+    /// Creates a new [`PriceGrid`] instance spanning up to [`PRICE_GRID_MAX_TICKS`].
+    ///
+    /// # Panics
+    ///
+    /// - If `side` is `NoOrderSide`.
+    /// - If `price_increment` is not positive (> 0).
+    #[must_use]
+    pub fn new(side: OrderSide, price_increment: Price) -> Self {
+        Self::with_max_ticks(side, price_increment, PRICE_GRID_MAX_TICKS)
+    }
+
+    /// Creates a new [`PriceGrid`] instance whose ring buffer spans up to `max_ticks`.
+    ///
+    /// # Panics
+    ///
+    /// - If `side` is `NoOrderSide`.
+    /// - If `price_increment` is not positive (> 0).
+    /// - If `max_ticks` is zero.
+    #[must_use]
+    pub fn with_max_ticks(side: OrderSide, price_increment: Price, max_ticks: usize) -> Self {
+        if side == OrderSide::NoOrderSide {
+            panic!("{}", BookIntegrityError::NoOrderSide);
+        }
+        if price_increment.raw <= 0 {
+            panic!("invalid `price_increment` for `PriceGrid`, was {price_increment}");
+        }
+        if max_ticks == 0 {
+            panic!("invalid `max_ticks` for `PriceGrid`, was {max_ticks} (must be > 0)");
+        }
+
+        Self {
+            side,
+            price_increment,
+            max_ticks,
+            origin: 0,
+            slots: VecDeque::new(),
+            overflow: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of levels in the grid.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.overflow.clear();
+        self.len = 0;
+    }
+
+    /// Returns whether the `price` is a multiple of the price increment.
+    #[must_use]
+    pub fn is_on_grid(&self, price: Price) -> bool {
+        price.raw % self.price_increment.raw == 0
+    }
+
+    #[must_use]
+    pub fn get(&self, price: &BookPrice) -> Option<&Level> {
+        match self.index(price.value) {
+            Some(index) => self.slots[index].as_ref(),
+            None => self.overflow.get(price),
+        }
+    }
+
+    pub fn get_mut(&mut self, price: &BookPrice) -> Option<&mut Level> {
+        match self.index(price.value) {
+            Some(index) => self.slots[index].as_mut(),
+            None => self.overflow.get_mut(price),
+        }
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, price: &BookPrice) -> bool {
+        self.get(price).is_some()
+    }
+
+    /// Returns the best level, if any.
+    #[must_use]
+    pub fn first(&self) -> Option<&Level> {
+        self.slots.front()?.as_ref()
+    }
+
+    /// Inserts the `level` at `price`, returning the level it replaced, if any.
+    ///
+    /// # Panics
+    ///
+    /// If `price` is not a multiple of the price increment.
+    pub fn insert(&mut self, price: BookPrice, level: Level) -> Option<Level> {
+        if !self.is_on_grid(price.value) {
+            panic!(
+                "invalid `price` for `PriceGrid`, was {} with increment {}",
+                price.value, self.price_increment
+            );
+        }
+
+        if self.slots.is_empty() {
+            self.origin = price.value.raw;
+            self.slots.push_back(Some(level));
+            self.len += 1;
+            return None;
+        }
+
+        let offset = self.offset(price.value.raw);
+        let index = if offset < 0 {
+            // Better than the current best, so the grid extends at the front and any
+            // levels pushed out of the window move to the overflow
+            let shift = offset.unsigned_abs() as usize;
+            if shift >= self.max_ticks {
+                self.spill(0);
+            } else {
+                self.spill(self.max_ticks - shift);
+                for _ in 0..shift {
+                    self.slots.push_front(None);
+                }
+            }
+            self.origin = price.value.raw;
+            if self.slots.is_empty() {
+                self.slots.push_back(None);
+            }
+            0
+        } else if offset as usize >= self.max_ticks {
+            let replaced = self.overflow.insert(price, level);
+            if replaced.is_none() {
+                self.len += 1;
+            }
+            return replaced;
+        } else {
+            let index = offset as usize;
+            if index >= self.slots.len() {
+                self.slots.resize(index + 1, None);
+            }
+            index
+        };
+
+        let replaced = self.slots[index].replace(level);
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    /// Removes and returns the level at `price`, if any.
+    pub fn remove(&mut self, price: &BookPrice) -> Option<Level> {
+        let index = match self.index(price.value) {
+            Some(index) => index,
+            None => {
+                let removed = self.overflow.remove(price)?;
+                self.len -= 1;
+                return Some(removed);
+            }
+        };
+        let removed = self.slots[index].take()?;
+        self.len -= 1;
+        self.trim();
+        self.absorb();
+        Some(removed)
+    }
+
+    /// Returns an iterator over the levels from the best price outwards.
+    pub fn values(&self) -> PriceGridValues<'_> {
+        self.slots.iter().flatten().chain(self.overflow.values())
+    }
+
+    fn tick_step(&self) -> i64 {
+        match self.side {
+            OrderSide::Buy => -self.price_increment.raw,
+            _ => self.price_increment.raw,
+        }
+    }
+
+    fn offset(&self, raw: i64) -> i64 {
+        (raw - self.origin) / self.tick_step()
+    }
+
+    fn index(&self, price: Price) -> Option<usize> {
+        if self.slots.is_empty() || (price.raw - self.origin) % self.price_increment.raw != 0 {
+            return None;
+        }
+        let offset = self.offset(price.raw);
+        if offset < 0 || offset as usize >= self.slots.len() {
+            return None;
+        }
+        Some(offset as usize)
+    }
+
+    fn trim(&mut self) {
+        while let Some(None) = self.slots.front() {
+            self.slots.pop_front();
+            self.origin += self.tick_step();
+        }
+        while let Some(None) = self.slots.back() {
+            self.slots.pop_back();
+        }
+    }
+
+    /// Moves the levels at and beyond `index` from the ring buffer to the overflow.
+    fn spill(&mut self, index: usize) {
+        if index >= self.slots.len() {
+            return;
+        }
+        for level in self.slots.drain(index..).flatten() {
+            self.overflow.insert(level.price, level);
+        }
+        self.trim();
+    }
+
+    /// Moves the overflow levels which are now within the window into the ring
+    /// buffer, restarting the buffer from the best overflow level if it is empty.
+    fn absorb(&mut self) {
+        if self.slots.is_empty() {
+            match self.overflow.keys().next() {
+                Some(price) => self.origin = price.value.raw,
+                None => return,
+            }
+        }
+        while let Some(price) = self.overflow.keys().next().copied() {
+            let index = self.offset(price.value.raw) as usize;
+            if index >= self.max_ticks {
+                break;
+            }
+            let level = match self.overflow.remove(&price) {
+                Some(level) => level,
+                None => break,
+            };
+            if index >= self.slots.len() {
+                self.slots.resize(index + 1, None);
+            }
+            self.slots[index] = Some(level);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{data::book::BookOrder, types::quantity::Quantity};
+
+    fn level(side: OrderSide, price: &str) -> (BookPrice, Level) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from("1.0"), 1);
+        (order.to_book_price(), Level::from_order(order))
+    }
+
+    fn prices(grid: &PriceGrid) -> Vec<Price> {
+        grid.values().map(|level| level.price.value).collect()
+    }
+
+    #[test]
+    fn test_insert_orders_levels_best_first() {
+        let mut bids = PriceGrid::new(OrderSide::Buy, Price::from("0.01"));
+        for price in ["1.00", "1.02", "0.97", "1.01"] {
+            let (book_price, level) = level(OrderSide::Buy, price);
+            bids.insert(book_price, level);
+        }
+
+        assert_eq!(bids.len(), 4);
+        assert_eq!(bids.first().unwrap().price.value, Price::from("1.02"));
+        assert_eq!(
+            prices(&bids),
+            vec![
+                Price::from("1.02"),
+                Price::from("1.01"),
+                Price::from("1.00"),
+                Price::from("0.97")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_and_remove() {
+        let mut asks = PriceGrid::new(OrderSide::Sell, Price::from("0.01"));
+        for price in ["1.00", "1.03", "1.05"] {
+            let (book_price, level) = level(OrderSide::Sell, price);
+            asks.insert(book_price, level);
+        }
+        let (best, _) = level(OrderSide::Sell, "1.00");
+        let (middle, _) = level(OrderSide::Sell, "1.03");
+        let (empty, _) = level(OrderSide::Sell, "1.04");
+
+        assert!(asks.contains_key(&middle));
+        assert!(!asks.contains_key(&empty));
+        assert!(asks.remove(&empty).is_none());
+
+        asks.remove(&best).unwrap();
+
+        assert_eq!(asks.len(), 2);
+        assert_eq!(asks.first().unwrap().price.value, Price::from("1.03"));
+        assert!(asks.contains_key(&middle));
+
+        asks.remove(&middle).unwrap();
+        let (worst, _) = level(OrderSide::Sell, "1.05");
+        asks.remove(&worst).unwrap();
+
+        assert!(asks.is_empty());
+        assert!(asks.first().is_none());
+        assert!(prices(&asks).is_empty());
+    }
+
+    #[test]
+    fn test_insert_replaces_existing_level() {
+        let mut bids = PriceGrid::new(OrderSide::Buy, Price::from("0.01"));
+        let (book_price, first) = level(OrderSide::Buy, "1.00");
+        let (_, second) = level(OrderSide::Buy, "1.00");
+
+        assert!(bids.insert(book_price, first).is_none());
+        assert!(bids.insert(book_price, second).is_some());
+        assert_eq!(bids.len(), 1);
+    }
+
+    #[test]
+    fn test_get_off_grid_price() {
+        let mut bids = PriceGrid::new(OrderSide::Buy, Price::from("0.01"));
+        let (book_price, level) = level(OrderSide::Buy, "1.00");
+        bids.insert(book_price, level);
+
+        let off_grid = BookPrice::new(Price::from("1.005"), OrderSide::Buy);
+
+        assert!(!bids.is_on_grid(off_grid.value));
+        assert!(bids.get(&off_grid).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `price` for `PriceGrid`")]
+    fn test_insert_off_grid_price() {
+        let mut bids = PriceGrid::new(OrderSide::Buy, Price::from("0.01"));
+        let (book_price, level) = level(OrderSide::Buy, "1.005");
+        bids.insert(book_price, level);
+    }
+
+    #[test]
+    fn test_insert_far_from_best_uses_overflow() {
+        let mut bids = PriceGrid::with_max_ticks(OrderSide::Buy, Price::from("0.01"), 10);
+        for price in ["1.00", "0.50", "0.99"] {
+            let (book_price, level) = level(OrderSide::Buy, price);
+            bids.insert(book_price, level);
+        }
+        let (far, _) = level(OrderSide::Buy, "0.50");
+
+        assert_eq!(bids.len(), 3);
+        assert_eq!(bids.slots.len(), 2);
+        assert_eq!(bids.overflow.len(), 1);
+        assert!(bids.contains_key(&far));
+        assert_eq!(
+            prices(&bids),
+            vec![
+                Price::from("1.00"),
+                Price::from("0.99"),
+                Price::from("0.50")
+            ]
+        );
+
+        bids.remove(&far).unwrap();
+
+        assert_eq!(bids.len(), 2);
+        assert!(bids.overflow.is_empty());
+    }
+
+    #[test]
+    fn test_insert_better_price_spills_levels_outside_window() {
+        let mut asks = PriceGrid::with_max_ticks(OrderSide::Sell, Price::from("0.01"), 10);
+        for price in ["1.00", "1.05", "0.92"] {
+            let (book_price, level) = level(OrderSide::Sell, price);
+            asks.insert(book_price, level);
+        }
+
+        assert_eq!(asks.slots.len(), 9);
+        assert_eq!(asks.overflow.len(), 1);
+        assert_eq!(
+            prices(&asks),
+            vec![
+                Price::from("0.92"),
+                Price::from("1.00"),
+                Price::from("1.05")
+            ]
+        );
+
+        let (best, _) = level(OrderSide::Sell, "0.92");
+        asks.remove(&best).unwrap();
+
+        // The best moved back, so the spilled level is within the window again
+        assert_eq!(asks.slots.len(), 6);
+        assert!(asks.overflow.is_empty());
+        assert_eq!(asks.first().unwrap().price.value, Price::from("1.00"));
+    }
+
+    #[test]
+    fn test_insert_better_price_beyond_window() {
+        let mut bids = PriceGrid::with_max_ticks(OrderSide::Buy, Price::from("0.01"), 10);
+        for price in ["1.00", "0.99", "2.00"] {
+            let (book_price, level) = level(OrderSide::Buy, price);
+            bids.insert(book_price, level);
+        }
+
+        assert_eq!(bids.slots.len(), 1);
+        assert_eq!(bids.overflow.len(), 2);
+        assert_eq!(
+            prices(&bids),
+            vec![
+                Price::from("2.00"),
+                Price::from("1.00"),
+                Price::from("0.99")
+            ]
+        );
+
+        let (best, _) = level(OrderSide::Buy, "2.00");
+        bids.remove(&best).unwrap();
+
+        assert_eq!(bids.slots.len(), 2);
+        assert!(bids.overflow.is_empty());
+        assert_eq!(bids.first().unwrap().price.value, Price::from("1.00"));
+    }
+
+    #[test]
+    fn test_matches_btree_map_for_random_operations() {
+        let mut grid = PriceGrid::with_max_ticks(OrderSide::Buy, Price::from("0.01"), 8);
+        let mut expected = BTreeMap::new();
+        let mut state: u64 = 7;
+        for _ in 0..2_000 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let ticks = (state >> 33) % 40;
+            let price = Price::from_raw(1_000_000_000 + ticks as i64 * 10_000_000, 2);
+            let (book_price, level) = level(OrderSide::Buy, &price.to_string());
+            if (state >> 20) % 3 == 0 {
+                assert_eq!(
+                    grid.remove(&book_price).is_some(),
+                    expected.remove(&book_price).is_some()
+                );
+            } else {
+                grid.insert(book_price, level.clone());
+                expected.insert(book_price, level);
+            }
+
+            assert_eq!(grid.len(), expected.len());
+            assert!(grid.slots.len() <= grid.max_ticks);
+            assert_eq!(
+                prices(&grid),
+                expected.keys().map(|p| p.value).collect::<Vec<Price>>()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `max_ticks` for `PriceGrid`")]
+    fn test_new_with_zero_max_ticks() {
+        let _ = PriceGrid::with_max_ticks(OrderSide::Buy, Price::from("0.01"), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `price_increment` for `PriceGrid`")]
+    fn test_new_with_zero_price_increment() {
+        let _ = PriceGrid::new(OrderSide::Buy, Price::from("0.00"));
+    }
+}
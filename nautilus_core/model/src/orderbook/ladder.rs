@@ -15,7 +15,7 @@
 
 use std::{
     cmp::Ordering,
-    collections::{BTreeMap, HashMap},
+    collections::{btree_map, BTreeMap, HashMap},
     fmt::{Display, Formatter},
 };
 
@@ -23,8 +23,16 @@ use super::book::BookIntegrityError;
 use crate::{
     data::book::BookOrder,
     enums::OrderSide,
-    orderbook::level::Level,
-    types::{price::Price, quantity::Quantity},
+    orderbook::{
+        depth::DepthLevel,
+        grid::{PriceGrid, PriceGridValues},
+        level::Level,
+        queue::QueuePosition,
+    },
+    types::{
+        price::{Price, PRICE_MAX, PRICE_MIN},
+        quantity::Quantity,
+    },
 };
 
 #[derive(Copy, Clone, Debug, Eq)]
@@ -72,9 +80,121 @@ impl Display for BookPrice {
     }
 }
 
+/// The storage for the price levels of a [`Ladder`].
+///
+/// A `Tree` accepts any price, while a `Grid` gives O(1) level access for prices on
+/// a dense grid of a known price increment.
+#[derive(Clone, Debug)]
+pub enum LadderLevels {
+    Tree(BTreeMap<BookPrice, Level>),
+    Grid(PriceGrid),
+}
+
+impl LadderLevels {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Tree(levels) => levels.len(),
+            Self::Grid(levels) => levels.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Tree(levels) => levels.is_empty(),
+            Self::Grid(levels) => levels.is_empty(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        match self {
+            Self::Tree(levels) => levels.clear(),
+            Self::Grid(levels) => levels.clear(),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, price: &BookPrice) -> Option<&Level> {
+        match self {
+            Self::Tree(levels) => levels.get(price),
+            Self::Grid(levels) => levels.get(price),
+        }
+    }
+
+    pub fn get_mut(&mut self, price: &BookPrice) -> Option<&mut Level> {
+        match self {
+            Self::Tree(levels) => levels.get_mut(price),
+            Self::Grid(levels) => levels.get_mut(price),
+        }
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, price: &BookPrice) -> bool {
+        match self {
+            Self::Tree(levels) => levels.contains_key(price),
+            Self::Grid(levels) => levels.contains_key(price),
+        }
+    }
+
+    pub fn insert(&mut self, price: BookPrice, level: Level) -> Option<Level> {
+        match self {
+            Self::Tree(levels) => levels.insert(price, level),
+            Self::Grid(levels) => levels.insert(price, level),
+        }
+    }
+
+    pub fn remove(&mut self, price: &BookPrice) -> Option<Level> {
+        match self {
+            Self::Tree(levels) => levels.remove(price),
+            Self::Grid(levels) => levels.remove(price),
+        }
+    }
+
+    /// Returns the best level, if any.
+    #[must_use]
+    pub fn first(&self) -> Option<&Level> {
+        match self {
+            Self::Tree(levels) => levels.values().next(),
+            Self::Grid(levels) => levels.first(),
+        }
+    }
+
+    /// Returns an iterator over the levels from the best price outwards.
+    #[must_use]
+    pub fn values(&self) -> LevelsIter<'_> {
+        match self {
+            Self::Tree(levels) => LevelsIter::Tree(levels.values()),
+            Self::Grid(levels) => LevelsIter::Grid(levels.values()),
+        }
+    }
+
+    /// Returns an iterator over the prices and levels from the best price outwards.
+    pub fn iter(&self) -> impl Iterator<Item = (&BookPrice, &Level)> {
+        self.values().map(|level| (&level.price, level))
+    }
+}
+
+/// An iterator over the levels of a [`LadderLevels`], from the best price outwards.
+pub enum LevelsIter<'a> {
+    Tree(btree_map::Values<'a, BookPrice, Level>),
+    Grid(PriceGridValues<'a>),
+}
+
+impl<'a> Iterator for LevelsIter<'a> {
+    type Item = &'a Level;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Tree(iter) => iter.next(),
+            Self::Grid(iter) => iter.next(),
+        }
+    }
+}
+
 pub struct Ladder {
     pub side: OrderSide,
-    pub levels: BTreeMap<BookPrice, Level>,
+    pub levels: LadderLevels,
     pub cache: HashMap<u64, BookPrice>,
 }
 
@@ -83,11 +203,45 @@ impl Ladder {
     pub fn new(side: OrderSide) -> Self {
         Self {
             side,
-            levels: BTreeMap::new(),
+            levels: LadderLevels::Tree(BTreeMap::new()),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Creates a new [`Ladder`] backed by a [`PriceGrid`], for instruments with a
+    /// known `price_increment` and densely populated levels.
+    ///
+    /// # Panics
+    ///
+    /// If `price_increment` is not positive (> 0).
+    #[must_use]
+    pub fn with_price_increment(side: OrderSide, price_increment: Price) -> Self {
+        Self {
+            side,
+            levels: LadderLevels::Grid(PriceGrid::new(side, price_increment)),
             cache: HashMap::new(),
         }
     }
 
+    /// Returns the price increment of a grid ladder, or `None` if the ladder
+    /// accepts any price.
+    #[must_use]
+    pub fn price_increment(&self) -> Option<Price> {
+        match &self.levels {
+            LadderLevels::Tree(_) => None,
+            LadderLevels::Grid(grid) => Some(grid.price_increment),
+        }
+    }
+
+    /// Returns whether the ladder can hold a level at `price`.
+    #[must_use]
+    pub fn accepts_price(&self, price: Price) -> bool {
+        match &self.levels {
+            LadderLevels::Tree(_) => true,
+            LadderLevels::Grid(grid) => grid.is_on_grid(price),
+        }
+    }
+
     #[must_use]
     pub fn len(&self) -> usize {
         self.levels.len()
@@ -111,30 +265,36 @@ impl Ladder {
 
     pub fn add(&mut self, order: BookOrder) {
         let book_price = order.to_book_price();
+        self.cache.insert(order.order_id, book_price);
         match self.levels.get_mut(&book_price) {
             Some(level) => {
                 level.add(order);
             }
             None => {
-                let order_id = order.order_id;
                 let level = Level::from_order(order);
-                self.cache.insert(order_id, book_price);
                 self.levels.insert(book_price, level);
             }
         }
     }
 
     pub fn update(&mut self, order: BookOrder) {
-        if let Some(price) = self.cache.get(&order.order_id) {
-            let level = self.levels.get_mut(price).unwrap();
+        if let Some(price) = self.cache.get(&order.order_id).copied() {
+            let level = self.levels.get_mut(&price).unwrap();
             if order.price == level.price.value {
                 // Size update for this level
                 level.update(order);
+                if order.size.raw == 0 {
+                    // Zero size update deletes the order
+                    self.cache.remove(&order.order_id);
+                    if level.is_empty() {
+                        self.levels.remove(&price);
+                    }
+                }
             } else {
                 // Price update, delete and insert at new level
                 level.delete(&order);
                 if level.is_empty() {
-                    self.levels.remove(price);
+                    self.levels.remove(&price);
                 }
                 self.add(order);
             }
@@ -159,6 +319,27 @@ impl Ladder {
         }
     }
 
+    /// Removes the levels better than `price`, and the level at `price` if
+    /// `inclusive`, returning the number of levels removed.
+    pub fn remove_through(&mut self, price: Price, inclusive: bool) -> usize {
+        let bound = BookPrice::new(price, self.side);
+        let prices: Vec<BookPrice> = self
+            .levels
+            .values()
+            .take_while(|level| level.price < bound || (inclusive && level.price == bound))
+            .map(|level| level.price)
+            .collect();
+
+        for price in &prices {
+            if let Some(level) = self.levels.remove(price) {
+                for order in &level.orders {
+                    self.cache.remove(&order.order_id);
+                }
+            }
+        }
+        prices.len()
+    }
+
     #[must_use]
     pub fn volumes(&self) -> f64 {
         return self.levels.values().map(|l| l.volume()).sum();
@@ -171,10 +352,151 @@ impl Ladder {
 
     #[must_use]
     pub fn top(&self) -> Option<&Level> {
-        match self.levels.iter().next() {
-            Some((_, l)) => Option::Some(l),
-            None => Option::None,
+        self.levels.first()
+    }
+
+    /// Returns the level at the given `price`, if any.
+    #[must_use]
+    pub fn level(&self, price: Price) -> Option<&Level> {
+        self.levels.get(&BookPrice::new(price, self.side))
+    }
+
+    /// Returns the position of the order with the given `order_id` in its level
+    /// queue, or `None` if the order is not in the ladder.
+    #[must_use]
+    pub fn queue_position(&self, order_id: u64) -> Option<QueuePosition> {
+        let price = self.cache.get(&order_id)?;
+        self.levels.get(price)?.queue_position(order_id)
+    }
+
+    /// Returns the aggregated depth of the top `num_levels` levels.
+    #[must_use]
+    pub fn depth(&self, num_levels: usize) -> Vec<DepthLevel> {
+        self.levels
+            .values()
+            .take(num_levels)
+            .map(DepthLevel::from)
+            .collect()
+    }
+
+    /// Returns the cumulative volume of the levels at or better than `price`.
+    #[must_use]
+    pub fn volume_to_price(&self, price: Price) -> f64 {
+        let bound = BookPrice::new(price, self.side);
+        self.levels
+            .values()
+            .take_while(|level| level.price <= bound)
+            .map(|level| level.volume())
+            .sum()
+    }
+
+    /// Returns the price of the level at which the cumulative volume from the top
+    /// reaches `volume`, or `None` if the ladder does not hold enough volume.
+    #[must_use]
+    pub fn price_for_volume(&self, volume: f64) -> Option<Price> {
+        let mut cumulative = 0.0;
+        for level in self.levels.values() {
+            cumulative += level.volume();
+            if cumulative >= volume {
+                return Some(level.price.value);
+            }
+        }
+        None
+    }
+
+    /// Returns the top `num_levels` levels aggregated into buckets of `group_size`.
+    ///
+    /// Bid prices are rounded down and ask prices are rounded up to a multiple of
+    /// `group_size`, so a grouped level never appears better than its orders.
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is not positive (> 0).
+    #[must_use]
+    pub fn group_by(&self, group_size: Price, num_levels: usize) -> Vec<DepthLevel> {
+        if group_size.raw <= 0 {
+            panic!("invalid `group_size` for `Ladder`, was {group_size}");
+        }
+
+        let step = group_size.raw;
+        let mut grouped: Vec<DepthLevel> = Vec::new();
+        for level in self.levels.values() {
+            let raw = level.price.value.raw;
+            let bucket = match self.side {
+                OrderSide::Buy => raw.div_euclid(step) * step,
+                OrderSide::Sell => -(-raw).div_euclid(step) * step,
+                _ => panic!("{}", BookIntegrityError::NoOrderSide),
+            };
+
+            match grouped.last_mut() {
+                Some(last) if last.price.raw == bucket => {
+                    last.size += level.size();
+                    last.order_count += level.len();
+                }
+                _ => {
+                    if grouped.len() == num_levels {
+                        break;
+                    }
+                    let precision = level.price.value.precision.max(group_size.precision);
+                    grouped.push(DepthLevel::new(
+                        Price::from_raw(bucket, precision),
+                        level.size(),
+                        level.len(),
+                    ));
+                }
+            }
+        }
+        grouped
+    }
+
+    /// Returns the total volume of the top `num_levels` levels.
+    #[must_use]
+    pub fn top_volume(&self, num_levels: usize) -> f64 {
+        self.levels
+            .values()
+            .take(num_levels)
+            .map(|level| level.volume())
+            .sum()
+    }
+
+    /// Returns the volume weighted average price of the top `num_levels` levels,
+    /// or `None` if the ladder is empty.
+    #[must_use]
+    pub fn top_vwap(&self, num_levels: usize) -> Option<f64> {
+        let levels = self.levels.values().take(num_levels);
+        let (exposure, volume) = levels.fold((0.0, 0.0), |(exposure, volume), level| {
+            (exposure + level.exposure(), volume + level.volume())
+        });
+        if volume == 0.0 {
+            return None;
+        }
+        Some(exposure / volume)
+    }
+
+    /// Returns the volume weighted average price to fill a market order of `size`
+    /// against the ladder, or `None` if the ladder cannot fill the entire size.
+    #[must_use]
+    pub fn vwap_to_fill(&self, size: Quantity) -> Option<f64> {
+        if size.raw == 0 {
+            return None;
+        }
+
+        let order = match self.side {
+            OrderSide::Buy => BookOrder::new(OrderSide::Sell, Price::new(PRICE_MIN, 0), size, 0),
+            OrderSide::Sell => BookOrder::new(OrderSide::Buy, Price::new(PRICE_MAX, 0), size, 0),
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        };
+
+        let mut filled = Quantity::zero(size.precision);
+        let mut notional = 0.0;
+        for (price, quantity) in self.simulate_fills(&order) {
+            filled += quantity;
+            notional += price.as_f64() * quantity.as_f64();
+        }
+        if filled < size {
+            return None;
         }
+        Some(notional / filled.as_f64())
     }
 
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
@@ -218,7 +540,10 @@ mod tests {
     use crate::{
         data::book::BookOrder,
         enums::OrderSide,
-        orderbook::ladder::{BookPrice, Ladder},
+        orderbook::{
+            depth::DepthLevel,
+            ladder::{BookPrice, Ladder},
+        },
         types::{
             price::{Price, PRICE_MAX, PRICE_MIN},
             quantity::Quantity,
@@ -499,6 +824,89 @@ mod tests {
         assert_eq!(ladder.top(), None)
     }
 
+    #[test]
+    fn test_update_and_delete_order_at_existing_level() {
+        let mut ladder = Ladder::new(OrderSide::Buy);
+        let order1 = BookOrder::new(
+            OrderSide::Buy,
+            Price::new(10.00, 2),
+            Quantity::new(20.0, 0),
+            1,
+        );
+        let order2 = BookOrder::new(
+            OrderSide::Buy,
+            Price::new(10.00, 2),
+            Quantity::new(30.0, 0),
+            2,
+        );
+        ladder.add(order1);
+        ladder.add(order2);
+
+        let order2_update = BookOrder::new(
+            OrderSide::Buy,
+            Price::new(10.00, 2),
+            Quantity::new(25.0, 0),
+            2,
+        );
+        ladder.update(order2_update);
+
+        assert_eq!(ladder.top().unwrap().len(), 2);
+        assert_eq!(ladder.volumes(), 45.0);
+
+        ladder.delete(order2);
+
+        assert_eq!(ladder.top().unwrap().len(), 1);
+        assert_eq!(ladder.volumes(), 20.0);
+    }
+
+    #[test]
+    fn test_update_order_with_zero_size_deletes_order() {
+        let mut ladder = Ladder::new(OrderSide::Sell);
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(11.00, 2),
+            Quantity::new(20.0, 0),
+            1,
+        );
+        ladder.add(order);
+
+        let order_update = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(11.00, 2),
+            Quantity::new(0.0, 0),
+            1,
+        );
+        ladder.update(order_update);
+
+        assert!(ladder.is_empty());
+        assert!(ladder.cache.is_empty());
+    }
+
+    #[test]
+    fn test_queue_position() {
+        let mut ladder = Ladder::new(OrderSide::Buy);
+        ladder.add(BookOrder::new(
+            OrderSide::Buy,
+            Price::new(10.00, 2),
+            Quantity::new(20.0, 0),
+            1,
+        ));
+        ladder.add(BookOrder::new(
+            OrderSide::Buy,
+            Price::new(10.00, 2),
+            Quantity::new(30.0, 0),
+            2,
+        ));
+
+        let position = ladder.queue_position(2).unwrap();
+
+        assert_eq!(position.orders_ahead, 1);
+        assert_eq!(position.size_ahead, Quantity::new(20.0, 0));
+        assert_eq!(ladder.queue_position(3), None);
+        assert_eq!(ladder.level(Price::new(10.00, 2)).unwrap().len(), 2);
+        assert!(ladder.level(Price::new(9.00, 2)).is_none());
+    }
+
     #[test]
     fn test_simulate_order_fills_buy_when_far_from_market() {
         let mut ladder = Ladder::new(OrderSide::Sell);
@@ -691,4 +1099,239 @@ mod tests {
         assert_eq!(price3, &Price::new(100.00, 2));
         assert_eq!(size3, &Quantity::new(399.999999999, 9));
     }
+
+    #[test]
+    fn test_depth_and_cumulative_volume() {
+        let mut ladder = Ladder::new(OrderSide::Buy);
+        ladder.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(10.00, 2),
+                Quantity::new(20.0, 0),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(9.00, 2),
+                Quantity::new(30.0, 0),
+                2,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(9.00, 2),
+                Quantity::new(50.0, 0),
+                3,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(8.00, 2),
+                Quantity::new(200.0, 0),
+                4,
+            ),
+        ]);
+
+        let depth = ladder.depth(2);
+
+        assert_eq!(
+            depth,
+            vec![
+                DepthLevel::new(Price::new(10.00, 2), Quantity::new(20.0, 0), 1),
+                DepthLevel::new(Price::new(9.00, 2), Quantity::new(80.0, 0), 2),
+            ]
+        );
+        assert_eq!(ladder.volume_to_price(Price::new(9.00, 2)), 100.0);
+        assert_eq!(ladder.volume_to_price(Price::new(10.50, 2)), 0.0);
+        assert_eq!(ladder.price_for_volume(50.0), Some(Price::new(9.00, 2)));
+        assert_eq!(ladder.price_for_volume(301.0), None);
+    }
+
+    #[test]
+    fn test_group_by_rounds_bids_down_and_asks_up() {
+        let mut bids = Ladder::new(OrderSide::Buy);
+        bids.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(10.07, 2),
+                Quantity::new(1.0, 0),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(10.02, 2),
+                Quantity::new(2.0, 0),
+                2,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(9.98, 2),
+                Quantity::new(3.0, 0),
+                3,
+            ),
+        ]);
+        let mut asks = Ladder::new(OrderSide::Sell);
+        asks.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::new(10.11, 2),
+                Quantity::new(1.0, 0),
+                4,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::new(10.19, 2),
+                Quantity::new(2.0, 0),
+                5,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::new(10.21, 2),
+                Quantity::new(3.0, 0),
+                6,
+            ),
+        ]);
+
+        let grouped_bids = bids.group_by(Price::new(0.1, 1), 10);
+        let grouped_asks = asks.group_by(Price::new(0.1, 1), 1);
+
+        assert_eq!(
+            grouped_bids,
+            vec![
+                DepthLevel::new(Price::new(10.00, 2), Quantity::new(3.0, 0), 2),
+                DepthLevel::new(Price::new(9.90, 2), Quantity::new(3.0, 0), 1),
+            ]
+        );
+        assert_eq!(
+            grouped_asks,
+            vec![DepthLevel::new(
+                Price::new(10.20, 2),
+                Quantity::new(3.0, 0),
+                2
+            )]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_group_by_with_zero_group_size() {
+        let ladder = Ladder::new(OrderSide::Buy);
+        let _ = ladder.group_by(Price::new(0.0, 1), 10);
+    }
+
+    #[test]
+    fn test_top_volume_and_vwap() {
+        let mut ladder = Ladder::new(OrderSide::Sell);
+        ladder.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::new(11.00, 2),
+                Quantity::new(20.0, 0),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::new(12.00, 2),
+                Quantity::new(30.0, 0),
+                2,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::new(13.00, 2),
+                Quantity::new(50.0, 0),
+                3,
+            ),
+        ]);
+
+        assert_eq!(ladder.top_volume(2), 50.0);
+        assert_eq!(ladder.top_vwap(2), Some(11.6));
+        assert_eq!(Ladder::new(OrderSide::Sell).top_vwap(2), None);
+    }
+
+    #[test]
+    fn test_vwap_to_fill() {
+        let mut ladder = Ladder::new(OrderSide::Buy);
+        ladder.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(10.00, 2),
+                Quantity::new(20.0, 0),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(9.00, 2),
+                Quantity::new(30.0, 0),
+                2,
+            ),
+        ]);
+
+        assert_eq!(ladder.vwap_to_fill(Quantity::new(10.0, 0)), Some(10.0));
+        assert_eq!(ladder.vwap_to_fill(Quantity::new(40.0, 0)), Some(9.5));
+        assert_eq!(ladder.vwap_to_fill(Quantity::new(51.0, 0)), None);
+        assert_eq!(ladder.vwap_to_fill(Quantity::new(0.0, 0)), None);
+    }
+
+    #[test]
+    fn test_grid_ladder_update_order_price() {
+        let mut ladder = Ladder::with_price_increment(OrderSide::Sell, Price::from("0.01"));
+        ladder.add(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("11.00"),
+            Quantity::from(20),
+            1,
+        ));
+        ladder.add(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("11.05"),
+            Quantity::from(10),
+            2,
+        ));
+
+        ladder.update(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("11.10"),
+            Quantity::from(20),
+            1,
+        ));
+
+        assert_eq!(ladder.len(), 2);
+        assert_eq!(ladder.top().unwrap().price.value, Price::from("11.05"));
+        assert_eq!(
+            ladder.level(Price::from("11.10")).unwrap().size(),
+            Quantity::from(20)
+        );
+        assert!(ladder.level(Price::from("11.00")).is_none());
+    }
+
+    #[test]
+    fn test_accepts_price() {
+        let tree = Ladder::new(OrderSide::Buy);
+        let grid = Ladder::with_price_increment(OrderSide::Buy, Price::from("0.05"));
+
+        assert_eq!(tree.price_increment(), None);
+        assert_eq!(grid.price_increment(), Some(Price::from("0.05")));
+        assert!(tree.accepts_price(Price::from("1.01")));
+        assert!(grid.accepts_price(Price::from("1.05")));
+        assert!(!grid.accepts_price(Price::from("1.01")));
+    }
+
+    #[test]
+    fn test_remove_through() {
+        let mut ladder = Ladder::new(OrderSide::Buy);
+        for (price, order_id) in [("1.02", 1), ("1.01", 2), ("1.00", 3)] {
+            ladder.add(BookOrder::new(
+                OrderSide::Buy,
+                Price::from(price),
+                Quantity::from(1),
+                order_id,
+            ));
+        }
+
+        assert_eq!(ladder.remove_through(Price::from("1.01"), false), 1);
+        assert_eq!(ladder.top().unwrap().price.value, Price::from("1.01"));
+        assert_eq!(ladder.remove_through(Price::from("1.01"), true), 1);
+        assert_eq!(ladder.top().unwrap().price.value, Price::from("1.00"));
+        assert_eq!(ladder.remove_through(Price::from("1.01"), true), 0);
+        assert_eq!(ladder.len(), 1);
+        assert_eq!(ladder.cache.len(), 1);
+    }
 }
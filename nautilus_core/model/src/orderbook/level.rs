@@ -17,7 +17,8 @@ use std::cmp::Ordering;
 
 use crate::{
     data::book::BookOrder,
-    orderbook::{book::BookIntegrityError, ladder::BookPrice},
+    orderbook::{book::BookIntegrityError, ladder::BookPrice, queue::QueuePosition},
+    types::quantity::Quantity,
 };
 
 #[derive(Clone, Debug, Eq)]
@@ -114,6 +115,30 @@ impl Level {
         }
         sum
     }
+
+    /// Returns the total size of the orders at the level, with the precision of the
+    /// first order.
+    #[must_use]
+    pub fn size(&self) -> Quantity {
+        let precision = self.orders.first().map_or(0, |o| o.size.precision);
+        let mut sum = Quantity::zero(precision);
+        for o in self.orders.iter() {
+            sum += o.size
+        }
+        sum
+    }
+
+    /// Returns the position of the order with the given `order_id` in the level
+    /// queue, or `None` if the order is not at the level.
+    #[must_use]
+    pub fn queue_position(&self, order_id: u64) -> Option<QueuePosition> {
+        let index = self.orders.iter().position(|o| o.order_id == order_id)?;
+        let mut size_ahead = Quantity::zero(self.orders[index].size.precision);
+        for o in &self.orders[..index] {
+            size_ahead += o.size
+        }
+        Some(QueuePosition::new(index, size_ahead))
+    }
 }
 
 impl PartialEq for Level {
@@ -348,4 +373,64 @@ mod tests {
         let mut level = Level::new(BookPrice::new(Price::new(1.00, 2), OrderSide::Buy));
         level.remove(1);
     }
+
+    #[test]
+    fn test_size_empty_level() {
+        let level = Level::new(BookPrice::new(Price::new(1.00, 2), OrderSide::Buy));
+        assert_eq!(level.size(), Quantity::zero(0));
+    }
+
+    #[test]
+    fn test_size_multiple_orders() {
+        let mut level = Level::new(BookPrice::new(Price::new(2.00, 2), OrderSide::Buy));
+        level.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(2.00, 2),
+                Quantity::new(10.5, 1),
+                0,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(2.00, 2),
+                Quantity::new(20.0, 1),
+                1,
+            ),
+        ]);
+
+        assert_eq!(level.size(), Quantity::new(30.5, 1));
+        assert_eq!(level.size().precision, 1);
+    }
+
+    #[test]
+    fn test_queue_position() {
+        let mut level = Level::new(BookPrice::new(Price::new(1.00, 2), OrderSide::Buy));
+        level.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(1.00, 2),
+                Quantity::new(10.0, 0),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(1.00, 2),
+                Quantity::new(20.0, 0),
+                2,
+            ),
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::new(1.00, 2),
+                Quantity::new(30.0, 0),
+                3,
+            ),
+        ]);
+
+        let position = level.queue_position(3).unwrap();
+
+        assert_eq!(position.orders_ahead, 2);
+        assert_eq!(position.size_ahead, Quantity::new(30.0, 0));
+        assert_eq!(level.queue_position(1).unwrap().orders_ahead, 0);
+        assert_eq!(level.queue_position(4), None);
+    }
 }
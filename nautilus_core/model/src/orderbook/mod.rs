@@ -15,5 +15,12 @@
 
 pub mod book;
 pub mod book_api;
+pub mod depth;
+pub mod grid;
 pub mod ladder;
 pub mod level;
+pub mod own;
+pub mod queue;
+pub mod sampler;
+pub mod sequence;
+pub mod snapshot;
@@ -0,0 +1,511 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{book::BookIntegrityError, ladder::BookPrice};
+use crate::{
+    enums::OrderSide,
+    events::order::OrderEvent,
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    orders::{Order, OrderError},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Provides a book of our own orders for a single instrument, grouped by the price
+/// levels they are working at.
+///
+/// Orders are tracked by their `ClientOrderId` from when they are added, and are
+/// kept up to date by applying their subsequent events. An order is working at a
+/// price level while it is open with a limit price (and triggered, if it has a
+/// trigger price), and it is removed from the book once closed.
+pub struct OwnOrderBook {
+    pub instrument_id: InstrumentId,
+    orders: HashMap<ClientOrderId, Order>,
+    bids: BTreeMap<BookPrice, Vec<ClientOrderId>>,
+    asks: BTreeMap<BookPrice, Vec<ClientOrderId>>,
+}
+
+impl OwnOrderBook {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            orders: HashMap::new(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of tracked orders (including those not yet working).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.orders.clear();
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&Order> {
+        self.orders.get(client_order_id)
+    }
+
+    /// Adds the `order` to be tracked, replacing any order with the same client order ID.
+    ///
+    /// # Panics
+    ///
+    /// If the order instrument ID does not match the book instrument ID.
+    pub fn add(&mut self, order: Order) {
+        assert_eq!(order.instrument_id, self.instrument_id);
+
+        let client_order_id = order.client_order_id.clone();
+        self.remove(&client_order_id);
+        self.index(&order);
+        if !order.is_closed() {
+            self.orders.insert(client_order_id, order);
+        }
+    }
+
+    /// Removes and returns the order with the given `client_order_id`, if tracked.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<Order> {
+        let order = self.orders.remove(client_order_id)?;
+        self.unindex(&order);
+        Some(order)
+    }
+
+    /// Applies the `event` to the tracked order it relates to, updating the price
+    /// level the order is working at. Events for untracked orders are ignored.
+    ///
+    /// The order keeps its place at a price level while it works at the same price,
+    /// and only moves to the back of a level when it starts working at a new price.
+    ///
+    /// # Errors
+    ///
+    /// If the event is not a valid transition for the order, in which case the order
+    /// is left unchanged.
+    pub fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        let client_order_id = event.client_order_id().clone();
+        let order = match self.orders.get_mut(&client_order_id) {
+            Some(order) => order,
+            None => return Ok(()),
+        };
+
+        let previous_price = working_price(order);
+        let result = order.apply(event);
+        let price = working_price(order);
+        let side = order.side;
+        let is_closed = order.is_closed();
+
+        if price != previous_price {
+            if let Some(previous_price) = previous_price {
+                self.unindex_at(side, previous_price, &client_order_id);
+            }
+            if let Some(price) = price {
+                self.index_at(side, price, client_order_id.clone());
+            }
+        }
+        if is_closed {
+            self.orders.remove(&client_order_id);
+        }
+        result
+    }
+
+    /// Returns our own orders working at the given `price` on the given `side`, in
+    /// the order they started working at the price.
+    #[must_use]
+    pub fn orders_at(&self, side: OrderSide, price: Price) -> Vec<&Order> {
+        self.side(side)
+            .get(&BookPrice::new(price, side))
+            .map(|ids| ids.iter().filter_map(|id| self.orders.get(id)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the total leaves quantity of our own orders working at the given
+    /// `price` on the given `side`.
+    #[must_use]
+    pub fn size_at(&self, side: OrderSide, price: Price) -> Option<Quantity> {
+        let orders = self.orders_at(side, price);
+        let first = orders.first()?;
+        let mut size = Quantity::zero(first.leaves_qty.precision);
+        for order in orders {
+            size += order.leaves_qty
+        }
+        Some(size)
+    }
+
+    /// Returns the total leaves quantity of our own orders at each working price
+    /// level on the given `side`, ordered from the best price outwards.
+    #[must_use]
+    pub fn levels(&self, side: OrderSide) -> Vec<(Price, Quantity)> {
+        self.side(side)
+            .keys()
+            .filter_map(|price| Some((price.value, self.size_at(side, price.value)?)))
+            .collect()
+    }
+
+    fn side(&self, side: OrderSide) -> &BTreeMap<BookPrice, Vec<ClientOrderId>> {
+        match side {
+            OrderSide::Buy => &self.bids,
+            OrderSide::Sell => &self.asks,
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        }
+    }
+
+    fn side_mut(&mut self, side: OrderSide) -> &mut BTreeMap<BookPrice, Vec<ClientOrderId>> {
+        match side {
+            OrderSide::Buy => &mut self.bids,
+            OrderSide::Sell => &mut self.asks,
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        }
+    }
+
+    fn index(&mut self, order: &Order) {
+        if let Some(price) = working_price(order) {
+            self.index_at(order.side, price, order.client_order_id.clone());
+        }
+    }
+
+    fn unindex(&mut self, order: &Order) {
+        if let Some(price) = working_price(order) {
+            self.unindex_at(order.side, price, &order.client_order_id);
+        }
+    }
+
+    fn index_at(&mut self, side: OrderSide, price: Price, client_order_id: ClientOrderId) {
+        self.side_mut(side)
+            .entry(BookPrice::new(price, side))
+            .or_default()
+            .push(client_order_id);
+    }
+
+    fn unindex_at(&mut self, side: OrderSide, price: Price, client_order_id: &ClientOrderId) {
+        let levels = self.side_mut(side);
+        let book_price = BookPrice::new(price, side);
+        if let Some(ids) = levels.get_mut(&book_price) {
+            ids.retain(|id| id != client_order_id);
+            if ids.is_empty() {
+                levels.remove(&book_price);
+            }
+        }
+    }
+}
+
+/// Returns the price the `order` is working at in the market, if any.
+fn working_price(order: &Order) -> Option<Price> {
+    let is_triggered = order.trigger_price.is_none() || order.ts_triggered.is_some();
+    if order.is_open() && is_triggered && order.leaves_qty.raw > 0 {
+        order.price
+    } else {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        data::book::BookOrder,
+        enums::{BookType, LiquiditySide, OrderStatus, OrderType, TimeInForce},
+        events::order::{
+            OrderAcceptedBuilder, OrderCanceledBuilder, OrderFilled, OrderInitializedBuilder,
+            OrderPendingUpdateBuilder, OrderSubmittedBuilder, OrderUpdatedBuilder,
+        },
+        identifiers::{trade_id::TradeId, venue_order_id::VenueOrderId},
+        orderbook::book::OrderBook,
+        types::{currency::Currency, money::Money},
+    };
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap()
+    }
+
+    fn submitted_order(
+        client_order_id: &str,
+        side: OrderSide,
+        quantity: &str,
+        price: &str,
+    ) -> Order {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id())
+            .client_order_id(ClientOrderId::new(client_order_id))
+            .order_side(side)
+            .order_type(OrderType::Limit)
+            .quantity(Quantity::from(quantity))
+            .price(Some(Price::from(price)))
+            .time_in_force(TimeInForce::Gtc)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(ClientOrderId::new(client_order_id))
+            .build()
+            .unwrap();
+        let mut order: Order = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order
+    }
+
+    fn accepted(client_order_id: &str) -> OrderEvent {
+        OrderEvent::OrderAccepted(
+            OrderAcceptedBuilder::default()
+                .client_order_id(ClientOrderId::new(client_order_id))
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn filled(order: &Order, last_qty: &str) -> OrderEvent {
+        let last_qty = Quantity::from(last_qty);
+        let fill = OrderFilled {
+            trader_id: order.trader_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            instrument_id: order.instrument_id.clone(),
+            client_order_id: order.client_order_id.clone(),
+            venue_order_id: VenueOrderId::new("1"),
+            account_id: Default::default(),
+            trade_id: TradeId::new("1"),
+            position_id: None,
+            order_side: order.side,
+            order_type: order.order_type,
+            last_qty,
+            last_px: order.price.unwrap(),
+            currency: Currency::from("USDT"),
+            commission: Money::new(0.0, Currency::from("USDT")),
+            liquidity_side: LiquiditySide::Maker,
+            event_id: Default::default(),
+            ts_event: 0,
+            ts_init: 0,
+            reconciliation: false,
+        };
+        if last_qty.raw < order.leaves_qty.raw {
+            OrderEvent::OrderPartiallyFilled(fill)
+        } else {
+            OrderEvent::OrderFilled(fill)
+        }
+    }
+
+    #[test]
+    fn test_order_works_at_price_once_accepted() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        own.add(submitted_order("O-1", OrderSide::Buy, "1.0", "100.00"));
+
+        assert_eq!(own.len(), 1);
+        assert_eq!(own.size_at(OrderSide::Buy, Price::from("100.00")), None);
+
+        own.apply(accepted("O-1")).unwrap();
+
+        assert_eq!(
+            own.size_at(OrderSide::Buy, Price::from("100.00")),
+            Some(Quantity::from("1.0"))
+        );
+    }
+
+    #[test]
+    fn test_levels_aggregate_orders() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        for (id, size, price) in [
+            ("O-1", "1.0", "100.00"),
+            ("O-2", "2.0", "100.00"),
+            ("O-3", "3.0", "101.00"),
+        ] {
+            own.add(submitted_order(id, OrderSide::Buy, size, price));
+            own.apply(accepted(id)).unwrap();
+        }
+
+        assert_eq!(
+            own.levels(OrderSide::Buy),
+            vec![
+                (Price::from("101.00"), Quantity::from("3.0")),
+                (Price::from("100.00"), Quantity::from("3.0")),
+            ]
+        );
+        assert_eq!(
+            own.orders_at(OrderSide::Buy, Price::from("100.00")).len(),
+            2
+        );
+        assert!(own.levels(OrderSide::Sell).is_empty());
+    }
+
+    #[test]
+    fn test_updated_order_moves_level() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        own.add(submitted_order("O-1", OrderSide::Sell, "1.0", "100.00"));
+        own.apply(accepted("O-1")).unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-1"))
+            .quantity(Quantity::from("2.0"))
+            .price(Some(Price::from("99.00")))
+            .build()
+            .unwrap();
+
+        let pending_update = OrderPendingUpdateBuilder::default()
+            .client_order_id(ClientOrderId::new("O-1"))
+            .build()
+            .unwrap();
+
+        own.apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        own.apply(OrderEvent::OrderUpdated(updated)).unwrap();
+
+        assert_eq!(
+            own.levels(OrderSide::Sell),
+            vec![(Price::from("99.00"), Quantity::from("2.0"))]
+        );
+    }
+
+    #[test]
+    fn test_fills_reduce_size_and_remove_filled_order() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        own.add(submitted_order("O-1", OrderSide::Buy, "3.0", "100.00"));
+        own.apply(accepted("O-1")).unwrap();
+        let order = own.get(&ClientOrderId::new("O-1")).unwrap().clone();
+
+        own.apply(filled(&order, "1.0")).unwrap();
+
+        assert_eq!(
+            own.size_at(OrderSide::Buy, Price::from("100.00")),
+            Some(Quantity::from("2.0"))
+        );
+
+        let order = own.get(&ClientOrderId::new("O-1")).unwrap().clone();
+        own.apply(filled(&order, "2.0")).unwrap();
+
+        assert!(own.is_empty());
+        assert_eq!(own.size_at(OrderSide::Buy, Price::from("100.00")), None);
+    }
+
+    #[test]
+    fn test_partial_fill_keeps_queue_order() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        for id in ["O-1", "O-2"] {
+            own.add(submitted_order(id, OrderSide::Buy, "3.0", "100.00"));
+            own.apply(accepted(id)).unwrap();
+        }
+        let order = own.get(&ClientOrderId::new("O-1")).unwrap().clone();
+
+        own.apply(filled(&order, "1.0")).unwrap();
+
+        let orders = own.orders_at(OrderSide::Buy, Price::from("100.00"));
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].client_order_id, ClientOrderId::new("O-1"));
+        assert_eq!(orders[0].leaves_qty, Quantity::from("2.0"));
+        assert_eq!(orders[1].client_order_id, ClientOrderId::new("O-2"));
+    }
+
+    #[test]
+    fn test_price_amend_moves_order_to_back_of_level() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        for (id, price) in [("O-1", "100.00"), ("O-2", "99.00")] {
+            own.add(submitted_order(id, OrderSide::Buy, "1.0", price));
+            own.apply(accepted(id)).unwrap();
+        }
+        let pending_update = OrderPendingUpdateBuilder::default()
+            .client_order_id(ClientOrderId::new("O-1"))
+            .build()
+            .unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .client_order_id(ClientOrderId::new("O-1"))
+            .quantity(Quantity::from("1.0"))
+            .price(Some(Price::from("99.00")))
+            .build()
+            .unwrap();
+
+        own.apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        own.apply(OrderEvent::OrderUpdated(updated)).unwrap();
+
+        let orders = own.orders_at(OrderSide::Buy, Price::from("99.00"));
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].client_order_id, ClientOrderId::new("O-2"));
+        assert_eq!(orders[1].client_order_id, ClientOrderId::new("O-1"));
+        assert!(own
+            .orders_at(OrderSide::Buy, Price::from("100.00"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_canceled_order_is_removed() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        own.add(submitted_order("O-1", OrderSide::Buy, "1.0", "100.00"));
+        own.apply(accepted("O-1")).unwrap();
+        let canceled = OrderCanceledBuilder::default()
+            .client_order_id(ClientOrderId::new("O-1"))
+            .build()
+            .unwrap();
+
+        own.apply(OrderEvent::OrderCanceled(canceled)).unwrap();
+
+        assert!(own.is_empty());
+        assert!(own.levels(OrderSide::Buy).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_event_leaves_order_unchanged() {
+        let mut own = OwnOrderBook::new(instrument_id());
+        own.add(submitted_order("O-1", OrderSide::Buy, "1.0", "100.00"));
+        own.apply(accepted("O-1")).unwrap();
+
+        assert!(own.apply(accepted("O-1")).is_err());
+        assert_eq!(
+            own.get(&ClientOrderId::new("O-1")).unwrap().status,
+            OrderStatus::Accepted
+        );
+        assert!(own.apply(accepted("O-2")).is_ok()); // Untracked order
+    }
+
+    #[test]
+    fn test_subtract_own_from_market_book() {
+        let mut book = OrderBook::new(instrument_id(), BookType::L2_MBP);
+        for (side, price, size) in [
+            (OrderSide::Buy, "100.00", "5.0"),
+            (OrderSide::Buy, "99.00", "2.0"),
+            (OrderSide::Sell, "101.00", "4.0"),
+        ] {
+            book.add(
+                BookOrder::new(side, Price::from(price), Quantity::from(size), 0),
+                0,
+                1,
+            );
+        }
+        let mut own = OwnOrderBook::new(instrument_id());
+        for (id, side, size, price) in [
+            ("O-1", OrderSide::Buy, "1.5", "100.00"),
+            ("O-2", OrderSide::Buy, "2.0", "99.00"),
+        ] {
+            own.add(submitted_order(id, side, size, price));
+            own.apply(accepted(id)).unwrap();
+        }
+
+        let available = book.subtract_own(&own);
+
+        assert_eq!(available.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(available.best_bid_size(), Some(Quantity::from("3.5")));
+        assert_eq!(available.depth(10).bids.len(), 1);
+        assert_eq!(available.best_ask_size(), Some(Quantity::from("4.0")));
+    }
+}
@@ -0,0 +1,165 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashSet;
+
+use super::book::OrderBook;
+use crate::{
+    enums::OrderSide,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents the position of an order in the queue of its price level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct QueuePosition {
+    /// The number of orders ahead in the queue.
+    pub orders_ahead: usize,
+    /// The total size of the orders ahead in the queue.
+    pub size_ahead: Quantity,
+}
+
+impl QueuePosition {
+    #[must_use]
+    pub fn new(orders_ahead: usize, size_ahead: Quantity) -> Self {
+        Self {
+            orders_ahead,
+            size_ahead,
+        }
+    }
+}
+
+/// Estimates the queue position of an order which is not in an `L3_MBO` book,
+/// such as a simulated order resting at a price level.
+///
+/// The order joins the back of the queue, behind the orders at the level when the
+/// estimate is created. Orders ahead leave the queue when they are deleted or move
+/// to another price, and their size ahead is reduced as they are partially filled.
+/// Orders added to the level afterwards are always behind.
+#[derive(Clone, Debug)]
+pub struct QueueEstimate {
+    pub side: OrderSide,
+    pub price: Price,
+    ahead: HashSet<u64>,
+}
+
+impl QueueEstimate {
+    /// Creates a new [`QueueEstimate`] for an order joining the `price` level on
+    /// the given `side` of the `book`.
+    #[must_use]
+    pub fn new(book: &OrderBook, side: OrderSide, price: Price) -> Self {
+        let ahead = book
+            .level(side, price)
+            .map(|level| level.orders.iter().map(|o| o.order_id).collect())
+            .unwrap_or_default();
+        Self { side, price, ahead }
+    }
+
+    /// Updates the estimate from the current state of the `book`, and returns the
+    /// queue position.
+    pub fn update(&mut self, book: &OrderBook) -> QueuePosition {
+        let mut still_ahead = HashSet::with_capacity(self.ahead.len());
+        let mut size_ahead = Quantity::zero(0);
+        if let Some(level) = book.level(self.side, self.price) {
+            size_ahead = Quantity::zero(level.orders.first().map_or(0, |o| o.size.precision));
+            for order in &level.orders {
+                if self.ahead.contains(&order.order_id) {
+                    still_ahead.insert(order.order_id);
+                    size_ahead += order.size;
+                }
+            }
+        }
+
+        // Orders which left the queue cannot rejoin ahead
+        self.ahead = still_ahead;
+        QueuePosition::new(self.ahead.len(), size_ahead)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{data::book::BookOrder, enums::BookType, identifiers::instrument_id::InstrumentId};
+
+    fn create_stub_book() -> OrderBook {
+        let instrument_id = InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap();
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        book.add(bid("1.000", "1.0", 1), 100, 1);
+        book.add(bid("1.000", "2.0", 2), 100, 2);
+        book.add(bid("0.990", "3.0", 3), 100, 3);
+        book
+    }
+
+    fn bid(price: &str, size: &str, order_id: u64) -> BookOrder {
+        BookOrder::new(
+            OrderSide::Buy,
+            Price::from(price),
+            Quantity::from(size),
+            order_id,
+        )
+    }
+
+    #[test]
+    fn test_estimate_joins_back_of_queue() {
+        let book = create_stub_book();
+
+        let mut estimate = QueueEstimate::new(&book, OrderSide::Buy, Price::from("1.000"));
+
+        assert_eq!(
+            estimate.update(&book),
+            QueuePosition::new(2, Quantity::from("3.0"))
+        );
+    }
+
+    #[test]
+    fn test_estimate_at_empty_level() {
+        let book = create_stub_book();
+
+        let mut estimate = QueueEstimate::new(&book, OrderSide::Buy, Price::from("0.980"));
+
+        assert_eq!(estimate.update(&book).orders_ahead, 0);
+        assert_eq!(estimate.update(&book).size_ahead.raw, 0);
+    }
+
+    #[test]
+    fn test_estimate_updates_as_orders_ahead_change() {
+        let mut book = create_stub_book();
+        let mut estimate = QueueEstimate::new(&book, OrderSide::Buy, Price::from("1.000"));
+
+        // Orders joining later are behind, partial fills reduce the size ahead
+        book.add(bid("1.000", "5.0", 4), 101, 4);
+        book.update(bid("1.000", "0.5", 1), 102, 5);
+
+        assert_eq!(
+            estimate.update(&book),
+            QueuePosition::new(2, Quantity::from("2.5"))
+        );
+
+        // Orders moving to another price leave the queue, and cannot rejoin ahead
+        book.update(bid("0.990", "0.5", 1), 103, 6);
+        estimate.update(&book);
+        book.update(bid("1.000", "0.5", 1), 104, 7);
+        book.delete(bid("1.000", "2.0", 2), 105, 8);
+
+        assert_eq!(
+            estimate.update(&book),
+            QueuePosition::new(0, Quantity::zero(0))
+        );
+    }
+}
@@ -0,0 +1,479 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+
+use super::book::{OrderBook, OrderBookError};
+use crate::{
+    data::{
+        bar::{Bar, BarSpecification, BarType},
+        book::OrderBookDelta,
+        tick::QuoteTick,
+    },
+    enums::{BarAggregation, PriceType},
+    types::{price::Price, quantity::Quantity},
+};
+
+const NANOSECONDS_IN_MILLISECOND: u64 = 1_000_000;
+const NANOSECONDS_IN_SECOND: u64 = 1_000 * NANOSECONDS_IN_MILLISECOND;
+const NANOSECONDS_IN_MINUTE: u64 = 60 * NANOSECONDS_IN_SECOND;
+const NANOSECONDS_IN_HOUR: u64 = 60 * NANOSECONDS_IN_MINUTE;
+const NANOSECONDS_IN_DAY: u64 = 24 * NANOSECONDS_IN_HOUR;
+const NANOSECONDS_IN_WEEK: u64 = 7 * NANOSECONDS_IN_DAY;
+
+/// Samples an order book into a [`QuoteTick`] whenever its top of book changes.
+#[derive(Clone, Debug, Default)]
+pub struct QuoteSampler {
+    last: Option<QuoteTick>,
+}
+
+impl QuoteSampler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last quote sampled, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<&QuoteTick> {
+        self.last.as_ref()
+    }
+
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// Samples the top of the `book`, returning a quote if the best prices or
+    /// their sizes changed since the last sample.
+    ///
+    /// No quote is returned while either side of the book is empty.
+    pub fn sample(
+        &mut self,
+        book: &OrderBook,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Option<QuoteTick> {
+        let quote = match book.top_quote(ts_event, ts_init) {
+            Some(quote) => quote,
+            None => {
+                self.last = None;
+                return None;
+            }
+        };
+
+        let changed = match &self.last {
+            Some(last) => {
+                last.bid != quote.bid
+                    || last.ask != quote.ask
+                    || last.bid_size != quote.bid_size
+                    || last.ask_size != quote.ask_size
+            }
+            None => true,
+        };
+        if !changed {
+            return None;
+        }
+
+        self.last = Some(quote.clone());
+        Some(quote)
+    }
+
+    /// Applies the `delta` to the `book`, then samples the top of the book.
+    ///
+    /// # Errors
+    ///
+    /// If the delta cannot be applied, see [`OrderBook::try_apply_delta`].
+    pub fn apply_delta(
+        &mut self,
+        book: &mut OrderBook,
+        delta: OrderBookDelta,
+    ) -> Result<Option<QuoteTick>, OrderBookError> {
+        let (ts_event, ts_init) = (delta.ts_event, delta.ts_init);
+        book.try_apply_delta(delta)?;
+        Ok(self.sample(book, ts_event, ts_init))
+    }
+}
+
+/// Returns the quotes emitted while applying the `deltas` to the `book` in order,
+/// one for each change to the top of book.
+///
+/// # Errors
+///
+/// If a delta cannot be applied, see [`OrderBook::try_apply_delta`].
+pub fn quotes_from_deltas(
+    book: &mut OrderBook,
+    deltas: impl IntoIterator<Item = OrderBookDelta>,
+) -> Result<Vec<QuoteTick>, OrderBookError> {
+    let mut sampler = QuoteSampler::new();
+    let mut quotes = Vec::new();
+    for delta in deltas {
+        if let Some(quote) = sampler.apply_delta(book, delta)? {
+            quotes.push(quote);
+        }
+    }
+    Ok(quotes)
+}
+
+/// Samples an order book into fixed-interval bars of its bid, ask or mid price.
+///
+/// Intervals are aligned to the UNIX epoch, and each bar is stamped with the close
+/// time of its interval. A bar opens at the price prevailing at the start of its
+/// interval, and intervals in which the book was not sampled produce no bar.
+/// Bars carry zero volume, as the book holds no traded volume.
+#[derive(Clone, Debug)]
+pub struct BarSampler {
+    pub bar_type: BarType,
+    interval_ns: u64,
+    bar_start: Option<UnixNanos>,
+    open: Option<Price>,
+    high: Option<Price>,
+    low: Option<Price>,
+    close: Option<Price>,
+    size_precision: u8,
+    sampled: bool,
+}
+
+impl BarSampler {
+    /// Creates a new [`BarSampler`] instance.
+    ///
+    /// # Panics
+    ///
+    /// - If the bar aggregation is not a fixed time interval, from `Millisecond`
+    ///   to `Week`.
+    /// - If the bar step is zero.
+    /// - If the bar price type is not `Bid`, `Ask` or `Mid`.
+    #[must_use]
+    pub fn new(bar_type: BarType) -> Self {
+        if bar_type.spec.price_type == PriceType::Last {
+            panic!(
+                "invalid `PriceType` for `BarSampler`, was {}",
+                bar_type.spec.price_type
+            );
+        }
+        let interval_ns = get_interval_ns(&bar_type.spec);
+
+        Self {
+            bar_type,
+            interval_ns,
+            bar_start: None,
+            open: None,
+            high: None,
+            low: None,
+            close: None,
+            size_precision: 0,
+            sampled: false,
+        }
+    }
+
+    /// Returns the interval of the bars in nanoseconds.
+    #[must_use]
+    pub fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    /// Samples the price of the `book` at `ts_event`, returning the previous bar if
+    /// `ts_event` is past the end of its interval.
+    pub fn sample(&mut self, book: &OrderBook, ts_event: UnixNanos) -> Option<Bar> {
+        let bar = self.advance(ts_event);
+        if let Some(quote) = book.top_quote(ts_event, ts_event) {
+            self.update(quote.extract_price(self.bar_type.spec.price_type));
+            self.size_precision = quote.bid_size.precision;
+        } else if let Some(price) = self.extract_one_sided_price(book) {
+            self.update(price);
+        }
+        bar
+    }
+
+    /// Applies the `delta` to the `book`, then samples the book price.
+    ///
+    /// # Errors
+    ///
+    /// If the delta cannot be applied, see [`OrderBook::try_apply_delta`].
+    pub fn apply_delta(
+        &mut self,
+        book: &mut OrderBook,
+        delta: OrderBookDelta,
+    ) -> Result<Option<Bar>, OrderBookError> {
+        let ts_event = delta.ts_event;
+        book.try_apply_delta(delta)?;
+        Ok(self.sample(book, ts_event))
+    }
+
+    /// Closes the current bar if `ts` is at or after the end of its interval, such
+    /// as on a timer or at the end of a stream, and starts the interval containing
+    /// `ts`.
+    pub fn advance(&mut self, ts: UnixNanos) -> Option<Bar> {
+        let interval_start = ts - ts % self.interval_ns;
+        let bar_start = match self.bar_start {
+            Some(bar_start) => bar_start,
+            None => {
+                self.bar_start = Some(interval_start);
+                return None;
+            }
+        };
+        if interval_start <= bar_start {
+            return None;
+        }
+
+        let bar = self.build_bar(bar_start + self.interval_ns);
+
+        // The next bar opens at the price prevailing at the start of its interval
+        self.bar_start = Some(interval_start);
+        self.open = self.close;
+        self.high = self.close;
+        self.low = self.close;
+        self.sampled = false;
+        bar
+    }
+
+    fn update(&mut self, price: Price) {
+        if self.open.is_none() {
+            self.open = Some(price);
+        }
+        self.high = Some(self.high.map_or(price, |high| high.max(price)));
+        self.low = Some(self.low.map_or(price, |low| low.min(price)));
+        self.close = Some(price);
+        self.sampled = true;
+    }
+
+    fn extract_one_sided_price(&self, book: &OrderBook) -> Option<Price> {
+        match self.bar_type.spec.price_type {
+            PriceType::Bid => book.best_bid_price(),
+            PriceType::Ask => book.best_ask_price(),
+            _ => None,
+        }
+    }
+
+    fn build_bar(&self, ts_close: UnixNanos) -> Option<Bar> {
+        if !self.sampled {
+            return None;
+        }
+
+        Some(Bar {
+            bar_type: self.bar_type.clone(),
+            open: self.open?,
+            high: self.high?,
+            low: self.low?,
+            close: self.close?,
+            volume: Quantity::zero(self.size_precision),
+            ts_event: ts_close,
+            ts_init: ts_close,
+        })
+    }
+}
+
+/// Returns the bars sampled while applying the `deltas` to the `book` in order,
+/// including the final bar once the last delta is applied.
+///
+/// # Errors
+///
+/// If a delta cannot be applied, see [`OrderBook::try_apply_delta`].
+pub fn bars_from_deltas(
+    book: &mut OrderBook,
+    bar_type: BarType,
+    deltas: impl IntoIterator<Item = OrderBookDelta>,
+) -> Result<Vec<Bar>, OrderBookError> {
+    let mut sampler = BarSampler::new(bar_type);
+    let mut bars = Vec::new();
+    let mut ts_last = None;
+    for delta in deltas {
+        ts_last = Some(delta.ts_event);
+        if let Some(bar) = sampler.apply_delta(book, delta)? {
+            bars.push(bar);
+        }
+    }
+    if let Some(ts_last) = ts_last {
+        bars.extend(sampler.advance(ts_last + sampler.interval_ns()));
+    }
+    Ok(bars)
+}
+
+fn get_interval_ns(spec: &BarSpecification) -> u64 {
+    let unit_ns = match spec.aggregation {
+        BarAggregation::Millisecond => NANOSECONDS_IN_MILLISECOND,
+        BarAggregation::Second => NANOSECONDS_IN_SECOND,
+        BarAggregation::Minute => NANOSECONDS_IN_MINUTE,
+        BarAggregation::Hour => NANOSECONDS_IN_HOUR,
+        BarAggregation::Day => NANOSECONDS_IN_DAY,
+        BarAggregation::Week => NANOSECONDS_IN_WEEK,
+        _ => panic!(
+            "invalid `BarAggregation` for `BarSampler`, was {}",
+            spec.aggregation
+        ),
+    };
+    if spec.step == 0 {
+        panic!("invalid `step` for `BarSampler`, was 0");
+    }
+    spec.step * unit_ns
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        data::book::BookOrder,
+        enums::{AggregationSource, BookAction, BookType, OrderSide},
+        identifiers::instrument_id::InstrumentId,
+    };
+
+    const SECOND: u64 = NANOSECONDS_IN_SECOND;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap()
+    }
+
+    fn create_book() -> OrderBook {
+        OrderBook::new(instrument_id(), BookType::L2_MBP)
+    }
+
+    fn delta(side: OrderSide, price: &str, size: &str, ts: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            instrument_id(),
+            BookAction::Update,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), 0),
+            0,
+            0,
+            ts,
+            ts + 1,
+        )
+    }
+
+    fn bar_type(price_type: PriceType) -> BarType {
+        BarType {
+            instrument_id: instrument_id(),
+            spec: BarSpecification {
+                step: 1,
+                aggregation: BarAggregation::Second,
+                price_type,
+            },
+            aggregation_source: AggregationSource::Internal,
+        }
+    }
+
+    #[test]
+    fn test_quotes_emitted_when_top_of_book_changes() {
+        let mut book = create_book();
+        let deltas = vec![
+            delta(OrderSide::Buy, "99.0", "1.0", 1),
+            delta(OrderSide::Sell, "101.0", "2.0", 2),
+            delta(OrderSide::Sell, "102.0", "5.0", 3), // Behind the top
+            delta(OrderSide::Buy, "99.0", "3.0", 4),
+            delta(OrderSide::Sell, "100.5", "1.0", 5),
+        ];
+
+        let quotes = quotes_from_deltas(&mut book, deltas).unwrap();
+
+        assert_eq!(quotes.len(), 3);
+        assert_eq!(quotes[0].bid, Price::from("99.0"));
+        assert_eq!(quotes[0].ask, Price::from("101.0"));
+        assert_eq!(quotes[0].ts_event, 2);
+        assert_eq!(quotes[0].ts_init, 3);
+        assert_eq!(quotes[1].bid_size, Quantity::from("3.0"));
+        assert_eq!(quotes[2].ask, Price::from("100.5"));
+        assert_eq!(quotes[2].ask_size, Quantity::from("1.0"));
+    }
+
+    #[test]
+    fn test_quote_emitted_again_after_side_empties() {
+        let mut book = create_book();
+        let mut sampler = QuoteSampler::new();
+        sampler
+            .apply_delta(&mut book, delta(OrderSide::Buy, "99.0", "1.0", 1))
+            .unwrap();
+        sampler
+            .apply_delta(&mut book, delta(OrderSide::Sell, "101.0", "1.0", 2))
+            .unwrap();
+
+        let emptied = sampler
+            .apply_delta(&mut book, delta(OrderSide::Sell, "101.0", "0.0", 3))
+            .unwrap();
+        let last_when_emptied = sampler.last().cloned();
+        let restored = sampler
+            .apply_delta(&mut book, delta(OrderSide::Sell, "101.0", "1.0", 4))
+            .unwrap();
+
+        assert!(emptied.is_none());
+        assert!(last_when_emptied.is_none());
+        assert_eq!(restored.unwrap().ts_event, 4);
+    }
+
+    #[test]
+    fn test_mid_bars_from_deltas() {
+        let mut book = create_book();
+        let deltas = vec![
+            delta(OrderSide::Buy, "99.0", "1.0", SECOND / 2),
+            delta(OrderSide::Sell, "101.0", "1.0", SECOND / 2),
+            delta(OrderSide::Sell, "103.0", "1.0", SECOND / 2 + 1),
+            delta(OrderSide::Buy, "100.0", "1.0", SECOND / 2 + 2),
+            delta(OrderSide::Sell, "101.0", "0.0", SECOND + 1),
+            delta(OrderSide::Buy, "98.0", "1.0", 3 * SECOND), // No samples in second 2
+        ];
+
+        let bars = bars_from_deltas(&mut book, bar_type(PriceType::Mid), deltas).unwrap();
+
+        assert_eq!(bars.len(), 3);
+        assert_eq!(bars[0].open, Price::from("100.00"));
+        assert_eq!(bars[0].high, Price::from("100.50"));
+        assert_eq!(bars[0].low, Price::from("100.00"));
+        assert_eq!(bars[0].close, Price::from("100.50"));
+        assert_eq!(bars[0].volume, Quantity::zero(1));
+        assert_eq!(bars[0].ts_event, SECOND);
+        assert_eq!(bars[1].open, Price::from("100.50"));
+        assert_eq!(bars[1].high, Price::from("101.50"));
+        assert_eq!(bars[1].close, Price::from("101.50"));
+        assert_eq!(bars[1].ts_event, 2 * SECOND);
+        assert_eq!(bars[2].open, Price::from("101.50"));
+        assert_eq!(bars[2].close, Price::from("101.50"));
+        assert_eq!(bars[2].ts_event, 4 * SECOND);
+    }
+
+    #[test]
+    fn test_bid_bars_sample_one_sided_book() {
+        let mut book = create_book();
+        let mut sampler = BarSampler::new(bar_type(PriceType::Bid));
+        sampler
+            .apply_delta(&mut book, delta(OrderSide::Buy, "99.0", "1.0", 1))
+            .unwrap();
+        sampler
+            .apply_delta(&mut book, delta(OrderSide::Buy, "98.0", "1.0", 2))
+            .unwrap();
+
+        let bar = sampler.advance(SECOND).unwrap();
+
+        assert_eq!(bar.open, Price::from("99.0"));
+        assert_eq!(bar.close, Price::from("99.0"));
+        assert_eq!(bar.ts_event, SECOND);
+        assert!(sampler.advance(2 * SECOND).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `BarAggregation` for `BarSampler`")]
+    fn test_new_with_non_time_aggregation() {
+        let mut bar_type = bar_type(PriceType::Mid);
+        bar_type.spec.aggregation = BarAggregation::Tick;
+        let _ = BarSampler::new(bar_type);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `PriceType` for `BarSampler`")]
+    fn test_new_with_last_price_type() {
+        let _ = BarSampler::new(bar_type(PriceType::Last));
+    }
+}
@@ -0,0 +1,311 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::{data::book::OrderBookDelta, enums::BookAction};
+
+/// The sequence validation mode for order book delta streams.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SequenceMode {
+    /// Deltas are applied regardless of their sequence.
+    #[default]
+    Off = 0,
+    /// Deltas which are not next in sequence are rejected.
+    Reject = 1,
+    /// Deltas ahead of the next sequence are buffered until the gap is filled.
+    Buffer = 2,
+}
+
+/// The outcome of a delta which passed sequence validation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SequenceStatus {
+    /// The delta, and any buffered deltas which followed it, were applied.
+    Applied,
+    /// The delta was buffered until the gap before it is filled.
+    Buffered { expected: u64 },
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SequenceError {
+    #[error("Sequence error: gap detected, expected {expected} but received {received}")]
+    Gap { expected: u64, received: u64 },
+    #[error("Sequence error: duplicate sequence {0}")]
+    Duplicate(u64),
+    #[error("Sequence error: regression from {last} to {received}")]
+    Regression { last: u64, received: u64 },
+    #[error("Sequence error: buffer full with {buffered} deltas, expected {expected}")]
+    BufferFull { expected: u64, buffered: usize },
+}
+
+/// Tracks the sequence of the deltas applied to an order book.
+///
+/// Sequences are expected to increase by one for each delta. The first delta, and
+/// any `Clear` delta, starts a new baseline, and the deltas which follow with the
+/// same sequence are accepted as part of that snapshot.
+#[derive(Clone, Debug)]
+pub struct SequenceTracker {
+    pub mode: SequenceMode,
+    pub max_buffered: usize,
+    last: Option<u64>,
+    in_snapshot: bool,
+    buffer: BTreeMap<u64, OrderBookDelta>,
+}
+
+impl SequenceTracker {
+    #[must_use]
+    pub fn new(mode: SequenceMode, max_buffered: usize) -> Self {
+        Self {
+            mode,
+            max_buffered,
+            last: None,
+            in_snapshot: false,
+            buffer: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the sequence of the last accepted delta.
+    #[must_use]
+    pub fn last(&self) -> Option<u64> {
+        self.last
+    }
+
+    /// Returns the number of deltas waiting for a gap to be filled.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Clears the baseline and any buffered deltas, so the next delta is accepted.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.in_snapshot = false;
+        self.buffer.clear();
+    }
+
+    /// Checks the sequence of the `delta` against the last accepted delta.
+    ///
+    /// # Errors
+    ///
+    /// If the mode is not `Off` and the delta is not next in sequence.
+    pub fn check(&self, delta: &OrderBookDelta) -> Result<(), SequenceError> {
+        let last = match self.last {
+            Some(last) if self.mode != SequenceMode::Off => last,
+            _ => return Ok(()),
+        };
+        if delta.action == BookAction::Clear {
+            return Ok(());
+        }
+
+        let received = delta.sequence;
+        if received == last + 1 || (received == last && self.in_snapshot) {
+            Ok(())
+        } else if received == last {
+            Err(SequenceError::Duplicate(received))
+        } else if received < last {
+            Err(SequenceError::Regression { last, received })
+        } else {
+            Err(SequenceError::Gap {
+                expected: last + 1,
+                received,
+            })
+        }
+    }
+
+    /// Records the `delta` as applied.
+    pub fn accept(&mut self, delta: &OrderBookDelta) {
+        let sequence = delta.sequence;
+        self.in_snapshot = delta.action == BookAction::Clear
+            || self.last.is_none()
+            || (self.in_snapshot && self.last == Some(sequence));
+        self.last = Some(sequence);
+
+        if delta.action == BookAction::Clear {
+            // Deltas at or before a new snapshot can never be applied
+            self.buffer = self.buffer.split_off(&(sequence + 1));
+        }
+    }
+
+    /// Buffers the `delta` until the deltas before it have been accepted.
+    ///
+    /// # Errors
+    ///
+    /// - If a delta with the same sequence is already buffered.
+    /// - If the buffer is full, in which case the buffer is cleared and the book
+    ///   should be rebuilt from a snapshot.
+    pub fn buffer(&mut self, delta: OrderBookDelta) -> Result<(), SequenceError> {
+        if self.buffer.contains_key(&delta.sequence) {
+            return Err(SequenceError::Duplicate(delta.sequence));
+        }
+        if self.buffer.len() >= self.max_buffered {
+            let buffered = self.buffer.len();
+            self.buffer.clear();
+            return Err(SequenceError::BufferFull {
+                expected: self.last.map_or(0, |last| last + 1),
+                buffered,
+            });
+        }
+
+        self.buffer.insert(delta.sequence, delta);
+        Ok(())
+    }
+
+    /// Removes and returns the buffered delta which is next in sequence, if any.
+    pub fn pop_next(&mut self) -> Option<OrderBookDelta> {
+        let next = self.last? + 1;
+        self.buffer.remove(&next)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        data::book::BookOrder,
+        enums::OrderSide,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn delta(action: BookAction, sequence: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            action,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from("10"),
+                sequence,
+            ),
+            0,
+            sequence,
+            0,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_check_when_off_accepts_any_sequence() {
+        let mut tracker = SequenceTracker::new(SequenceMode::Off, 10);
+        tracker.accept(&delta(BookAction::Add, 5));
+
+        assert_eq!(tracker.check(&delta(BookAction::Add, 2)), Ok(()));
+        assert_eq!(tracker.check(&delta(BookAction::Add, 9)), Ok(()));
+    }
+
+    #[test]
+    fn test_check_classifies_errors() {
+        let mut tracker = SequenceTracker::new(SequenceMode::Reject, 10);
+        tracker.accept(&delta(BookAction::Add, 5));
+        tracker.accept(&delta(BookAction::Add, 6));
+
+        assert_eq!(tracker.check(&delta(BookAction::Add, 7)), Ok(()));
+        assert_eq!(
+            tracker.check(&delta(BookAction::Add, 6)),
+            Err(SequenceError::Duplicate(6))
+        );
+        assert_eq!(
+            tracker.check(&delta(BookAction::Add, 4)),
+            Err(SequenceError::Regression {
+                last: 6,
+                received: 4
+            })
+        );
+        assert_eq!(
+            tracker.check(&delta(BookAction::Add, 9)),
+            Err(SequenceError::Gap {
+                expected: 7,
+                received: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_accepts_snapshot_with_shared_sequence() {
+        let mut tracker = SequenceTracker::new(SequenceMode::Reject, 10);
+        tracker.accept(&delta(BookAction::Update, 3));
+
+        // A snapshot may jump ahead, and its orders share the snapshot sequence
+        assert_eq!(tracker.check(&delta(BookAction::Clear, 20)), Ok(()));
+        tracker.accept(&delta(BookAction::Clear, 20));
+        assert_eq!(tracker.check(&delta(BookAction::Add, 20)), Ok(()));
+        tracker.accept(&delta(BookAction::Add, 20));
+        tracker.accept(&delta(BookAction::Update, 21));
+
+        assert_eq!(
+            tracker.check(&delta(BookAction::Update, 21)),
+            Err(SequenceError::Duplicate(21))
+        );
+    }
+
+    #[test]
+    fn test_buffer_and_pop_next() {
+        let mut tracker = SequenceTracker::new(SequenceMode::Buffer, 10);
+        tracker.accept(&delta(BookAction::Add, 1));
+        tracker.buffer(delta(BookAction::Add, 3)).unwrap();
+
+        assert_eq!(tracker.buffered(), 1);
+        assert_eq!(
+            tracker.buffer(delta(BookAction::Add, 3)),
+            Err(SequenceError::Duplicate(3))
+        );
+        assert_eq!(tracker.pop_next(), None);
+
+        tracker.accept(&delta(BookAction::Add, 2));
+
+        assert_eq!(tracker.pop_next().map(|d| d.sequence), Some(3));
+        assert_eq!(tracker.buffered(), 0);
+    }
+
+    #[test]
+    fn test_buffer_when_full_clears_buffer() {
+        let mut tracker = SequenceTracker::new(SequenceMode::Buffer, 2);
+        tracker.accept(&delta(BookAction::Add, 1));
+        tracker.buffer(delta(BookAction::Add, 3)).unwrap();
+        tracker.buffer(delta(BookAction::Add, 4)).unwrap();
+
+        let result = tracker.buffer(delta(BookAction::Add, 5));
+
+        assert_eq!(
+            result,
+            Err(SequenceError::BufferFull {
+                expected: 2,
+                buffered: 2
+            })
+        );
+        assert_eq!(tracker.buffered(), 0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tracker = SequenceTracker::new(SequenceMode::Buffer, 2);
+        tracker.accept(&delta(BookAction::Add, 1));
+        tracker.buffer(delta(BookAction::Add, 3)).unwrap();
+
+        tracker.reset();
+
+        assert_eq!(tracker.last(), None);
+        assert_eq!(tracker.buffered(), 0);
+        assert_eq!(tracker.check(&delta(BookAction::Add, 10)), Ok(()));
+    }
+}
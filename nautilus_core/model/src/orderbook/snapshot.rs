@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_core::correctness::is_valid_string;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    data::book::BookOrder,
+    enums::{BookType, FromU8, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    types::{fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
+};
+
+/// The current version of the order book snapshot schema.
+pub const SNAPSHOT_VERSION: u8 = 1;
+
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    #[error("Snapshot error: unsupported version {0}, expected {SNAPSHOT_VERSION}")]
+    UnsupportedVersion(u8),
+    #[error("Snapshot error: invalid {0}")]
+    InvalidField(String),
+    #[error("Snapshot error: duplicate order ID {0}")]
+    DuplicateOrderId(u64),
+    #[error("Snapshot error: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+    #[error("Snapshot error: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+}
+
+/// Represents a single order in an order book snapshot.
+///
+/// Prices and sizes are stored as raw fixed-point values with their precision, so a
+/// snapshot restores the book exactly.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotOrder {
+    pub side: u8,
+    pub price_raw: i64,
+    pub price_precision: u8,
+    pub size_raw: u64,
+    pub size_precision: u8,
+    pub order_id: u64,
+}
+
+impl From<&BookOrder> for SnapshotOrder {
+    fn from(order: &BookOrder) -> Self {
+        Self {
+            side: order.side as u8,
+            price_raw: order.price.raw,
+            price_precision: order.price.precision,
+            size_raw: order.size.raw,
+            size_precision: order.size.precision,
+            order_id: order.order_id,
+        }
+    }
+}
+
+impl TryFrom<&SnapshotOrder> for BookOrder {
+    type Error = SnapshotError;
+
+    fn try_from(order: &SnapshotOrder) -> Result<Self, Self::Error> {
+        let side = match OrderSide::from_u8(order.side) {
+            Some(side @ (OrderSide::Buy | OrderSide::Sell)) => side,
+            _ => {
+                return Err(SnapshotError::InvalidField(format!(
+                    "order side {}",
+                    order.side
+                )))
+            }
+        };
+        if order.price_precision > FIXED_PRECISION {
+            return Err(SnapshotError::InvalidField(format!(
+                "price precision {}",
+                order.price_precision
+            )));
+        }
+        if order.size_precision > FIXED_PRECISION {
+            return Err(SnapshotError::InvalidField(format!(
+                "size precision {}",
+                order.size_precision
+            )));
+        }
+        Ok(Self::new(
+            side,
+            Price::from_raw(order.price_raw, order.price_precision),
+            Quantity::from_raw(order.size_raw, order.size_precision),
+            order.order_id,
+        ))
+    }
+}
+
+/// Represents the full state of an order book in a stable schema, encoded as
+/// MessagePack for a compact binary format.
+///
+/// The orders are ordered from the best price outwards, bids then asks, and in
+/// queue order within each price level.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookSnapshot {
+    pub version: u8,
+    pub instrument_id: String,
+    pub book_type: u8,
+    pub sequence: u64,
+    pub ts_last: u64,
+    pub count: u64,
+    pub orders: Vec<SnapshotOrder>,
+}
+
+impl BookSnapshot {
+    /// Encodes the snapshot to bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the snapshot cannot be encoded.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SnapshotError> {
+        Ok(rmp_serde::to_vec(self)?)
+    }
+
+    /// Decodes a snapshot from `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// - If the bytes cannot be decoded.
+    /// - If the snapshot was written with an unsupported schema version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let snapshot: Self = rmp_serde::from_slice(bytes)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(snapshot.version));
+        }
+        Ok(snapshot)
+    }
+
+    /// Returns the instrument ID of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// If the instrument ID is not valid.
+    pub fn instrument_id(&self) -> Result<InstrumentId, SnapshotError> {
+        let invalid =
+            || SnapshotError::InvalidField(format!("instrument ID {}", self.instrument_id));
+        // Validate the parts first, as the identifier constructors panic on invalid strings
+        match self.instrument_id.rsplit_once('.') {
+            Some((symbol, venue)) if is_valid_string(symbol) && is_valid_string(venue) => {
+                InstrumentId::from_str(&self.instrument_id).map_err(|_| invalid())
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Returns the book type of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// If the book type is not valid.
+    pub fn book_type(&self) -> Result<BookType, SnapshotError> {
+        BookType::from_u8(self.book_type)
+            .ok_or_else(|| SnapshotError::InvalidField(format!("book type {}", self.book_type)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_stub_snapshot(version: u8) -> BookSnapshot {
+        BookSnapshot {
+            version,
+            instrument_id: "ETHUSDT-PERP.BINANCE".to_string(),
+            book_type: BookType::L3_MBO as u8,
+            sequence: 10,
+            ts_last: 100,
+            count: 3,
+            orders: vec![SnapshotOrder::from(&BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("2.0"),
+                1,
+            ))],
+        }
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let snapshot = create_stub_snapshot(SNAPSHOT_VERSION);
+
+        let bytes = snapshot.to_bytes().unwrap();
+
+        assert_eq!(BookSnapshot::from_bytes(&bytes).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_from_bytes_with_unsupported_version() {
+        let bytes = create_stub_snapshot(SNAPSHOT_VERSION + 1)
+            .to_bytes()
+            .unwrap();
+
+        assert!(matches!(
+            BookSnapshot::from_bytes(&bytes),
+            Err(SnapshotError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_with_invalid_bytes() {
+        assert!(matches!(
+            BookSnapshot::from_bytes(&[0xc1]),
+            Err(SnapshotError::Decode(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("ETHUSDT-PERP.")]
+    #[case(".BINANCE")]
+    #[case("ETHUSDT-PERP")]
+    #[case("ETHUSDT-PERP.  ")]
+    #[case("ÉTH.BINANCE")]
+    fn test_instrument_id_when_invalid(#[case] value: &str) {
+        let mut snapshot = create_stub_snapshot(SNAPSHOT_VERSION);
+        snapshot.instrument_id = value.to_string();
+
+        assert!(matches!(
+            snapshot.instrument_id(),
+            Err(SnapshotError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn test_book_type_when_invalid() {
+        let mut snapshot = create_stub_snapshot(SNAPSHOT_VERSION);
+        snapshot.book_type = 255;
+
+        assert!(matches!(
+            snapshot.book_type(),
+            Err(SnapshotError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn test_order_with_invalid_price_precision() {
+        let mut order = create_stub_snapshot(SNAPSHOT_VERSION).orders[0].clone();
+        order.price_precision = FIXED_PRECISION + 1;
+
+        assert!(matches!(
+            BookOrder::try_from(&order),
+            Err(SnapshotError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn test_order_with_invalid_size_precision() {
+        let mut order = create_stub_snapshot(SNAPSHOT_VERSION).orders[0].clone();
+        order.size_precision = u8::MAX;
+
+        assert!(matches!(
+            BookOrder::try_from(&order),
+            Err(SnapshotError::InvalidField(_))
+        ));
+    }
+
+    #[test]
+    fn test_order_with_no_order_side_is_invalid() {
+        let mut order = create_stub_snapshot(SNAPSHOT_VERSION).orders[0].clone();
+        order.side = OrderSide::NoOrderSide as u8;
+
+        assert!(matches!(
+            BookOrder::try_from(&order),
+            Err(SnapshotError::InvalidField(_))
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::{check_order_side, check_quantity_positive, Order};
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::quantity::Quantity,
+};
+
+pub trait MarketOrder {
+    /// Initializes a new MARKET order.
+    ///
+    /// # Panics
+    ///
+    /// - If `order_side` is `NoOrderSide`.
+    /// - If `quantity` is not positive.
+    /// - If `time_in_force` is `Gtd`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        quote_quantity: bool,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self;
+}
+
+impl MarketOrder for Order {
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        quote_quantity: bool,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        check_order_side(order_side);
+        check_quantity_positive(quantity);
+        if time_in_force == TimeInForce::Gtd {
+            panic!("invalid `TimeInForce` for MARKET order, was {time_in_force}");
+        }
+
+        Self::from(OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::Market,
+            quantity,
+            time_in_force,
+            reduce_only,
+            quote_quantity,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            ..Default::default()
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::enums::OrderStatus;
+
+    fn market_order(order_side: OrderSide, quantity: &str, time_in_force: TimeInForce) -> Order {
+        <Order as MarketOrder>::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            order_side,
+            Quantity::from(quantity),
+            time_in_force,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_new_market_order() {
+        let order = market_order(OrderSide::Buy, "100", TimeInForce::Ioc);
+
+        assert_eq!(order.order_type, OrderType::Market);
+        assert_eq!(order.status, OrderStatus::Initialized);
+        assert_eq!(order.side, OrderSide::Buy);
+        assert_eq!(order.quantity, Quantity::from("100"));
+        assert_eq!(order.leaves_qty, Quantity::from("100"));
+        assert_eq!(order.time_in_force, TimeInForce::Ioc);
+        assert_eq!(order.price, None);
+        assert_eq!(order.ts_init, 1);
+        assert!(order.is_aggressive());
+    }
+
+    #[rstest]
+    #[case(OrderSide::NoOrderSide, "100", TimeInForce::Gtc)]
+    #[case(OrderSide::Buy, "0", TimeInForce::Gtc)]
+    #[case(OrderSide::Sell, "100", TimeInForce::Gtd)]
+    #[should_panic]
+    fn test_new_market_order_with_invalid_params(
+        #[case] order_side: OrderSide,
+        #[case] quantity: &str,
+        #[case] time_in_force: TimeInForce,
+    ) {
+        let _ = market_order(order_side, quantity, time_in_force);
+    }
+}
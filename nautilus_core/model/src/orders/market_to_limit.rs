@@ -0,0 +1,188 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::{
+    check_display_qty, check_order_side, check_quantity_positive, check_time_in_force, Order,
+};
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+pub trait MarketToLimitOrder {
+    /// Initializes a new MARKET_TO_LIMIT order.
+    ///
+    /// The limit price is only set once the order is filled at the market.
+    ///
+    /// # Panics
+    ///
+    /// - If `order_side` is `NoOrderSide`.
+    /// - If `quantity` is not positive.
+    /// - If `time_in_force` is `AtTheOpen` or `AtTheClose`.
+    /// - If `expire_time` is inconsistent with `time_in_force`.
+    /// - If `display_qty` is greater than `quantity`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self;
+
+    /// Returns the limit price of the order, if it has been filled at the market.
+    fn price(&self) -> Option<&Price>;
+}
+
+impl MarketToLimitOrder for Order {
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        check_order_side(order_side);
+        check_quantity_positive(quantity);
+        check_time_in_force(time_in_force, expire_time);
+        check_display_qty(display_qty, quantity);
+
+        Self::from(OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::MarketToLimit,
+            quantity,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            ..Default::default()
+        })
+    }
+
+    fn price(&self) -> Option<&Price> {
+        self.price.as_ref()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market_to_limit_order(
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        display_qty: Option<Quantity>,
+    ) -> Order {
+        <Order as MarketToLimitOrder>::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Sell,
+            Quantity::from("100"),
+            time_in_force,
+            expire_time,
+            false,
+            false,
+            display_qty,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_new_market_to_limit_order() {
+        let order =
+            market_to_limit_order(TimeInForce::Gtd, Some(1_000), Some(Quantity::from("10")));
+
+        assert_eq!(order.order_type, OrderType::MarketToLimit);
+        assert_eq!(order.expire_time, Some(1_000));
+        assert_eq!(order.display_qty, Some(Quantity::from("10")));
+        assert_eq!(MarketToLimitOrder::price(&order), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_market_to_limit_order_with_gtd_and_no_expire_time() {
+        let _ = market_to_limit_order(TimeInForce::Gtd, None, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_market_to_limit_order_with_expire_time_when_not_gtd() {
+        let _ = market_to_limit_order(TimeInForce::Gtc, Some(1_000), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_market_to_limit_order_with_display_qty_exceeding_quantity() {
+        let _ = market_to_limit_order(TimeInForce::Gtc, None, Some(Quantity::from("101")));
+    }
+}
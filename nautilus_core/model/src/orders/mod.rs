@@ -16,6 +16,14 @@
 #![allow(dead_code)]
 
 pub mod limit;
+pub mod limit_if_touched;
+pub mod market;
+pub mod market_if_touched;
+pub mod market_to_limit;
+pub mod stop_limit;
+pub mod stop_market;
+pub mod trailing_stop_limit;
+pub mod trailing_stop_market;
 
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 use thiserror::Error;
@@ -23,7 +31,7 @@ use thiserror::Error;
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
-        TimeInForce, TriggerType,
+        TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::order::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEvent, OrderExpired,
@@ -46,6 +54,104 @@ pub enum OrderError {
     UnrecognizedEvent,
 }
 
+/// Check the `order_side` is a valid side for an order.
+///
+/// # Panics
+///
+/// - If `order_side` is `NoOrderSide`.
+fn check_order_side(order_side: OrderSide) {
+    if order_side == OrderSide::NoOrderSide {
+        panic!("invalid `OrderSide` for order, was {order_side}");
+    }
+}
+
+/// Check the `quantity` is positive.
+///
+/// # Panics
+///
+/// - If `quantity` is zero.
+fn check_quantity_positive(quantity: Quantity) {
+    if quantity.raw == 0 {
+        panic!("invalid `Quantity` for order, was {quantity}");
+    }
+}
+
+/// Check the `time_in_force` is valid for a passive order with the given `expire_time`.
+///
+/// # Panics
+///
+/// - If `time_in_force` is `AtTheOpen` or `AtTheClose`.
+/// - If `time_in_force` is `Gtd` and `expire_time` is not after the UNIX epoch.
+/// - If `time_in_force` is not `Gtd` and `expire_time` is set.
+fn check_time_in_force(time_in_force: TimeInForce, expire_time: Option<UnixNanos>) {
+    if time_in_force == TimeInForce::AtTheOpen || time_in_force == TimeInForce::AtTheClose {
+        panic!("invalid `TimeInForce` for order, was {time_in_force}");
+    }
+    check_expire_time(time_in_force, expire_time);
+}
+
+/// Check the `expire_time` is consistent with the `time_in_force`.
+///
+/// # Panics
+///
+/// - If `time_in_force` is `Gtd` and `expire_time` is not after the UNIX epoch.
+/// - If `time_in_force` is not `Gtd` and `expire_time` is set.
+fn check_expire_time(time_in_force: TimeInForce, expire_time: Option<UnixNanos>) {
+    if time_in_force == TimeInForce::Gtd {
+        if expire_time.unwrap_or(0) == 0 {
+            panic!("invalid `expire_time` for GTD order, cannot be <= UNIX epoch");
+        }
+    } else if expire_time.is_some() {
+        panic!("invalid `expire_time` for order, was set when `time_in_force` not GTD");
+    }
+}
+
+/// Check the `trigger_type` is a valid trigger type for an order.
+///
+/// # Panics
+///
+/// - If `trigger_type` is `NoTrigger`.
+fn check_trigger_type(trigger_type: TriggerType) {
+    if trigger_type == TriggerType::NoTrigger {
+        panic!("invalid `TriggerType` for order, was {trigger_type}");
+    }
+}
+
+/// Check the `trailing_offset_type` is a valid trailing offset type for an order.
+///
+/// # Panics
+///
+/// - If `trailing_offset_type` is `NoTrailingOffset`.
+fn check_trailing_offset_type(trailing_offset_type: TrailingOffsetType) {
+    if trailing_offset_type == TrailingOffsetType::NoTrailingOffset {
+        panic!("invalid `TrailingOffsetType` for order, was {trailing_offset_type}");
+    }
+}
+
+/// Check the `offset` is not negative.
+///
+/// # Panics
+///
+/// - If `offset` is negative.
+fn check_offset_non_negative(offset: Price, desc: &str) {
+    if offset.raw < 0 {
+        panic!("invalid `{desc}` for order, was negative {offset}");
+    }
+}
+
+/// Check the `display_qty` does not exceed the order `quantity`.
+///
+/// # Panics
+///
+/// - If `display_qty` is greater than `quantity`.
+fn check_display_qty(display_qty: Option<Quantity>, quantity: Quantity) {
+    if let Some(display_qty) = display_qty {
+        if display_qty > quantity {
+            panic!("invalid `display_qty` for order, was {display_qty} when `quantity` {quantity}");
+        }
+    }
+}
+
 impl OrderStatus {
     #[rustfmt::skip]
     pub fn transition(&mut self, event: &OrderEvent) -> Result<OrderStatus, OrderError> {
@@ -65,6 +171,7 @@ impl OrderStatus {
             (OrderStatus::Submitted, OrderEvent::OrderTriggered(_)) => OrderStatus::Triggered,  // Covers emulated StopLimit order
             (OrderStatus::Submitted, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::Submitted, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::Submitted, OrderEvent::OrderUpdated(_)) => OrderStatus::Submitted,
             (OrderStatus::Accepted, OrderEvent::OrderRejected(_)) => OrderStatus::Rejected,  // Covers StopLimit order
             (OrderStatus::Accepted, OrderEvent::OrderPendingUpdate(_)) => OrderStatus::PendingUpdate,
             (OrderStatus::Accepted, OrderEvent::OrderPendingCancel(_)) => OrderStatus::PendingCancel,
@@ -73,6 +180,7 @@ impl OrderStatus {
             (OrderStatus::Accepted, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::Accepted, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::Accepted, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::Accepted, OrderEvent::OrderUpdated(_)) => OrderStatus::Accepted,
             (OrderStatus::Canceled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,  // Real world possibility
             (OrderStatus::Canceled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,  // Real world possibility
             (OrderStatus::PendingUpdate, OrderEvent::OrderRejected(_)) => OrderStatus::Rejected,
@@ -84,6 +192,7 @@ impl OrderStatus {
             (OrderStatus::PendingUpdate, OrderEvent::OrderPendingCancel(_)) => OrderStatus::PendingCancel,
             (OrderStatus::PendingUpdate, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::PendingUpdate, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::PendingUpdate, OrderEvent::OrderUpdated(_)) => OrderStatus::PendingUpdate,  // Restored to previous status when applied
             (OrderStatus::PendingCancel, OrderEvent::OrderRejected(_)) => OrderStatus::Rejected,
             (OrderStatus::PendingCancel, OrderEvent::OrderPendingCancel(_)) => OrderStatus::PendingCancel,  // Allow multiple requests
             (OrderStatus::PendingCancel, OrderEvent::OrderCanceled(_)) => OrderStatus::Canceled,
@@ -97,19 +206,22 @@ impl OrderStatus {
             (OrderStatus::Triggered, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::Triggered, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::Triggered, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::Triggered, OrderEvent::OrderUpdated(_)) => OrderStatus::Triggered,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPendingUpdate(_)) => OrderStatus::PendingUpdate,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPendingCancel(_)) => OrderStatus::PendingCancel,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderCanceled(_)) => OrderStatus::Canceled,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::PartiallyFilled, OrderEvent::OrderUpdated(_)) => OrderStatus::PartiallyFilled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
     }
 }
 
-struct Order {
+#[derive(Clone, Debug)]
+pub struct Order {
     events: Vec<OrderEvent>,
     venue_order_ids: Vec<VenueOrderId>, // TODO(cs): Should be `Vec<&VenueOrderId>` or similar
     trade_ids: Vec<TradeId>,            // TODO(cs): Should be `Vec<&TradeId>` or similar
@@ -139,7 +251,7 @@ struct Order {
     pub display_qty: Option<Quantity>,
     pub limit_offset: Option<Price>,
     pub trailing_offset: Option<Price>,
-    pub trailing_offset_type: Option<TriggerType>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
     pub emulation_trigger: Option<TriggerType>,
     pub contingency_type: Option<ContingencyType>,
     pub order_list_id: Option<OrderListId>,
@@ -188,15 +300,15 @@ impl From<OrderInitialized> for Order {
             trigger_price: value.trigger_price,
             trigger_type: value.trigger_type,
             time_in_force: value.time_in_force,
-            expire_time: None,
+            expire_time: value.expire_time,
             liquidity_side: None,
             is_post_only: value.post_only,
             is_reduce_only: value.reduce_only,
             is_quote_quantity: value.quote_quantity,
-            display_qty: None,
-            limit_offset: None,
-            trailing_offset: None,
-            trailing_offset_type: None,
+            display_qty: value.display_qty,
+            limit_offset: value.limit_offset,
+            trailing_offset: value.trailing_offset,
+            trailing_offset_type: value.trailing_offset_type,
             emulation_trigger: value.emulation_trigger,
             contingency_type: value.contingency_type,
             order_list_id: value.order_list_id,
@@ -226,7 +338,7 @@ impl From<&Order> for OrderInitialized {
             order_type: value.order_type,
             quantity: value.quantity,
             price: value.price,
-            trigger_price: value.triggered_price,
+            trigger_price: value.trigger_price,
             trigger_type: value.trigger_type,
             time_in_force: value.time_in_force,
             expire_time: value.expire_time,
@@ -376,7 +488,7 @@ impl Order {
 
     pub fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
         let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
+        let previous_status = self.status;
         self.status = new_status;
 
         match &event {
@@ -392,9 +504,18 @@ impl Order {
             OrderEvent::OrderTriggered(event) => self.triggered(event),
             OrderEvent::OrderCanceled(event) => self.canceled(event),
             OrderEvent::OrderExpired(event) => self.expired(event),
+            OrderEvent::OrderPartiallyFilled(event) => self.filled(event),
+            OrderEvent::OrderFilled(event) => self.filled(event),
             _ => return Err(OrderError::UnrecognizedEvent),
         }
 
+        // Pending states are transient, so the status to restore is kept
+        if previous_status != OrderStatus::PendingUpdate
+            && previous_status != OrderStatus::PendingCancel
+        {
+            self.previous_status = Some(previous_status);
+        }
+
         self.events.push(event);
         Ok(())
     }
@@ -431,13 +552,21 @@ impl Order {
         self.status = self.previous_status.unwrap();
     }
 
-    fn triggered(&mut self, _event: &OrderTriggered) {}
+    fn triggered(&mut self, event: &OrderTriggered) {
+        self.ts_triggered = Some(event.ts_event);
+    }
 
     fn canceled(&mut self, _event: &OrderCanceled) {}
 
     fn expired(&mut self, _event: &OrderExpired) {}
 
     fn updated(&mut self, event: &OrderUpdated) {
+        if self.status == OrderStatus::PendingUpdate {
+            if let Some(previous_status) = self.previous_status {
+                self.status = previous_status;
+            }
+        }
+
         match &event.venue_order_id {
             Some(venue_order_id) => {
                 if self.venue_order_id.is_some()
@@ -478,16 +607,17 @@ impl Order {
         self.trade_ids.push(event.trade_id.clone());
         self.last_trade_id = Some(event.trade_id.clone());
         self.liquidity_side = Some(event.liquidity_side);
+        self.set_avg_px(&event.last_qty, &event.last_px);
         self.filled_qty += &event.last_qty;
         self.leaves_qty -= &event.last_qty;
         self.ts_last = event.ts_event;
-        self.set_avg_px(&event.last_qty, &event.last_px);
         self.set_slippage();
     }
 
     fn set_avg_px(&mut self, last_qty: &Quantity, last_px: &Price) {
         if self.avg_px.is_none() {
             self.avg_px = Some(last_px.as_f64());
+            return;
         }
 
         let filled_qty = self.filled_qty.as_f64();
@@ -527,7 +657,7 @@ mod tests {
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
             OrderAcceptedBuilder, OrderDeniedBuilder, OrderEvent, OrderInitializedBuilder,
-            OrderSubmittedBuilder,
+            OrderPendingUpdateBuilder, OrderSubmittedBuilder, OrderUpdatedBuilder,
         },
     };
 
@@ -654,4 +784,29 @@ mod tests {
 
         assert_eq!(order.client_order_id, client_order_id);
     }
+
+    #[test]
+    fn test_order_updated_restores_status_after_pending_update() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(200))
+            .build()
+            .unwrap();
+
+        let mut order: Order = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        order.apply(OrderEvent::OrderUpdated(updated)).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Accepted);
+        assert_eq!(order.quantity, Quantity::from(200));
+        assert_eq!(order.leaves_qty, Quantity::from(200));
+        assert_eq!(order.event_count(), 4);
+    }
 }
@@ -0,0 +1,230 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::{
+    check_display_qty, check_order_side, check_quantity_positive, check_time_in_force,
+    check_trigger_type, Order,
+};
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TriggerType},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+pub trait StopLimitOrder {
+    /// Initializes a new STOP_LIMIT order.
+    ///
+    /// # Panics
+    ///
+    /// - If `order_side` is `NoOrderSide`.
+    /// - If `quantity` is not positive.
+    /// - If `trigger_type` is `NoTrigger`.
+    /// - If `time_in_force` is `AtTheOpen` or `AtTheClose`.
+    /// - If `expire_time` is inconsistent with `time_in_force`.
+    /// - If `display_qty` is greater than `quantity`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self;
+
+    fn price(&self) -> &Price;
+
+    fn trigger_price(&self) -> &Price;
+
+    fn trigger_type(&self) -> TriggerType;
+}
+
+impl StopLimitOrder for Order {
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        check_order_side(order_side);
+        check_quantity_positive(quantity);
+        check_trigger_type(trigger_type);
+        check_time_in_force(time_in_force, expire_time);
+        check_display_qty(display_qty, quantity);
+
+        Self::from(OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::StopLimit,
+            quantity,
+            price: Some(price),
+            trigger_price: Some(trigger_price),
+            trigger_type: Some(trigger_type),
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            ..Default::default()
+        })
+    }
+
+    fn price(&self) -> &Price {
+        match &self.price {
+            Some(price) => price,
+            _ => panic!("Invalid `StopLimitOrder`: did not have a price"),
+        }
+    }
+
+    fn trigger_price(&self) -> &Price {
+        match &self.trigger_price {
+            Some(trigger_price) => trigger_price,
+            _ => panic!("Invalid `StopLimitOrder`: did not have a trigger price"),
+        }
+    }
+
+    fn trigger_type(&self) -> TriggerType {
+        match self.trigger_type {
+            Some(trigger_type) => trigger_type,
+            _ => panic!("Invalid `StopLimitOrder`: did not have a trigger type"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stop_limit_order(
+        trigger_type: TriggerType,
+        time_in_force: TimeInForce,
+        display_qty: Option<Quantity>,
+    ) -> Order {
+        <Order as StopLimitOrder>::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Price::from("1.00020"),
+            Price::from("1.00010"),
+            trigger_type,
+            time_in_force,
+            None,
+            true,
+            false,
+            false,
+            display_qty,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_new_stop_limit_order() {
+        let order = stop_limit_order(TriggerType::LastTrade, TimeInForce::Day, None);
+
+        assert_eq!(order.order_type, OrderType::StopLimit);
+        assert_eq!(StopLimitOrder::price(&order), &Price::from("1.00020"));
+        assert_eq!(
+            StopLimitOrder::trigger_price(&order),
+            &Price::from("1.00010")
+        );
+        assert_eq!(StopLimitOrder::trigger_type(&order), TriggerType::LastTrade);
+        assert!(order.is_post_only);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_stop_limit_order_with_at_the_open() {
+        let _ = stop_limit_order(TriggerType::LastTrade, TimeInForce::AtTheOpen, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_stop_limit_order_with_display_qty_exceeding_quantity() {
+        let _ = stop_limit_order(
+            TriggerType::LastTrade,
+            TimeInForce::Gtc,
+            Some(Quantity::from("200")),
+        );
+    }
+}
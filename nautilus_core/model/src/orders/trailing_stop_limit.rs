@@ -0,0 +1,278 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::{
+    check_display_qty, check_offset_non_negative, check_order_side, check_quantity_positive,
+    check_time_in_force, check_trailing_offset_type, check_trigger_type, Order,
+};
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+pub trait TrailingStopLimitOrder {
+    /// Initializes a new TRAILING_STOP_LIMIT order.
+    ///
+    /// The `price` and `trigger_price` may be `None`, in which case they will
+    /// be set from the market on submission.
+    ///
+    /// # Panics
+    ///
+    /// - If `order_side` is `NoOrderSide`.
+    /// - If `quantity` is not positive.
+    /// - If `trigger_type` is `NoTrigger`.
+    /// - If `limit_offset` or `trailing_offset` is negative.
+    /// - If `trailing_offset_type` is `NoTrailingOffset`.
+    /// - If `time_in_force` is `AtTheOpen` or `AtTheClose`.
+    /// - If `expire_time` is inconsistent with `time_in_force`.
+    /// - If `display_qty` is greater than `quantity`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self;
+
+    fn price(&self) -> Option<&Price>;
+
+    fn trigger_price(&self) -> Option<&Price>;
+
+    fn trigger_type(&self) -> TriggerType;
+
+    fn limit_offset(&self) -> &Price;
+
+    fn trailing_offset(&self) -> &Price;
+
+    fn trailing_offset_type(&self) -> TrailingOffsetType;
+}
+
+impl TrailingStopLimitOrder for Order {
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        quote_quantity: bool,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        check_order_side(order_side);
+        check_quantity_positive(quantity);
+        check_trigger_type(trigger_type);
+        check_offset_non_negative(limit_offset, "limit_offset");
+        check_offset_non_negative(trailing_offset, "trailing_offset");
+        check_trailing_offset_type(trailing_offset_type);
+        check_time_in_force(time_in_force, expire_time);
+        check_display_qty(display_qty, quantity);
+
+        Self::from(OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::TrailingStopLimit,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type: Some(trigger_type),
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            limit_offset: Some(limit_offset),
+            trailing_offset: Some(trailing_offset),
+            trailing_offset_type: Some(trailing_offset_type),
+            emulation_trigger,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            ..Default::default()
+        })
+    }
+
+    fn price(&self) -> Option<&Price> {
+        self.price.as_ref()
+    }
+
+    fn trigger_price(&self) -> Option<&Price> {
+        self.trigger_price.as_ref()
+    }
+
+    fn trigger_type(&self) -> TriggerType {
+        match self.trigger_type {
+            Some(trigger_type) => trigger_type,
+            _ => panic!("Invalid `TrailingStopLimitOrder`: did not have a trigger type"),
+        }
+    }
+
+    fn limit_offset(&self) -> &Price {
+        match &self.limit_offset {
+            Some(limit_offset) => limit_offset,
+            _ => panic!("Invalid `TrailingStopLimitOrder`: did not have a limit offset"),
+        }
+    }
+
+    fn trailing_offset(&self) -> &Price {
+        match &self.trailing_offset {
+            Some(trailing_offset) => trailing_offset,
+            _ => panic!("Invalid `TrailingStopLimitOrder`: did not have a trailing offset"),
+        }
+    }
+
+    fn trailing_offset_type(&self) -> TrailingOffsetType {
+        match self.trailing_offset_type {
+            Some(trailing_offset_type) => trailing_offset_type,
+            _ => panic!("Invalid `TrailingStopLimitOrder`: did not have a trailing offset type"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trailing_stop_limit_order(limit_offset: &str, trailing_offset: &str) -> Order {
+        <Order as TrailingStopLimitOrder>::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            Some(Price::from("1.00020")),
+            Some(Price::from("1.00010")),
+            TriggerType::BidAsk,
+            Price::from(limit_offset),
+            Price::from(trailing_offset),
+            TrailingOffsetType::Price,
+            TimeInForce::Gtd,
+            Some(1_000),
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_new_trailing_stop_limit_order() {
+        let order = trailing_stop_limit_order("0.00010", "0.00020");
+
+        assert_eq!(order.order_type, OrderType::TrailingStopLimit);
+        assert_eq!(
+            TrailingStopLimitOrder::price(&order),
+            Some(&Price::from("1.00020"))
+        );
+        assert_eq!(
+            TrailingStopLimitOrder::trigger_price(&order),
+            Some(&Price::from("1.00010"))
+        );
+        assert_eq!(
+            TrailingStopLimitOrder::limit_offset(&order),
+            &Price::from("0.00010")
+        );
+        assert_eq!(
+            TrailingStopLimitOrder::trailing_offset(&order),
+            &Price::from("0.00020")
+        );
+        assert_eq!(
+            TrailingStopLimitOrder::trailing_offset_type(&order),
+            TrailingOffsetType::Price
+        );
+        assert_eq!(order.expire_time, Some(1_000));
+    }
+
+    #[rstest]
+    #[case("-0.00010", "0.00020")]
+    #[case("0.00010", "-0.00020")]
+    #[should_panic]
+    fn test_new_trailing_stop_limit_order_with_negative_offsets(
+        #[case] limit_offset: &str,
+        #[case] trailing_offset: &str,
+    ) {
+        let _ = trailing_stop_limit_order(limit_offset, trailing_offset);
+    }
+}
@@ -0,0 +1,240 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+
+use super::{
+    check_offset_non_negative, check_order_side, check_quantity_positive, check_time_in_force,
+    check_trailing_offset_type, check_trigger_type, Order,
+};
+use crate::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
+    events::order::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+pub trait TrailingStopMarketOrder {
+    /// Initializes a new TRAILING_STOP_MARKET order.
+    ///
+    /// The `trigger_price` may be `None`, in which case the trigger price will
+    /// be set from the market on submission.
+    ///
+    /// # Panics
+    ///
+    /// - If `order_side` is `NoOrderSide`.
+    /// - If `quantity` is not positive.
+    /// - If `trigger_type` is `NoTrigger`.
+    /// - If `trailing_offset` is negative.
+    /// - If `trailing_offset_type` is `NoTrailingOffset`.
+    /// - If `time_in_force` is `AtTheOpen` or `AtTheClose`.
+    /// - If `expire_time` is inconsistent with `time_in_force`.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self;
+
+    fn trigger_price(&self) -> Option<&Price>;
+
+    fn trigger_type(&self) -> TriggerType;
+
+    fn trailing_offset(&self) -> &Price;
+
+    fn trailing_offset_type(&self) -> TrailingOffsetType;
+}
+
+impl TrailingStopMarketOrder for Order {
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Option<Price>,
+        trigger_type: TriggerType,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        reduce_only: bool,
+        quote_quantity: bool,
+        emulation_trigger: Option<TriggerType>,
+        contingency_type: Option<ContingencyType>,
+        order_list_id: Option<OrderListId>,
+        linked_order_ids: Option<Vec<ClientOrderId>>,
+        parent_order_id: Option<ClientOrderId>,
+        tags: Option<String>,
+        init_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        check_order_side(order_side);
+        check_quantity_positive(quantity);
+        check_trigger_type(trigger_type);
+        check_offset_non_negative(trailing_offset, "trailing_offset");
+        check_trailing_offset_type(trailing_offset_type);
+        check_time_in_force(time_in_force, expire_time);
+
+        Self::from(OrderInitialized {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type: OrderType::TrailingStopMarket,
+            quantity,
+            trigger_price,
+            trigger_type: Some(trigger_type),
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            trailing_offset: Some(trailing_offset),
+            trailing_offset_type: Some(trailing_offset_type),
+            emulation_trigger,
+            contingency_type,
+            order_list_id,
+            linked_order_ids,
+            parent_order_id,
+            tags,
+            event_id: init_id,
+            ts_event: ts_init,
+            ts_init,
+            ..Default::default()
+        })
+    }
+
+    fn trigger_price(&self) -> Option<&Price> {
+        self.trigger_price.as_ref()
+    }
+
+    fn trigger_type(&self) -> TriggerType {
+        match self.trigger_type {
+            Some(trigger_type) => trigger_type,
+            _ => panic!("Invalid `TrailingStopMarketOrder`: did not have a trigger type"),
+        }
+    }
+
+    fn trailing_offset(&self) -> &Price {
+        match &self.trailing_offset {
+            Some(trailing_offset) => trailing_offset,
+            _ => panic!("Invalid `TrailingStopMarketOrder`: did not have a trailing offset"),
+        }
+    }
+
+    fn trailing_offset_type(&self) -> TrailingOffsetType {
+        match self.trailing_offset_type {
+            Some(trailing_offset_type) => trailing_offset_type,
+            _ => panic!("Invalid `TrailingStopMarketOrder`: did not have a trailing offset type"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trailing_stop_market_order(
+        trailing_offset: &str,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> Order {
+        <Order as TrailingStopMarketOrder>::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::default(),
+            ClientOrderId::default(),
+            OrderSide::Sell,
+            Quantity::from("100"),
+            None,
+            TriggerType::Default,
+            Price::from(trailing_offset),
+            trailing_offset_type,
+            TimeInForce::Gtc,
+            None,
+            true,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_new_trailing_stop_market_order() {
+        let order = trailing_stop_market_order("50", TrailingOffsetType::BasisPoints);
+
+        assert_eq!(order.order_type, OrderType::TrailingStopMarket);
+        assert_eq!(TrailingStopMarketOrder::trigger_price(&order), None);
+        assert_eq!(
+            TrailingStopMarketOrder::trigger_type(&order),
+            TriggerType::Default
+        );
+        assert_eq!(
+            TrailingStopMarketOrder::trailing_offset(&order),
+            &Price::from("50")
+        );
+        assert_eq!(
+            TrailingStopMarketOrder::trailing_offset_type(&order),
+            TrailingOffsetType::BasisPoints
+        );
+        assert!(order.is_reduce_only);
+    }
+
+    #[rstest]
+    #[case("-1", TrailingOffsetType::Price)]
+    #[case("1", TrailingOffsetType::NoTrailingOffset)]
+    #[should_panic]
+    fn test_new_trailing_stop_market_order_with_invalid_offset(
+        #[case] trailing_offset: &str,
+        #[case] trailing_offset_type: TrailingOffsetType,
+    ) {
+        let _ = trailing_stop_market_order(trailing_offset, trailing_offset_type);
+    }
+}
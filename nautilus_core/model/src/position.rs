@@ -14,7 +14,6 @@
 // -------------------------------------------------------------------------------------------------
 
 // TODO: Liberal use of cloning to get things compiling initially
-#![allow(dead_code)]
 
 use std::collections::HashMap;
 
@@ -22,21 +21,25 @@ use nautilus_core::time::UnixNanos;
 
 use crate::{
     enums::{OrderSide, PositionSide},
-    events::order::OrderFilled,
+    events::{
+        order::OrderFilled,
+        position::{PositionChanged, PositionClosed, PositionEvent, PositionOpened},
+    },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
         venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
-    types::{currency::Currency, money::Money, quantity::Quantity},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
 /// Represents a position in a financial market.
 ///
 /// The position ID may be assigned at the trading venue, or can be system
 /// generated depending on a strategies OMS (Order Management System) settings.
-struct Position {
+#[derive(Clone, Debug)]
+pub struct Position {
     events: Vec<OrderFilled>,
     client_order_ids: Vec<ClientOrderId>,
     venue_order_ids: Vec<VenueOrderId>,
@@ -80,7 +83,7 @@ impl Position {
         assert!(fill.position_id.is_some());
         assert!(fill.order_side != OrderSide::NoOrderSide);
 
-        Self {
+        let mut position = Self {
             events: Vec::<OrderFilled>::new(),
             client_order_ids: Vec::<ClientOrderId>::new(),
             venue_order_ids: Vec::<VenueOrderId>::new(),
@@ -116,10 +119,18 @@ impl Position {
             avg_px_close: None,
             realized_return: None,
             realized_pnl: None,
-        }
+        };
+        position.apply(fill.clone());
+        position
     }
 
-    pub fn apply(&mut self, fill: OrderFilled) {
+    /// Applies the given order fill event to the position, returning the
+    /// resulting position event.
+    ///
+    /// # Panics
+    ///
+    /// If `fill.trade_id` has already been applied to the position.
+    pub fn apply(&mut self, fill: OrderFilled) -> PositionEvent {
         assert!(
             !self.trade_ids.contains(&fill.trade_id),
             "`fill.trade_id` already contained in `trade_ids",
@@ -128,6 +139,8 @@ impl Position {
         if self.side == PositionSide::Flat {
             // Reset position
             self.events.clear();
+            self.client_order_ids.clear();
+            self.venue_order_ids.clear();
             self.trade_ids.clear();
             self.buy_qty = Quantity::new(0.0, self.size_precision);
             self.sell_qty = Quantity::new(0.0, self.size_precision);
@@ -137,6 +150,7 @@ impl Position {
             self.peak_qty = Quantity::new(0.0, self.size_precision);
             self.ts_init = fill.ts_init;
             self.ts_opened = fill.ts_event;
+            self.ts_closed = None;
             self.duration_ns = None;
             self.avg_px_open = fill.last_px.as_f64();
             self.avg_px_close = None;
@@ -145,6 +159,12 @@ impl Position {
         }
 
         self.events.push(fill.clone()); // Potentially do this last
+        if !self.client_order_ids.contains(&fill.client_order_id) {
+            self.client_order_ids.push(fill.client_order_id.clone());
+        }
+        if !self.venue_order_ids.contains(&fill.venue_order_id) {
+            self.venue_order_ids.push(fill.venue_order_id.clone());
+        }
         self.trade_ids.push(fill.trade_id.clone());
 
         // Calculate cumulative commissions
@@ -160,9 +180,9 @@ impl Position {
 
         // Calculate avg prices, points, return, PnL
         match fill.order_side {
-            OrderSide::Buy => {}
-            OrderSide::Sell => {}
-            _ => panic!("invalid order side"),
+            OrderSide::Buy => self.handle_buy_order_fill(&fill),
+            OrderSide::Sell => self.handle_sell_order_fill(&fill),
+            _ => panic!("invalid `OrderSide`, was {}", fill.order_side),
         }
 
         // Set quantities
@@ -186,6 +206,251 @@ impl Position {
         }
 
         self.ts_last = fill.ts_event;
+
+        if self.is_closed() {
+            PositionEvent::PositionClosed(PositionClosed::create(self, &fill, fill.ts_init))
+        } else if self.events.len() == 1 {
+            PositionEvent::PositionOpened(PositionOpened::create(self, &fill, fill.ts_init))
+        } else {
+            PositionEvent::PositionChanged(PositionChanged::create(self, &fill, fill.ts_init))
+        }
+    }
+
+    #[must_use]
+    pub fn events(&self) -> &[OrderFilled] {
+        &self.events
+    }
+
+    #[must_use]
+    pub fn last_event(&self) -> Option<&OrderFilled> {
+        self.events.last()
+    }
+
+    #[must_use]
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    #[must_use]
+    pub fn client_order_ids(&self) -> &[ClientOrderId] {
+        &self.client_order_ids
+    }
+
+    #[must_use]
+    pub fn venue_order_ids(&self) -> &[VenueOrderId] {
+        &self.venue_order_ids
+    }
+
+    #[must_use]
+    pub fn trade_ids(&self) -> &[TradeId] {
+        &self.trade_ids
+    }
+
+    #[must_use]
+    pub fn last_trade_id(&self) -> Option<&TradeId> {
+        self.trade_ids.last()
+    }
+
+    #[must_use]
+    pub fn buy_qty(&self) -> Quantity {
+        self.buy_qty
+    }
+
+    #[must_use]
+    pub fn sell_qty(&self) -> Quantity {
+        self.sell_qty
+    }
+
+    #[must_use]
+    pub fn is_long(&self) -> bool {
+        self.side == PositionSide::Long
+    }
+
+    #[must_use]
+    pub fn is_short(&self) -> bool {
+        self.side == PositionSide::Short
+    }
+
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.side != PositionSide::Flat
+    }
+
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.side == PositionSide::Flat
+    }
+
+    /// Returns the total commissions generated by the position, one per currency.
+    #[must_use]
+    pub fn commissions(&self) -> Vec<Money> {
+        self.commissions.values().cloned().collect()
+    }
+
+    /// Returns the current notional value of the position at the `last` price.
+    ///
+    /// Result will be in quote currency for standard instruments, or base
+    /// currency for inverse instruments.
+    #[must_use]
+    pub fn notional_value(&self, last: &Price) -> Money {
+        let quantity = self.quantity.as_f64() * self.multiplier.as_f64();
+        if self.is_inverse {
+            Money::new(
+                quantity * (1.0 / last.as_f64()),
+                self.base_currency
+                    .clone()
+                    .expect("inverse instrument must have a `base_currency`"),
+            )
+        } else {
+            Money::new(quantity * last.as_f64(), self.quote_currency.clone())
+        }
+    }
+
+    /// Returns the unrealized PnL of the position at the `last` price.
+    ///
+    /// Result will be in the cost currency of the instrument, which is the
+    /// base currency for inverse instruments.
+    #[must_use]
+    pub fn unrealized_pnl(&self, last: &Price) -> Money {
+        if self.side == PositionSide::Flat {
+            return Money::new(0.0, self.cost_currency.clone());
+        }
+
+        let pnl = self.calculate_pnl(self.avg_px_open, last.as_f64(), self.quantity.as_f64());
+        Money::new(pnl, self.cost_currency.clone())
+    }
+
+    /// Returns the total (realized and unrealized) PnL of the position at the `last` price.
+    #[must_use]
+    pub fn total_pnl(&self, last: &Price) -> Money {
+        let realized_pnl = self.realized_pnl.as_ref().map_or(0.0, Money::as_f64);
+        Money::new(
+            realized_pnl + self.unrealized_pnl(last).as_f64(),
+            self.cost_currency.clone(),
+        )
+    }
+
+    fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
+        // Initialize realized PnL for fill
+        let mut realized_pnl = if fill.commission.currency == self.cost_currency {
+            -fill.commission.as_f64()
+        } else {
+            0.0
+        };
+
+        let last_px = fill.last_px.as_f64();
+        let last_qty = fill.last_qty.as_f64();
+
+        if self.signed_qty > 0.0 {
+            // LONG position
+            self.avg_px_open = self.calculate_avg_px_open_px(last_px, last_qty);
+        } else if self.signed_qty < 0.0 {
+            // SHORT position
+            let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
+            self.avg_px_close = Some(avg_px_close);
+            self.realized_return = Some(self.calculate_return(self.avg_px_open, avg_px_close));
+            realized_pnl += self.calculate_pnl(self.avg_px_open, last_px, last_qty);
+        }
+
+        self.add_realized_pnl(realized_pnl);
+        self.buy_qty.raw += fill.last_qty.raw;
+        self.signed_qty = self.round_qty(self.signed_qty + last_qty);
+    }
+
+    fn handle_sell_order_fill(&mut self, fill: &OrderFilled) {
+        // Initialize realized PnL for fill
+        let mut realized_pnl = if fill.commission.currency == self.cost_currency {
+            -fill.commission.as_f64()
+        } else {
+            0.0
+        };
+
+        let last_px = fill.last_px.as_f64();
+        let last_qty = fill.last_qty.as_f64();
+
+        if self.signed_qty < 0.0 {
+            // SHORT position
+            self.avg_px_open = self.calculate_avg_px_open_px(last_px, last_qty);
+        } else if self.signed_qty > 0.0 {
+            // LONG position
+            let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
+            self.avg_px_close = Some(avg_px_close);
+            self.realized_return = Some(self.calculate_return(self.avg_px_open, avg_px_close));
+            realized_pnl += self.calculate_pnl(self.avg_px_open, last_px, last_qty);
+        }
+
+        self.add_realized_pnl(realized_pnl);
+        self.sell_qty.raw += fill.last_qty.raw;
+        self.signed_qty = self.round_qty(self.signed_qty - last_qty);
+    }
+
+    fn add_realized_pnl(&mut self, realized_pnl: f64) {
+        let total = self.realized_pnl.as_ref().map_or(0.0, Money::as_f64) + realized_pnl;
+        self.realized_pnl = Some(Money::new(total, self.cost_currency.clone()));
+    }
+
+    fn round_qty(&self, qty: f64) -> f64 {
+        let factor = 10_f64.powi(i32::from(self.size_precision));
+        (qty * factor).round() / factor
+    }
+
+    fn calculate_avg_px_open_px(&self, last_px: f64, last_qty: f64) -> f64 {
+        self.calculate_avg_px(self.quantity.as_f64(), self.avg_px_open, last_px, last_qty)
+    }
+
+    fn calculate_avg_px_close_px(&self, last_px: f64, last_qty: f64) -> f64 {
+        match self.avg_px_close {
+            Some(avg_px_close) => {
+                let close_qty = if self.side == PositionSide::Long {
+                    self.sell_qty
+                } else {
+                    self.buy_qty
+                };
+                self.calculate_avg_px(close_qty.as_f64(), avg_px_close, last_px, last_qty)
+            }
+            None => last_px,
+        }
+    }
+
+    fn calculate_avg_px(&self, qty: f64, avg_px: f64, last_px: f64, last_qty: f64) -> f64 {
+        let start_cost = avg_px * qty;
+        let event_cost = last_px * last_qty;
+        (start_cost + event_cost) / (qty + last_qty)
+    }
+
+    fn calculate_points(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+        match self.side {
+            PositionSide::Long => avg_px_close - avg_px_open,
+            PositionSide::Short => avg_px_open - avg_px_close,
+            _ => 0.0, // FLAT
+        }
+    }
+
+    fn calculate_points_inverse(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+        match self.side {
+            PositionSide::Long => (1.0 / avg_px_open) - (1.0 / avg_px_close),
+            PositionSide::Short => (1.0 / avg_px_close) - (1.0 / avg_px_open),
+            _ => 0.0, // FLAT
+        }
+    }
+
+    fn calculate_return(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+        self.calculate_points(avg_px_open, avg_px_close) / avg_px_open
+    }
+
+    fn calculate_pnl(&self, avg_px_open: f64, avg_px_close: f64, quantity: f64) -> f64 {
+        // Only book open quantity towards PnL
+        let quantity = quantity.min(self.signed_qty.abs());
+
+        if self.is_inverse {
+            // In base currency
+            quantity
+                * self.multiplier.as_f64()
+                * self.calculate_points_inverse(avg_px_open, avg_px_close)
+        } else {
+            // In quote currency
+            quantity * self.multiplier.as_f64() * self.calculate_points(avg_px_open, avg_px_close)
+        }
     }
 }
 
@@ -200,3 +465,382 @@ impl Eq for Position {}
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_core::uuid::UUID4;
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::{
+        enums::{AssetClass, AssetType, LiquiditySide, OrderType},
+        identifiers::symbol::Symbol,
+    };
+
+    fn audusd_sim() -> Instrument {
+        Instrument {
+            id: InstrumentId::from_str("AUD/USD.SIM").unwrap(),
+            native_symbol: Symbol::new("AUD/USD"),
+            asset_class: AssetClass::FX,
+            asset_type: AssetType::Spot,
+            quote_currency: Currency::from("USD"),
+            base_currency: Some(Currency::from("AUD")),
+            cost_currency: Currency::from("USD"),
+            is_inverse: false,
+            price_precision: 5,
+            size_precision: 0,
+            price_increment: Price::from("0.00001"),
+            size_increment: Quantity::from("1"),
+            multiplier: Quantity::from("1"),
+            lot_size: None,
+            max_quantity: None,
+            min_quantity: None,
+            max_price: None,
+            min_price: None,
+            margin_init: Decimal::ZERO,
+            margin_maint: Decimal::ZERO,
+            maker_fee: Decimal::ZERO,
+            taker_fee: Decimal::ZERO,
+        }
+    }
+
+    fn xbtusd_bitmex() -> Instrument {
+        Instrument {
+            id: InstrumentId::from_str("BTC/USD.BITMEX").unwrap(),
+            native_symbol: Symbol::new("XBTUSD"),
+            asset_class: AssetClass::Cryptocurrency,
+            asset_type: AssetType::Swap,
+            quote_currency: Currency::from("USD"),
+            base_currency: Some(Currency::from("BTC")),
+            cost_currency: Currency::from("BTC"),
+            is_inverse: true,
+            price_precision: 1,
+            size_precision: 0,
+            price_increment: Price::from("0.5"),
+            size_increment: Quantity::from("1"),
+            multiplier: Quantity::from("1"),
+            lot_size: None,
+            max_quantity: None,
+            min_quantity: None,
+            max_price: None,
+            min_price: None,
+            margin_init: Decimal::ZERO,
+            margin_maint: Decimal::ZERO,
+            maker_fee: Decimal::ZERO,
+            taker_fee: Decimal::ZERO,
+        }
+    }
+
+    fn fill(
+        instrument: &Instrument,
+        trade_id: &str,
+        side: OrderSide,
+        qty: &str,
+        px: &str,
+        commission: Money,
+        ts: UnixNanos,
+    ) -> OrderFilled {
+        OrderFilled {
+            trader_id: TraderId::new("TRADER-001"),
+            strategy_id: StrategyId::new("S-001"),
+            instrument_id: instrument.id.clone(),
+            client_order_id: ClientOrderId::new(&format!("O-{trade_id}")),
+            venue_order_id: VenueOrderId::new(&format!("V-{trade_id}")),
+            account_id: AccountId::new("SIM-001"),
+            trade_id: TradeId::new(trade_id),
+            position_id: Some(PositionId::new("P-001")),
+            order_side: side,
+            order_type: OrderType::Market,
+            last_qty: Quantity::from(qty),
+            last_px: Price::from(px),
+            currency: instrument.quote_currency.clone(),
+            commission,
+            liquidity_side: LiquiditySide::Taker,
+            event_id: UUID4::new(),
+            ts_event: ts,
+            ts_init: ts,
+            reconciliation: false,
+        }
+    }
+
+    fn usd(amount: f64) -> Money {
+        Money::new(amount, Currency::from("USD"))
+    }
+
+    #[test]
+    fn test_position_opened_long() {
+        let instrument = audusd_sim();
+        let fill = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "1.00001",
+            usd(2.0),
+            1,
+        );
+
+        let position = Position::new(&instrument, &fill);
+
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.entry, OrderSide::Buy);
+        assert_eq!(position.signed_qty, 100_000.0);
+        assert_eq!(position.quantity, Quantity::from("100000"));
+        assert_eq!(position.peak_qty, Quantity::from("100000"));
+        assert_eq!(position.avg_px_open, 1.00001);
+        assert_eq!(position.avg_px_close, None);
+        assert_eq!(position.realized_pnl, Some(usd(-2.0)));
+        assert_eq!(position.commissions(), vec![usd(2.0)]);
+        assert_eq!(position.buy_qty(), Quantity::from("100000"));
+        assert_eq!(position.event_count(), 1);
+        assert!(position.is_open());
+        assert!(position.is_long());
+        assert_eq!(position.unrealized_pnl(&Price::from("1.00051")), usd(50.0));
+        assert_eq!(
+            position.notional_value(&Price::from("1.00000")),
+            usd(100_000.0)
+        );
+    }
+
+    #[test]
+    fn test_position_filled_with_short_then_partially_closed() {
+        let instrument = audusd_sim();
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Sell,
+            "100000",
+            "1.00000",
+            usd(2.0),
+            1,
+        );
+        let fill2 = fill(
+            &instrument,
+            "2",
+            OrderSide::Buy,
+            "50000",
+            "0.99990",
+            usd(1.0),
+            2,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        let event = position.apply(fill2);
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.signed_qty, -50_000.0);
+        assert_eq!(position.avg_px_open, 1.0);
+        assert_eq!(position.avg_px_close, Some(0.9999));
+        // 50_000 * 0.0001 - 2.0 - 1.0
+        assert_eq!(position.realized_pnl, Some(usd(2.0)));
+        assert_eq!(position.commissions(), vec![usd(3.0)]);
+        match event {
+            PositionEvent::PositionChanged(changed) => {
+                assert_eq!(changed.signed_qty, -50_000.0);
+                assert_eq!(changed.peak_quantity, Quantity::from("100000"));
+                assert_eq!(changed.realized_pnl, usd(2.0));
+            }
+            _ => panic!("expected `PositionChanged`"),
+        }
+    }
+
+    #[test]
+    fn test_position_opened_then_closed() {
+        let instrument = audusd_sim();
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "1.00000",
+            usd(0.0),
+            1,
+        );
+        let fill2 = fill(
+            &instrument,
+            "2",
+            OrderSide::Sell,
+            "100000",
+            "1.00010",
+            usd(0.0),
+            5,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        let event = position.apply(fill2);
+
+        assert_eq!(position.side, PositionSide::Flat);
+        assert!(position.is_closed());
+        assert_eq!(position.quantity, Quantity::from("0"));
+        assert_eq!(position.avg_px_close, Some(1.0001));
+        assert_eq!(position.realized_pnl, Some(usd(10.0)));
+        assert_eq!(position.ts_closed, Some(5));
+        assert_eq!(position.duration_ns, Some(4));
+        assert_eq!(position.closing_order_id, Some(ClientOrderId::new("O-2")));
+        assert_eq!(position.unrealized_pnl(&Price::from("1.00020")), usd(0.0));
+        match event {
+            PositionEvent::PositionClosed(closed) => {
+                assert_eq!(closed.realized_pnl, usd(10.0));
+                assert_eq!(closed.duration, 4);
+                assert_eq!(closed.ts_closed, 5);
+            }
+            _ => panic!("expected `PositionClosed`"),
+        }
+    }
+
+    #[test]
+    fn test_position_averages_open_price_when_adding() {
+        let instrument = audusd_sim();
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "1.00000",
+            usd(0.0),
+            1,
+        );
+        let fill2 = fill(
+            &instrument,
+            "2",
+            OrderSide::Buy,
+            "100000",
+            "1.00010",
+            usd(0.0),
+            2,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        position.apply(fill2);
+
+        assert_eq!(position.quantity, Quantity::from("200000"));
+        assert!((position.avg_px_open - 1.00005).abs() < 1e-9);
+        assert_eq!(position.realized_pnl, Some(usd(0.0)));
+    }
+
+    #[test]
+    fn test_position_reopened_after_flat_resets_state() {
+        let instrument = audusd_sim();
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "1.00000",
+            usd(0.0),
+            1,
+        );
+        let fill2 = fill(
+            &instrument,
+            "2",
+            OrderSide::Sell,
+            "100000",
+            "1.00010",
+            usd(0.0),
+            2,
+        );
+        let fill3 = fill(
+            &instrument,
+            "3",
+            OrderSide::Sell,
+            "50000",
+            "1.00020",
+            usd(0.0),
+            3,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        position.apply(fill2);
+        let event = position.apply(fill3);
+
+        assert!(matches!(event, PositionEvent::PositionOpened(_)));
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.avg_px_open, 1.0002);
+        assert_eq!(position.realized_pnl, Some(usd(0.0)));
+        assert_eq!(position.ts_closed, None);
+        assert_eq!(position.event_count(), 1);
+    }
+
+    #[test]
+    fn test_inverse_position_pnl_in_base_currency() {
+        let instrument = xbtusd_bitmex();
+        let btc = |amount| Money::new(amount, Currency::from("BTC"));
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "10000.0",
+            btc(0.0),
+            1,
+        );
+        let fill2 = fill(
+            &instrument,
+            "2",
+            OrderSide::Sell,
+            "100000",
+            "12500.0",
+            btc(0.0),
+            2,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        assert_eq!(position.notional_value(&Price::from("10000.0")), btc(10.0));
+        assert_eq!(position.unrealized_pnl(&Price::from("12500.0")), btc(2.0));
+
+        position.apply(fill2);
+
+        // 100_000 * (1 / 10_000 - 1 / 12_500)
+        assert_eq!(position.realized_pnl, Some(btc(2.0)));
+    }
+
+    #[test]
+    fn test_inverse_position_unrealized_pnl_when_flat() {
+        let instrument = xbtusd_bitmex();
+        let btc = |amount| Money::new(amount, Currency::from("BTC"));
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "10000.0",
+            btc(0.0),
+            1,
+        );
+        let fill2 = fill(
+            &instrument,
+            "2",
+            OrderSide::Sell,
+            "100000",
+            "12500.0",
+            btc(0.0),
+            2,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        position.apply(fill2);
+
+        assert_eq!(position.side, PositionSide::Flat);
+        assert_eq!(position.unrealized_pnl(&Price::from("12500.0")), btc(0.0));
+        assert_eq!(position.total_pnl(&Price::from("12500.0")), btc(2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_duplicate_trade_id_panics() {
+        let instrument = audusd_sim();
+        let fill1 = fill(
+            &instrument,
+            "1",
+            OrderSide::Buy,
+            "100000",
+            "1.00000",
+            usd(0.0),
+            1,
+        );
+
+        let mut position = Position::new(&instrument, &fill1);
+        position.apply(fill1);
+    }
+}
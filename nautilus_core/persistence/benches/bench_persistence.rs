@@ -40,7 +40,7 @@ fn single_stream_bench(c: &mut Criterion) {
             |query_result: QueryResult| {
                 let rt = get_runtime();
                 let _guard = rt.enter();
-                let count: usize = query_result.map(|vec| vec.len()).sum();
+                let count: usize = query_result.map(|chunk| chunk.unwrap().len()).sum();
                 assert_eq!(count, 9_689_614);
             },
             BatchSize::SmallInput,
@@ -90,7 +90,7 @@ fn multi_stream_bench(c: &mut Criterion) {
             |query_result: QueryResult| {
                 let rt = get_runtime();
                 let _guard = rt.enter();
-                let count: usize = query_result.map(|vec| vec.len()).sum();
+                let count: usize = query_result.map(|chunk| chunk.unwrap().len()).sum();
                 assert_eq!(count, 72_536_038);
             },
             BatchSize::SmallInput,
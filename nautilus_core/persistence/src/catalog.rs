@@ -0,0 +1,392 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::time::UnixNanos;
+
+use crate::{parquet::ParquetType, query::DataQuery};
+
+/// The characters replaced in partition keys, as they are invalid in Windows paths.
+const INVALID_WINDOWS_CHARS: &str = r#"<>:"/\|?* "#;
+
+/// Represents a parquet file discovered in a catalog directory.
+///
+/// The catalog layout matches the Python `ParquetDataCatalog`:
+/// `{root}/data/{type}.parquet/instrument_id={key}/{start}-{end}-{n}.parquet`, where
+/// `start` and `end` are the zero padded `ts_init` range of the records in the file.
+/// Bars are partitioned by `bar_type={key}` instead of `instrument_id={key}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogFile {
+    pub path: PathBuf,
+    pub data_type: CatalogDataType,
+    pub instrument_key: Option<String>,
+    pub bar_type_key: Option<String>,
+    pub time_range: Option<(UnixNanos, UnixNanos)>,
+}
+
+/// Represents the type of the records in a catalog file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CatalogDataType {
+    /// A built-in data type, decoded by its [`ParquetType`].
+    Data(ParquetType),
+    /// A custom data type, decoded by the type registered under the name.
+    Custom(String),
+}
+
+impl CatalogFile {
+    /// Returns whether the file may contain records matching the `query`, based on
+    /// its instrument or bar type partition and file name.
+    ///
+    /// Files without a partition or a parseable time range are always matched, as
+    /// their records are filtered when queried.
+    #[must_use]
+    pub fn matches_query(&self, query: &DataQuery) -> bool {
+        if let Some(instrument_ids) = &query.instrument_ids {
+            let keys: Vec<String> = instrument_ids
+                .iter()
+                .map(|instrument_id| clean_key(&instrument_id.to_string()))
+                .collect();
+            if let Some(instrument_key) = &self.instrument_key {
+                if !keys.contains(instrument_key) {
+                    return false;
+                }
+            }
+            // A bar type starts with its instrument ID, followed by the bar specification
+            if let Some(bar_type_key) = &self.bar_type_key {
+                if !keys.iter().any(|key| {
+                    bar_type_key
+                        .strip_prefix(key.as_str())
+                        .is_some_and(|spec| spec.starts_with('-'))
+                }) {
+                    return false;
+                }
+            }
+        }
+        match self.time_range {
+            Some((start, end)) => {
+                query.start.map_or(true, |query_start| end >= query_start)
+                    && query.end.map_or(true, |query_end| start <= query_end)
+            }
+            None => true,
+        }
+    }
+}
+
+/// Returns the name of the catalog directory for the `parquet_type`.
+#[must_use]
+pub fn type_dir_name(parquet_type: ParquetType) -> &'static str {
+    match parquet_type {
+        ParquetType::OrderBookDelta => "order_book_delta.parquet",
+        ParquetType::QuoteTick => "quote_tick.parquet",
+        ParquetType::TradeTick => "trade_tick.parquet",
+        ParquetType::Bar => "bar.parquet",
+    }
+}
+
+/// Returns the [`ParquetType`] for the catalog directory `name`, if supported.
+#[must_use]
+pub fn parquet_type_from_dir_name(name: &str) -> Option<ParquetType> {
+    [
+        ParquetType::OrderBookDelta,
+        ParquetType::QuoteTick,
+        ParquetType::TradeTick,
+        ParquetType::Bar,
+    ]
+    .into_iter()
+    .find(|parquet_type| type_dir_name(*parquet_type) == name)
+}
+
+/// Returns the partition key for the `value`, with characters invalid in Windows
+/// paths replaced by `-` as in the Python catalog.
+#[must_use]
+pub fn clean_key(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if INVALID_WINDOWS_CHARS.contains(c) {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Returns the `ts_init` range encoded in a catalog file name, if any.
+#[must_use]
+pub fn parse_file_time_range(file_name: &str) -> Option<(UnixNanos, UnixNanos)> {
+    let mut parts = file_name.split('-');
+    let start = parts.next()?;
+    let end = parts.next()?;
+    if start.len() != 19 || end.len() != 19 {
+        return None;
+    }
+    Some((start.parse().ok()?, end.parse().ok()?))
+}
+
+/// Discovers the parquet files for each built-in data type, and each of the
+/// `custom_types` in a `{type_name}.parquet` directory, in the catalog at `root`,
+/// sorted by path.
+///
+/// # Errors
+///
+/// - If a directory in the catalog cannot be read.
+/// - If a directory is partitioned on a column other than `instrument_id` or
+///   `bar_type`.
+pub fn discover_files(root: &Path, custom_types: &[&str]) -> io::Result<Vec<CatalogFile>> {
+    let data_dir = root.join("data");
+    let mut files = Vec::new();
+    if !data_dir.is_dir() {
+        return Ok(files);
+    }
+
+    for entry in fs::read_dir(data_dir)? {
+        let path = entry?.path();
+        let data_type = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| data_type_from_dir_name(name, custom_types));
+        if let (Some(data_type), true) = (data_type, path.is_dir()) {
+            collect_files(&path, &data_type, Partition::default(), &mut files)?;
+        }
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn data_type_from_dir_name(name: &str, custom_types: &[&str]) -> Option<CatalogDataType> {
+    if let Some(parquet_type) = parquet_type_from_dir_name(name) {
+        return Some(CatalogDataType::Data(parquet_type));
+    }
+    name.strip_suffix(".parquet")
+        .filter(|type_name| custom_types.contains(type_name))
+        .map(|type_name| CatalogDataType::Custom(type_name.to_string()))
+}
+
+#[derive(Clone, Copy, Default)]
+struct Partition<'a> {
+    instrument_key: Option<&'a str>,
+    bar_type_key: Option<&'a str>,
+}
+
+fn collect_files(
+    dir: &Path,
+    data_type: &CatalogDataType,
+    partition: Partition<'_>,
+    files: &mut Vec<CatalogFile>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        if path.is_dir() {
+            // Hive partition directories are named `{column}={value}`, a directory
+            // partitioned on any other column is not supported
+            let partition = match name.split_once('=') {
+                Some(("instrument_id", key)) => Partition {
+                    instrument_key: Some(key),
+                    ..partition
+                },
+                Some(("bar_type", key)) => Partition {
+                    bar_type_key: Some(key),
+                    ..partition
+                },
+                Some((column, _)) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "Unsupported partition column '{column}' in {}",
+                            path.display()
+                        ),
+                    ))
+                }
+                None => partition,
+            };
+            collect_files(&path, data_type, partition, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "parquet") {
+            let time_range = parse_file_time_range(name);
+            files.push(CatalogFile {
+                path,
+                data_type: data_type.clone(),
+                instrument_key: partition.instrument_key.map(ToString::to_string),
+                bar_type_key: partition.bar_type_key.map(ToString::to_string),
+                time_range,
+            });
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::identifiers::instrument_id::InstrumentId;
+
+    use super::*;
+
+    fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::File::create(path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_file_time_range() {
+        assert_eq!(
+            parse_file_time_range("1577836800000000000-1577923199000000000-0.parquet"),
+            Some((1_577_836_800_000_000_000, 1_577_923_199_000_000_000))
+        );
+        assert_eq!(parse_file_time_range("part-0.parquet"), None);
+        assert_eq!(parse_file_time_range("data.parquet"), None);
+    }
+
+    #[test]
+    fn test_clean_key() {
+        assert_eq!(clean_key("EUR/USD.SIM"), "EUR-USD.SIM");
+    }
+
+    #[test]
+    fn test_discover_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        let quotes = root.join("data/quote_tick.parquet/instrument_id=EUR-USD.SIM");
+        touch(&quotes.join("0000000000000000001-0000000000000000002-0.parquet"));
+        touch(&quotes.join("0000000000000000003-0000000000000000004-0.parquet"));
+        touch(&root.join("data/trade_tick.parquet/instrument_id=USD-JPY.SIM/part-0.parquet"));
+        touch(&root.join("data/currency_pair.parquet/0.parquet"));
+        touch(&quotes.join("_common_metadata"));
+
+        let files = discover_files(root, &[]).unwrap();
+
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0].time_range, Some((1, 2)));
+        assert_eq!(files[1].time_range, Some((3, 4)));
+        assert_eq!(
+            files[0].data_type,
+            CatalogDataType::Data(ParquetType::QuoteTick)
+        );
+        assert_eq!(files[0].instrument_key.as_deref(), Some("EUR-USD.SIM"));
+        assert_eq!(
+            files[2].data_type,
+            CatalogDataType::Data(ParquetType::TradeTick)
+        );
+        assert_eq!(files[2].time_range, None);
+    }
+
+    #[test]
+    fn test_discover_files_with_bar_type_partition() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        touch(
+            &root
+                .join("data/bar.parquet/bar_type=EUR-USD.SIM-1-MINUTE-BID-EXTERNAL/part-0.parquet"),
+        );
+
+        let files = discover_files(root, &[]).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].data_type, CatalogDataType::Data(ParquetType::Bar));
+        assert_eq!(files[0].instrument_key, None);
+        assert_eq!(
+            files[0].bar_type_key.as_deref(),
+            Some("EUR-USD.SIM-1-MINUTE-BID-EXTERNAL")
+        );
+    }
+
+    #[test]
+    fn test_discover_files_with_unsupported_partition() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        touch(&root.join("data/quote_tick.parquet/venue=SIM/part-0.parquet"));
+
+        let err = discover_files(root, &[]).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_discover_files_with_custom_types() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        touch(&root.join("data/funding_rate.parquet/instrument_id=BTCUSDT.BINANCE/part-0.parquet"));
+        touch(&root.join("data/signal.parquet/part-0.parquet"));
+
+        let files = discover_files(root, &["funding_rate"]).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0].data_type,
+            CatalogDataType::Custom("funding_rate".to_string())
+        );
+        assert_eq!(files[0].instrument_key.as_deref(), Some("BTCUSDT.BINANCE"));
+    }
+
+    #[test]
+    fn test_discover_files_without_data_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        assert!(discover_files(temp_dir.path(), &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_matches_query() {
+        let file = CatalogFile {
+            path: PathBuf::from("0000000000000000010-0000000000000000020-0.parquet"),
+            data_type: CatalogDataType::Data(ParquetType::QuoteTick),
+            instrument_key: Some("EUR-USD.SIM".to_string()),
+            bar_type_key: None,
+            time_range: Some((10, 20)),
+        };
+        let eurusd = InstrumentId::from_str("EUR/USD.SIM").unwrap();
+        let usdjpy = InstrumentId::from_str("USD/JPY.SIM").unwrap();
+
+        assert!(file.matches_query(&DataQuery::new()));
+        assert!(file.matches_query(&DataQuery::new().start(20).end(30)));
+        assert!(file.matches_query(&DataQuery::new().end(10)));
+        assert!(!file.matches_query(&DataQuery::new().start(21)));
+        assert!(!file.matches_query(&DataQuery::new().end(9)));
+        assert!(file.matches_query(&DataQuery::new().instrument_ids(vec![eurusd])));
+        assert!(!file.matches_query(&DataQuery::new().instrument_ids(vec![usdjpy])));
+    }
+
+    #[test]
+    fn test_matches_query_with_bar_type_partition() {
+        let file = CatalogFile {
+            path: PathBuf::from("part-0.parquet"),
+            data_type: CatalogDataType::Data(ParquetType::Bar),
+            instrument_key: None,
+            bar_type_key: Some("EUR-USD.SIM-1-MINUTE-BID-EXTERNAL".to_string()),
+            time_range: None,
+        };
+        let query = |instrument_id: &str| {
+            DataQuery::new().instrument_ids(vec![InstrumentId::from_str(instrument_id).unwrap()])
+        };
+
+        assert!(file.matches_query(&query("EUR/USD.SIM")));
+        assert!(!file.matches_query(&query("USD/JPY.SIM")));
+        assert!(!file.matches_query(&query("EUR/USD.SIMX")));
+    }
+}
@@ -0,0 +1,146 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{any::Any, collections::HashMap, fmt::Debug, sync::Arc};
+
+use datafusion::arrow::{datatypes::Schema, record_batch::RecordBatch};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::data::Data;
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::{error::PersistenceError, parquet::DecodeCustomDataFromRecordBatch};
+
+/// Represents a record of a user-defined data type, such as funding rates or
+/// signals, which can be queried and merged with the built-in [`Data`] types.
+pub trait CustomData: Any + Debug {
+    /// Returns the name the data type is registered under.
+    fn type_name(&self) -> &'static str;
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    fn ts_event(&self) -> UnixNanos;
+    /// Returns the UNIX timestamp (nanoseconds) when the data was initialized.
+    fn ts_init(&self) -> UnixNanos;
+    /// Returns the data as [`Any`], to downcast it to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+    /// Returns the data as a Python object.
+    ///
+    /// The default is a `dict` of the type name and timestamps, which types
+    /// should extend with their own fields.
+    fn to_py(&self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("type", self.type_name()).unwrap();
+        dict.set_item("ts_event", self.ts_event()).unwrap();
+        dict.set_item("ts_init", self.ts_init()).unwrap();
+        dict.into_py(py)
+    }
+}
+
+/// Represents a record returned by a query which merges built-in and custom data.
+#[derive(Clone, Debug)]
+pub enum QueryData {
+    Data(Data),
+    Custom(Arc<dyn CustomData>),
+}
+
+impl QueryData {
+    #[must_use]
+    pub fn get_ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Data(data) => data.get_ts_init(),
+            Self::Custom(custom) => custom.ts_init(),
+        }
+    }
+}
+
+impl From<Data> for QueryData {
+    fn from(value: Data) -> Self {
+        Self::Data(value)
+    }
+}
+
+/// Decodes a record batch into custom data of a registered type.
+pub type CustomDataDecoder = fn(
+    metadata: &HashMap<String, String>,
+    record_batch: RecordBatch,
+) -> Result<Vec<Arc<dyn CustomData>>, PersistenceError>;
+
+/// Validates that custom data of a registered type can be decoded from a file
+/// with the schema.
+pub type CustomSchemaValidator = fn(schema: &Schema) -> Result<(), PersistenceError>;
+
+/// Represents the decoding functions of a registered custom data type.
+#[derive(Clone, Copy)]
+pub struct CustomDataType {
+    pub decode: CustomDataDecoder,
+    pub validate: CustomSchemaValidator,
+}
+
+/// Provides a registry of user-defined data types and their decoders, keyed by
+/// type name.
+#[derive(Clone, Default)]
+pub struct CustomDataRegistry {
+    types: HashMap<&'static str, CustomDataType>,
+}
+
+impl CustomDataRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the data type `T` under its type name, replacing any type
+    /// previously registered under the same name.
+    pub fn register<T>(&mut self)
+    where
+        T: DecodeCustomDataFromRecordBatch,
+    {
+        self.types.insert(
+            T::TYPE_NAME,
+            CustomDataType {
+                decode: decode_custom::<T>,
+                validate: T::validate_schema,
+            },
+        );
+    }
+
+    /// Returns whether a data type is registered under `type_name`.
+    #[must_use]
+    pub fn contains(&self, type_name: &str) -> bool {
+        self.types.contains_key(type_name)
+    }
+
+    /// Returns the names of the registered data types.
+    pub fn type_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.types.keys().copied()
+    }
+
+    /// Returns the data type registered under `type_name`, if any.
+    #[must_use]
+    pub fn get(&self, type_name: &str) -> Option<CustomDataType> {
+        self.types.get(type_name).copied()
+    }
+}
+
+fn decode_custom<T>(
+    metadata: &HashMap<String, String>,
+    record_batch: RecordBatch,
+) -> Result<Vec<Arc<dyn CustomData>>, PersistenceError>
+where
+    T: DecodeCustomDataFromRecordBatch,
+{
+    Ok(T::decode_batch(metadata, record_batch)?
+        .into_iter()
+        .map(|value| Arc::new(value) as Arc<dyn CustomData>)
+        .collect())
+}
@@ -0,0 +1,67 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::io;
+
+use datafusion::{arrow::datatypes::DataType, error::DataFusionError};
+use pyo3::{
+    exceptions::{PyIOError, PyRuntimeError, PyValueError},
+    PyErr,
+};
+use thiserror::Error;
+
+use crate::validation::ValidationIssue;
+
+#[derive(Debug, Error)]
+pub enum PersistenceError {
+    #[error("Missing metadata key '{0}'")]
+    MissingMetadata(String),
+    #[error("Invalid metadata value for '{key}', was '{value}'")]
+    InvalidMetadata { key: String, value: String },
+    #[error("Missing column '{0}'")]
+    MissingColumn(String),
+    #[error("Invalid type for column '{column}', expected {expected} but was {actual}")]
+    InvalidColumnType {
+        column: String,
+        expected: DataType,
+        actual: DataType,
+    },
+    #[error("Column '{column}' contains {count} null value(s)")]
+    NullValues { column: String, count: usize },
+    #[error("Invalid value in column '{column}', was {value}")]
+    InvalidValue { column: String, value: String },
+    #[error("Custom data type '{0}' is not registered")]
+    UnregisteredType(String),
+    #[error("{0} custom data query(s) can only be returned by a merged query result")]
+    UnmergedCustomData(usize),
+    #[error("Data validation failed: {0}")]
+    Validation(ValidationIssue),
+    #[error("DataFusion error: {0}")]
+    DataFusion(#[from] DataFusionError),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Converts the error to a Python exception: `ValueError` for invalid data,
+/// `OSError` for IO errors and `RuntimeError` for query errors.
+impl From<PersistenceError> for PyErr {
+    fn from(err: PersistenceError) -> Self {
+        match err {
+            PersistenceError::Io(_) => PyIOError::new_err(err.to_string()),
+            PersistenceError::DataFusion(_) => PyRuntimeError::new_err(err.to_string()),
+            _ => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
@@ -13,13 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod catalog;
+pub mod custom;
+pub mod error;
 mod kmerge_batch;
 pub mod parquet;
+pub mod query;
 pub mod session;
+pub mod validation;
 
 use parquet::ParquetType;
 use pyo3::prelude::*;
-use session::{DataBackendSession, DataQueryResult};
+use session::{DataBackendSession, DataQueryResult, MergedQueryResult};
+use validation::ValidationMode;
 
 /// Loaded as nautilus_pyo3.persistence
 #[pymodule]
@@ -27,5 +33,7 @@ pub fn persistence(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<ParquetType>()?;
     m.add_class::<DataBackendSession>()?;
     m.add_class::<DataQueryResult>()?;
+    m.add_class::<MergedQueryResult>()?;
+    m.add_class::<ValidationMode>()?;
     Ok(())
 }
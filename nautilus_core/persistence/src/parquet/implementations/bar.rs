@@ -13,11 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Array, Int64Array, UInt64Array},
+    array::{Int64Array, UInt64Array},
     datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use nautilus_model::{
@@ -25,49 +26,60 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::{
+    error::PersistenceError,
+    parquet::{
+        get_column, parse_bar_type, parse_metadata_value, parse_metadata_with, Data,
+        DecodeDataFromRecordBatch, EncodeToRecordBatch,
+    },
+};
 
 impl DecodeDataFromRecordBatch for Bar {
-    fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, PersistenceError> {
         // Parse and validate metadata
-        let (bar_type, price_precision, size_precision) = parse_metadata(metadata);
+        let (bar_type, price_precision, size_precision) = parse_metadata(metadata)?;
 
         // Extract field value arrays from record batch
-        let cols = record_batch.columns();
-        let open_values = cols[0].as_any().downcast_ref::<Int64Array>().unwrap();
-        let high_values = cols[1].as_any().downcast_ref::<Int64Array>().unwrap();
-        let low_values = cols[2].as_any().downcast_ref::<Int64Array>().unwrap();
-        let close_values = cols[3].as_any().downcast_ref::<Int64Array>().unwrap();
-        let volume_values = cols[4].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_event_values = cols[5].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_init_values = cols[6].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let open_values = get_column::<Int64Array>(&record_batch, "open", &DataType::Int64)?;
+        let high_values = get_column::<Int64Array>(&record_batch, "high", &DataType::Int64)?;
+        let low_values = get_column::<Int64Array>(&record_batch, "low", &DataType::Int64)?;
+        let close_values = get_column::<Int64Array>(&record_batch, "close", &DataType::Int64)?;
+        let volume_values = get_column::<UInt64Array>(&record_batch, "volume", &DataType::UInt64)?;
+        let ts_event_values =
+            get_column::<UInt64Array>(&record_batch, "ts_event", &DataType::UInt64)?;
+        let ts_init_values =
+            get_column::<UInt64Array>(&record_batch, "ts_init", &DataType::UInt64)?;
 
         // Construct iterator of values from field value arrays
         let values = open_values
-            .into_iter()
-            .zip(high_values.iter())
-            .zip(low_values.iter())
-            .zip(close_values.iter())
-            .zip(volume_values.iter())
-            .zip(ts_event_values.iter())
-            .zip(ts_init_values.iter())
+            .values()
+            .iter()
+            .zip(high_values.values().iter())
+            .zip(low_values.values().iter())
+            .zip(close_values.values().iter())
+            .zip(volume_values.values().iter())
+            .zip(ts_event_values.values().iter())
+            .zip(ts_init_values.values().iter())
             .map(
-                |((((((open, high), low), close), volume), ts_event), ts_init)| {
+                |((((((&open, &high), &low), &close), &volume), &ts_event), &ts_init)| {
                     Self {
                         bar_type: bar_type.clone(),
-                        open: Price::from_raw(open.unwrap(), price_precision),
-                        high: Price::from_raw(high.unwrap(), price_precision),
-                        low: Price::from_raw(low.unwrap(), price_precision),
-                        close: Price::from_raw(close.unwrap(), price_precision),
-                        volume: Quantity::from_raw(volume.unwrap(), size_precision),
-                        ts_event: ts_event.unwrap(),
-                        ts_init: ts_init.unwrap(),
+                        open: Price::from_raw(open, price_precision),
+                        high: Price::from_raw(high, price_precision),
+                        low: Price::from_raw(low, price_precision),
+                        close: Price::from_raw(close, price_precision),
+                        volume: Quantity::from_raw(volume, size_precision),
+                        ts_event,
+                        ts_init,
                     }
                     .into()
                 },
             );
 
-        values.collect()
+        Ok(values.collect())
     }
 
     fn get_schema(metadata: std::collections::HashMap<String, String>) -> SchemaRef {
@@ -85,20 +97,43 @@ impl DecodeDataFromRecordBatch for Bar {
     }
 }
 
-fn parse_metadata(metadata: &HashMap<String, String>) -> (BarType, u8, u8) {
-    let bar_type = BarType::from_str(metadata.get("bar_type").unwrap().as_str()).unwrap();
-    let price_precision = metadata
-        .get("price_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
-    let size_precision = metadata
-        .get("size_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
+impl EncodeToRecordBatch for Bar {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        // Encode field value arrays in schema order
+        let open = Int64Array::from_iter_values(data.iter().map(|b| b.open.raw));
+        let high = Int64Array::from_iter_values(data.iter().map(|b| b.high.raw));
+        let low = Int64Array::from_iter_values(data.iter().map(|b| b.low.raw));
+        let close = Int64Array::from_iter_values(data.iter().map(|b| b.close.raw));
+        let volume = UInt64Array::from_iter_values(data.iter().map(|b| b.volume.raw));
+        let ts_event = UInt64Array::from_iter_values(data.iter().map(|b| b.ts_event));
+        let ts_init = UInt64Array::from_iter_values(data.iter().map(|b| b.ts_init));
 
-    (bar_type, price_precision, size_precision)
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(open),
+                Arc::new(high),
+                Arc::new(low),
+                Arc::new(close),
+                Arc::new(volume),
+                Arc::new(ts_event),
+                Arc::new(ts_init),
+            ],
+        )
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(BarType, u8, u8), PersistenceError> {
+    let bar_type = parse_metadata_with(metadata, "bar_type", parse_bar_type)?;
+    let price_precision = parse_metadata_value::<u8>(metadata, "price_precision")?;
+    let size_precision = parse_metadata_value::<u8>(metadata, "size_precision")?;
+
+    Ok((bar_type, price_precision, size_precision))
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -166,7 +201,64 @@ mod tests {
         )
         .unwrap();
 
-        let decoded_data = Bar::decode_batch(&metadata, record_batch);
+        let decoded_data = Bar::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[test]
+    fn test_encode_batch_round_trip() {
+        let metadata = create_metadata();
+        let (bar_type, price_precision, size_precision) = parse_metadata(&metadata).unwrap();
+        let bars = vec![
+            Bar {
+                bar_type: bar_type.clone(),
+                open: Price::from_raw(10010, price_precision),
+                high: Price::from_raw(10200, price_precision),
+                low: Price::from_raw(10000, price_precision),
+                close: Price::from_raw(10100, price_precision),
+                volume: Quantity::from_raw(110, size_precision),
+                ts_event: 1,
+                ts_init: 3,
+            },
+            Bar {
+                bar_type,
+                open: Price::from_raw(10000, price_precision),
+                high: Price::from_raw(10000, price_precision),
+                low: Price::from_raw(10000, price_precision),
+                close: Price::from_raw(10010, price_precision),
+                volume: Quantity::from_raw(100, size_precision),
+                ts_event: 2,
+                ts_init: 4,
+            },
+        ];
+
+        let record_batch = Bar::encode_batch(&metadata, &bars).unwrap();
+        assert_eq!(record_batch.schema(), Bar::get_schema(metadata.clone()));
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded: Vec<Bar> = Bar::decode_batch(&metadata, record_batch)
+            .unwrap()
+            .into_iter()
+            .map(|data| match data {
+                Data::Bar(bar) => bar,
+                _ => panic!("expected a bar"),
+            })
+            .collect();
+        assert_eq!(decoded, bars);
+    }
+
+    #[test]
+    fn test_validate_schema_with_invalid_bar_type() {
+        for bar_type in ["AAPL.-1-MINUTE-LAST-INTERNAL", "AAPL.NASDAQ-1-MINUTE-LAST"] {
+            let mut metadata = create_metadata();
+            metadata.insert("bar_type".to_string(), bar_type.to_string());
+
+            let result = Bar::validate_schema(&Bar::get_schema(metadata));
+
+            assert!(matches!(
+                result,
+                Err(PersistenceError::InvalidMetadata { key, .. }) if key == "bar_type"
+            ));
+        }
+    }
 }
@@ -13,11 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Array, Int64Array, UInt64Array, UInt8Array},
+    array::{Int64Array, UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use nautilus_model::{
@@ -27,56 +28,74 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::{
+    error::PersistenceError,
+    parquet::{
+        get_column, parse_instrument_id, parse_metadata_value, parse_metadata_with, Data,
+        DecodeDataFromRecordBatch, EncodeToRecordBatch,
+    },
+};
 
 impl DecodeDataFromRecordBatch for OrderBookDelta {
-    fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, PersistenceError> {
         // Parse and validate metadata
-        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata);
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
 
         // Extract field value arrays from record batch
-        let cols = record_batch.columns();
-        let action_values = cols[0].as_any().downcast_ref::<UInt8Array>().unwrap();
-        let side_values = cols[1].as_any().downcast_ref::<UInt8Array>().unwrap();
-        let price_values = cols[2].as_any().downcast_ref::<Int64Array>().unwrap();
-        let size_values = cols[3].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let order_id_values = cols[4].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let flags_values = cols[5].as_any().downcast_ref::<UInt8Array>().unwrap();
-        let sequence_values = cols[6].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_event_values = cols[7].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_init_values = cols[8].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let action_values = get_column::<UInt8Array>(&record_batch, "action", &DataType::UInt8)?;
+        let side_values = get_column::<UInt8Array>(&record_batch, "side", &DataType::UInt8)?;
+        let price_values = get_column::<Int64Array>(&record_batch, "price", &DataType::Int64)?;
+        let size_values = get_column::<UInt64Array>(&record_batch, "size", &DataType::UInt64)?;
+        let order_id_values =
+            get_column::<UInt64Array>(&record_batch, "order_id", &DataType::UInt64)?;
+        let flags_values = get_column::<UInt8Array>(&record_batch, "flags", &DataType::UInt8)?;
+        let sequence_values =
+            get_column::<UInt64Array>(&record_batch, "sequence", &DataType::UInt64)?;
+        let ts_event_values =
+            get_column::<UInt64Array>(&record_batch, "ts_event", &DataType::UInt64)?;
+        let ts_init_values =
+            get_column::<UInt64Array>(&record_batch, "ts_init", &DataType::UInt64)?;
 
         // Construct iterator of values from field value arrays
         let values = action_values
-            .into_iter()
-            .zip(side_values.iter())
-            .zip(price_values.iter())
-            .zip(size_values.iter())
-            .zip(order_id_values.iter())
-            .zip(flags_values.iter())
-            .zip(sequence_values.iter())
-            .zip(ts_event_values.iter())
-            .zip(ts_init_values.iter())
+            .values()
+            .iter()
+            .zip(side_values.values().iter())
+            .zip(price_values.values().iter())
+            .zip(size_values.values().iter())
+            .zip(order_id_values.values().iter())
+            .zip(flags_values.values().iter())
+            .zip(sequence_values.values().iter())
+            .zip(ts_event_values.values().iter())
+            .zip(ts_init_values.values().iter())
             .map(
                 |(
-                    (((((((action, side), price), size), order_id), flags), sequence), ts_event),
-                    ts_init,
+                    (
+                        ((((((&action, &side), &price), &size), &order_id), &flags), &sequence),
+                        &ts_event,
+                    ),
+                    &ts_init,
                 )| {
-                    Self {
+                    Ok(Self {
                         instrument_id: instrument_id.clone(),
-                        action: BookAction::from_u8(action.unwrap()).unwrap(),
+                        action: BookAction::from_u8(action)
+                            .ok_or_else(|| invalid_value("action", action))?,
                         order: BookOrder {
-                            side: OrderSide::from_u8(side.unwrap()).unwrap(),
-                            price: Price::from_raw(price.unwrap(), price_precision),
-                            size: Quantity::from_raw(size.unwrap(), size_precision),
-                            order_id: order_id.unwrap(),
+                            side: OrderSide::from_u8(side)
+                                .ok_or_else(|| invalid_value("side", side))?,
+                            price: Price::from_raw(price, price_precision),
+                            size: Quantity::from_raw(size, size_precision),
+                            order_id,
                         },
-                        flags: flags.unwrap(),
-                        sequence: sequence.unwrap(),
-                        ts_event: ts_event.unwrap(),
-                        ts_init: ts_init.unwrap(),
+                        flags,
+                        sequence,
+                        ts_event,
+                        ts_init,
                     }
-                    .into()
+                    .into())
                 },
             );
 
@@ -100,24 +119,62 @@ impl DecodeDataFromRecordBatch for OrderBookDelta {
     }
 }
 
-fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8) {
-    let instrument_id =
-        InstrumentId::from_str(metadata.get("instrument_id").unwrap().as_str()).unwrap();
+impl EncodeToRecordBatch for OrderBookDelta {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        // Encode field value arrays in schema order
+        let action = UInt8Array::from_iter_values(data.iter().map(|d| d.action as u8));
+        let side = UInt8Array::from_iter_values(data.iter().map(|d| d.order.side as u8));
+        let price = Int64Array::from_iter_values(data.iter().map(|d| d.order.price.raw));
+        let size = UInt64Array::from_iter_values(data.iter().map(|d| d.order.size.raw));
+        let order_id = UInt64Array::from_iter_values(data.iter().map(|d| d.order.order_id));
+        let flags = UInt8Array::from_iter_values(data.iter().map(|d| d.flags));
+        let sequence = UInt64Array::from_iter_values(data.iter().map(|d| d.sequence));
+        let ts_event = UInt64Array::from_iter_values(data.iter().map(|d| d.ts_event));
+        let ts_init = UInt64Array::from_iter_values(data.iter().map(|d| d.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(action),
+                Arc::new(side),
+                Arc::new(price),
+                Arc::new(size),
+                Arc::new(order_id),
+                Arc::new(flags),
+                Arc::new(sequence),
+                Arc::new(ts_event),
+                Arc::new(ts_init),
+            ],
+        )
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), PersistenceError> {
+    let instrument_id = parse_metadata_with(metadata, "instrument_id", parse_instrument_id)?;
     // BookType is unused for now: clarifies data
-    let _book_type =
-        BookType::from_u8(metadata.get("book_type").unwrap().parse::<u8>().unwrap()).unwrap();
-    let price_precision = metadata
-        .get("price_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
-    let size_precision = metadata
-        .get("size_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
+    let book_type = parse_metadata_value::<u8>(metadata, "book_type")?;
+    if BookType::from_u8(book_type).is_none() {
+        return Err(PersistenceError::InvalidMetadata {
+            key: "book_type".to_string(),
+            value: book_type.to_string(),
+        });
+    }
+    let price_precision = parse_metadata_value::<u8>(metadata, "price_precision")?;
+    let size_precision = parse_metadata_value::<u8>(metadata, "size_precision")?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
 
-    (instrument_id, price_precision, size_precision)
+fn invalid_value(column: &str, value: u8) -> PersistenceError {
+    PersistenceError::InvalidValue {
+        column: column.to_string(),
+        value: value.to_string(),
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -189,7 +246,87 @@ mod tests {
         )
         .unwrap();
 
-        let decoded_data = OrderBookDelta::decode_batch(&metadata, record_batch);
+        let decoded_data = OrderBookDelta::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[test]
+    fn test_encode_batch_round_trip() {
+        let metadata = create_metadata();
+        let (instrument_id, price_precision, size_precision) = parse_metadata(&metadata).unwrap();
+        let deltas = vec![
+            OrderBookDelta {
+                instrument_id: instrument_id.clone(),
+                action: BookAction::Add,
+                order: BookOrder {
+                    side: OrderSide::Buy,
+                    price: Price::from_raw(10000, price_precision),
+                    size: Quantity::from_raw(100, size_precision),
+                    order_id: 1,
+                },
+                flags: 0,
+                sequence: 1,
+                ts_event: 1,
+                ts_init: 3,
+            },
+            OrderBookDelta {
+                instrument_id,
+                action: BookAction::Update,
+                order: BookOrder {
+                    side: OrderSide::Sell,
+                    price: Price::from_raw(9900, price_precision),
+                    size: Quantity::from_raw(90, size_precision),
+                    order_id: 2,
+                },
+                flags: 0,
+                sequence: 2,
+                ts_event: 2,
+                ts_init: 4,
+            },
+        ];
+
+        let record_batch = OrderBookDelta::encode_batch(&metadata, &deltas).unwrap();
+        assert_eq!(
+            record_batch.schema(),
+            OrderBookDelta::get_schema(metadata.clone())
+        );
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded: Vec<OrderBookDelta> = OrderBookDelta::decode_batch(&metadata, record_batch)
+            .unwrap()
+            .into_iter()
+            .map(|data| match data {
+                Data::Delta(delta) => delta,
+                _ => panic!("expected an order book delta"),
+            })
+            .collect();
+        assert_eq!(decoded, deltas);
+    }
+
+    #[test]
+    fn test_decode_batch_with_invalid_action() {
+        let metadata = create_metadata();
+        let record_batch = RecordBatch::try_new(
+            OrderBookDelta::get_schema(metadata.clone()),
+            vec![
+                Arc::new(UInt8Array::from(vec![9])),
+                Arc::new(UInt8Array::from(vec![1])),
+                Arc::new(Int64Array::from(vec![10000])),
+                Arc::new(UInt64Array::from(vec![100])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt8Array::from(vec![0])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![3])),
+            ],
+        )
+        .unwrap();
+
+        let result = OrderBookDelta::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::InvalidValue { column, .. }) if column == "action"
+        ));
+    }
 }
@@ -13,11 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Array, Int64Array, UInt64Array},
+    array::{Int64Array, UInt64Array},
     datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use nautilus_model::{
@@ -26,46 +27,59 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::{
+    error::PersistenceError,
+    parquet::{
+        get_column, parse_instrument_id, parse_metadata_value, parse_metadata_with, Data,
+        DecodeDataFromRecordBatch, EncodeToRecordBatch,
+    },
+};
 
 impl DecodeDataFromRecordBatch for QuoteTick {
-    fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, PersistenceError> {
         // Parse and validate metadata
-        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata);
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
 
         // Extract field value arrays from record batch
-        let cols = record_batch.columns();
-        let bid_values = cols[0].as_any().downcast_ref::<Int64Array>().unwrap();
-        let ask_values = cols[1].as_any().downcast_ref::<Int64Array>().unwrap();
-        let ask_size_values = cols[2].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let bid_size_values = cols[3].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_event_values = cols[4].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_init_values = cols[5].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let bid_values = get_column::<Int64Array>(&record_batch, "bid", &DataType::Int64)?;
+        let ask_values = get_column::<Int64Array>(&record_batch, "ask", &DataType::Int64)?;
+        let bid_size_values =
+            get_column::<UInt64Array>(&record_batch, "bid_size", &DataType::UInt64)?;
+        let ask_size_values =
+            get_column::<UInt64Array>(&record_batch, "ask_size", &DataType::UInt64)?;
+        let ts_event_values =
+            get_column::<UInt64Array>(&record_batch, "ts_event", &DataType::UInt64)?;
+        let ts_init_values =
+            get_column::<UInt64Array>(&record_batch, "ts_init", &DataType::UInt64)?;
 
         // Construct iterator of values from field value arrays
         let values = bid_values
-            .into_iter()
-            .zip(ask_values.iter())
-            .zip(ask_size_values.iter())
-            .zip(bid_size_values.iter())
-            .zip(ts_event_values.iter())
-            .zip(ts_init_values.iter())
+            .values()
+            .iter()
+            .zip(ask_values.values().iter())
+            .zip(bid_size_values.values().iter())
+            .zip(ask_size_values.values().iter())
+            .zip(ts_event_values.values().iter())
+            .zip(ts_init_values.values().iter())
             .map(
-                |(((((bid, ask), ask_size), bid_size), ts_event), ts_init)| {
+                |(((((&bid, &ask), &bid_size), &ask_size), &ts_event), &ts_init)| {
                     Self {
                         instrument_id: instrument_id.clone(),
-                        bid: Price::from_raw(bid.unwrap(), price_precision),
-                        ask: Price::from_raw(ask.unwrap(), price_precision),
-                        bid_size: Quantity::from_raw(bid_size.unwrap(), size_precision),
-                        ask_size: Quantity::from_raw(ask_size.unwrap(), size_precision),
-                        ts_event: ts_event.unwrap(),
-                        ts_init: ts_init.unwrap(),
+                        bid: Price::from_raw(bid, price_precision),
+                        ask: Price::from_raw(ask, price_precision),
+                        bid_size: Quantity::from_raw(bid_size, size_precision),
+                        ask_size: Quantity::from_raw(ask_size, size_precision),
+                        ts_event,
+                        ts_init,
                     }
                     .into()
                 },
             );
 
-        values.collect()
+        Ok(values.collect())
     }
 
     fn get_schema(metadata: std::collections::HashMap<String, String>) -> SchemaRef {
@@ -82,21 +96,41 @@ impl DecodeDataFromRecordBatch for QuoteTick {
     }
 }
 
-fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8) {
-    let instrument_id =
-        InstrumentId::from_str(metadata.get("instrument_id").unwrap().as_str()).unwrap();
-    let price_precision = metadata
-        .get("price_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
-    let size_precision = metadata
-        .get("size_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
+impl EncodeToRecordBatch for QuoteTick {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        // Encode field value arrays in schema order
+        let bid = Int64Array::from_iter_values(data.iter().map(|q| q.bid.raw));
+        let ask = Int64Array::from_iter_values(data.iter().map(|q| q.ask.raw));
+        let bid_size = UInt64Array::from_iter_values(data.iter().map(|q| q.bid_size.raw));
+        let ask_size = UInt64Array::from_iter_values(data.iter().map(|q| q.ask_size.raw));
+        let ts_event = UInt64Array::from_iter_values(data.iter().map(|q| q.ts_event));
+        let ts_init = UInt64Array::from_iter_values(data.iter().map(|q| q.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(bid),
+                Arc::new(ask),
+                Arc::new(bid_size),
+                Arc::new(ask_size),
+                Arc::new(ts_event),
+                Arc::new(ts_init),
+            ],
+        )
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), PersistenceError> {
+    let instrument_id = parse_metadata_with(metadata, "instrument_id", parse_instrument_id)?;
+    let price_precision = parse_metadata_value::<u8>(metadata, "price_precision")?;
+    let size_precision = parse_metadata_value::<u8>(metadata, "size_precision")?;
 
-    (instrument_id, price_precision, size_precision)
+    Ok((instrument_id, price_precision, size_precision))
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -158,7 +192,235 @@ mod tests {
         )
         .unwrap();
 
-        let decoded_data = QuoteTick::decode_batch(&metadata, record_batch);
+        let decoded_data = QuoteTick::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
+        if let Data::Quote(quote) = &decoded_data[0] {
+            assert_eq!(quote.bid_size.raw, 100);
+            assert_eq!(quote.ask_size.raw, 110);
+        } else {
+            panic!("expected a quote tick");
+        }
+    }
+
+    #[test]
+    fn test_encode_batch_round_trip() {
+        let metadata = create_metadata();
+        let (instrument_id, price_precision, size_precision) = parse_metadata(&metadata).unwrap();
+        let quotes = vec![
+            QuoteTick {
+                instrument_id: instrument_id.clone(),
+                bid: Price::from_raw(10000, price_precision),
+                ask: Price::from_raw(10100, price_precision),
+                bid_size: Quantity::from_raw(100, size_precision),
+                ask_size: Quantity::from_raw(110, size_precision),
+                ts_event: 1,
+                ts_init: 3,
+            },
+            QuoteTick {
+                instrument_id,
+                bid: Price::from_raw(9900, price_precision),
+                ask: Price::from_raw(10000, price_precision),
+                bid_size: Quantity::from_raw(90, size_precision),
+                ask_size: Quantity::from_raw(100, size_precision),
+                ts_event: 2,
+                ts_init: 4,
+            },
+        ];
+
+        let record_batch = QuoteTick::encode_batch(&metadata, &quotes).unwrap();
+        assert_eq!(
+            record_batch.schema(),
+            QuoteTick::get_schema(metadata.clone())
+        );
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded: Vec<QuoteTick> = QuoteTick::decode_batch(&metadata, record_batch)
+            .unwrap()
+            .into_iter()
+            .map(|data| match data {
+                Data::Quote(quote) => quote,
+                _ => panic!("expected a quote tick"),
+            })
+            .collect();
+        assert_eq!(decoded, quotes);
+    }
+
+    #[test]
+    fn test_decode_batch_with_reordered_columns() {
+        let metadata = create_metadata();
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("ts_init", DataType::UInt64, false),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ask_size", DataType::UInt64, false),
+                Field::new("bid_size", DataType::UInt64, false),
+                Field::new("ask", DataType::Int64, false),
+                Field::new("bid", DataType::Int64, false),
+            ],
+            metadata.clone(),
+        );
+        let record_batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(UInt64Array::from(vec![3])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![110])),
+                Arc::new(UInt64Array::from(vec![100])),
+                Arc::new(Int64Array::from(vec![10100])),
+                Arc::new(Int64Array::from(vec![10000])),
+            ],
+        )
+        .unwrap();
+
+        QuoteTick::validate_schema(&record_batch.schema()).unwrap();
+        let decoded_data = QuoteTick::decode_batch(&metadata, record_batch).unwrap();
+        if let Data::Quote(quote) = &decoded_data[0] {
+            assert_eq!(quote.bid.raw, 10000);
+            assert_eq!(quote.ask_size.raw, 110);
+            assert_eq!(quote.ts_init, 3);
+        } else {
+            panic!("expected a quote tick");
+        }
+    }
+
+    #[test]
+    fn test_decode_batch_with_missing_metadata() {
+        let mut metadata = create_metadata();
+        metadata.remove("price_precision");
+        let record_batch = RecordBatch::new_empty(QuoteTick::get_schema(metadata.clone()));
+
+        let result = QuoteTick::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::MissingMetadata(key)) if key == "price_precision"
+        ));
+    }
+
+    #[test]
+    fn test_decode_batch_with_invalid_metadata() {
+        let mut metadata = create_metadata();
+        metadata.insert("size_precision".to_string(), "zero".to_string());
+
+        let result = QuoteTick::validate_schema(&QuoteTick::get_schema(metadata));
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::InvalidMetadata { key, .. }) if key == "size_precision"
+        ));
+    }
+
+    #[test]
+    fn test_validate_schema_with_missing_column() {
+        let metadata = create_metadata();
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("bid", DataType::Int64, false),
+                Field::new("ask", DataType::Int64, false),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ts_init", DataType::UInt64, false),
+            ],
+            metadata,
+        );
+
+        let result = QuoteTick::validate_schema(&schema);
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::MissingColumn(column)) if column == "bid_size"
+        ));
+    }
+
+    #[test]
+    fn test_validate_schema_with_invalid_column_type() {
+        let metadata = create_metadata();
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("bid", DataType::Float64, false),
+                Field::new("ask", DataType::Int64, false),
+                Field::new("bid_size", DataType::UInt64, false),
+                Field::new("ask_size", DataType::UInt64, false),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ts_init", DataType::UInt64, false),
+            ],
+            metadata,
+        );
+
+        let result = QuoteTick::validate_schema(&schema);
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::InvalidColumnType { column, .. }) if column == "bid"
+        ));
+    }
+
+    #[test]
+    fn test_decode_batch_with_null_values() {
+        let metadata = create_metadata();
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("bid", DataType::Int64, true),
+                Field::new("ask", DataType::Int64, false),
+                Field::new("bid_size", DataType::UInt64, false),
+                Field::new("ask_size", DataType::UInt64, false),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ts_init", DataType::UInt64, false),
+            ],
+            metadata.clone(),
+        );
+        let record_batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![Some(10000), None])),
+                Arc::new(Int64Array::from(vec![10100, 10000])),
+                Arc::new(UInt64Array::from(vec![100, 90])),
+                Arc::new(UInt64Array::from(vec![110, 100])),
+                Arc::new(UInt64Array::from(vec![1, 2])),
+                Arc::new(UInt64Array::from(vec![3, 4])),
+            ],
+        )
+        .unwrap();
+
+        let result = QuoteTick::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::NullValues { column, count: 1 }) if column == "bid"
+        ));
+    }
+
+    #[test]
+    fn test_validate_schema_with_nullable_columns() {
+        // Nullable fields are the pyarrow default, null values are only
+        // rejected when decoding
+        let metadata = create_metadata();
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("bid", DataType::Int64, true),
+                Field::new("ask", DataType::Int64, true),
+                Field::new("bid_size", DataType::UInt64, true),
+                Field::new("ask_size", DataType::UInt64, true),
+                Field::new("ts_event", DataType::UInt64, true),
+                Field::new("ts_init", DataType::UInt64, true),
+            ],
+            metadata,
+        );
+
+        assert!(QuoteTick::validate_schema(&schema).is_ok());
+    }
+
+    #[test]
+    fn test_validate_schema_with_invalid_instrument_id() {
+        for instrument_id in ["AAPL.", ".NASDAQ", "AAPL. ", "AAPL"] {
+            let mut metadata = create_metadata();
+            metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+
+            let result = QuoteTick::validate_schema(&QuoteTick::get_schema(metadata));
+
+            assert!(matches!(
+                result,
+                Err(PersistenceError::InvalidMetadata { key, .. }) if key == "instrument_id"
+            ));
+        }
     }
 }
@@ -13,11 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Array, Int64Array, StringArray, UInt64Array, UInt8Array},
+    array::{Int64Array, StringArray, UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use nautilus_model::{
@@ -27,43 +28,60 @@ use nautilus_model::{
     types::{price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{Data, DecodeDataFromRecordBatch};
+use crate::{
+    error::PersistenceError,
+    parquet::{
+        get_column, parse_instrument_id, parse_metadata_value, parse_metadata_with, Data,
+        DecodeDataFromRecordBatch, EncodeToRecordBatch,
+    },
+};
 
 impl DecodeDataFromRecordBatch for TradeTick {
-    fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data> {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, PersistenceError> {
         // Parse and validate metadata
-        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata);
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
 
         // Extract field value arrays from record batch
-        let cols = record_batch.columns();
-        let price_values = cols[0].as_any().downcast_ref::<Int64Array>().unwrap();
-        let size_values = cols[1].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let aggressor_side_values = cols[2].as_any().downcast_ref::<UInt8Array>().unwrap();
-        let trade_id_values_values = cols[3].as_any().downcast_ref::<StringArray>().unwrap();
-        let ts_event_values = cols[4].as_any().downcast_ref::<UInt64Array>().unwrap();
-        let ts_init_values = cols[5].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let price_values = get_column::<Int64Array>(&record_batch, "price", &DataType::Int64)?;
+        let size_values = get_column::<UInt64Array>(&record_batch, "size", &DataType::UInt64)?;
+        let aggressor_side_values =
+            get_column::<UInt8Array>(&record_batch, "aggressor_side", &DataType::UInt8)?;
+        let trade_id_values =
+            get_column::<StringArray>(&record_batch, "trade_id", &DataType::Utf8)?;
+        let ts_event_values =
+            get_column::<UInt64Array>(&record_batch, "ts_event", &DataType::UInt64)?;
+        let ts_init_values =
+            get_column::<UInt64Array>(&record_batch, "ts_init", &DataType::UInt64)?;
 
         // Construct iterator of values from field value arrays
         let values = price_values
-            .into_iter()
-            .zip(size_values.into_iter())
-            .zip(aggressor_side_values.into_iter())
-            .zip(trade_id_values_values.into_iter())
-            .zip(ts_event_values.into_iter())
-            .zip(ts_init_values.into_iter())
+            .values()
+            .iter()
+            .zip(size_values.values().iter())
+            .zip(aggressor_side_values.values().iter())
+            .zip(trade_id_values.iter())
+            .zip(ts_event_values.values().iter())
+            .zip(ts_init_values.values().iter())
             .map(
-                |(((((price, size), aggressor_side), trade_id), ts_event), ts_init)| {
-                    Self {
+                |(((((&price, &size), &aggressor_side), trade_id), &ts_event), &ts_init)| {
+                    Ok(Self {
                         instrument_id: instrument_id.clone(),
-                        price: Price::from_raw(price.unwrap(), price_precision),
-                        size: Quantity::from_raw(size.unwrap(), size_precision),
-                        aggressor_side: AggressorSide::from_repr(aggressor_side.unwrap() as usize)
-                            .expect("cannot parse enum value"),
-                        trade_id: TradeId::new(trade_id.unwrap()),
-                        ts_event: ts_event.unwrap(),
-                        ts_init: ts_init.unwrap(),
+                        price: Price::from_raw(price, price_precision),
+                        size: Quantity::from_raw(size, size_precision),
+                        aggressor_side: AggressorSide::from_repr(aggressor_side as usize)
+                            .ok_or_else(|| PersistenceError::InvalidValue {
+                                column: "aggressor_side".to_string(),
+                                value: aggressor_side.to_string(),
+                            })?,
+                        // Null values were rejected when the column was extracted
+                        trade_id: TradeId::new(trade_id.unwrap_or_default()),
+                        ts_event,
+                        ts_init,
                     }
-                    .into()
+                    .into())
                 },
             );
 
@@ -84,21 +102,42 @@ impl DecodeDataFromRecordBatch for TradeTick {
     }
 }
 
-fn parse_metadata(metadata: &HashMap<String, String>) -> (InstrumentId, u8, u8) {
-    let instrument_id =
-        InstrumentId::from_str(metadata.get("instrument_id").unwrap().as_str()).unwrap();
-    let price_precision = metadata
-        .get("price_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
-    let size_precision = metadata
-        .get("size_precision")
-        .unwrap()
-        .parse::<u8>()
-        .unwrap();
+impl EncodeToRecordBatch for TradeTick {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        // Encode field value arrays in schema order
+        let price = Int64Array::from_iter_values(data.iter().map(|t| t.price.raw));
+        let size = UInt64Array::from_iter_values(data.iter().map(|t| t.size.raw));
+        let aggressor_side =
+            UInt8Array::from_iter_values(data.iter().map(|t| t.aggressor_side as u8));
+        let trade_id = StringArray::from_iter_values(data.iter().map(|t| t.trade_id.to_string()));
+        let ts_event = UInt64Array::from_iter_values(data.iter().map(|t| t.ts_event));
+        let ts_init = UInt64Array::from_iter_values(data.iter().map(|t| t.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(metadata.clone()),
+            vec![
+                Arc::new(price),
+                Arc::new(size),
+                Arc::new(aggressor_side),
+                Arc::new(trade_id),
+                Arc::new(ts_event),
+                Arc::new(ts_init),
+            ],
+        )
+    }
+}
 
-    (instrument_id, price_precision, size_precision)
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), PersistenceError> {
+    let instrument_id = parse_metadata_with(metadata, "instrument_id", parse_instrument_id)?;
+    let price_precision = parse_metadata_value::<u8>(metadata, "price_precision")?;
+    let size_precision = parse_metadata_value::<u8>(metadata, "size_precision")?;
+
+    Ok((instrument_id, price_precision, size_precision))
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -163,7 +202,50 @@ mod tests {
         )
         .unwrap();
 
-        let decoded_data = TradeTick::decode_batch(&metadata, record_batch);
+        let decoded_data = TradeTick::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[test]
+    fn test_encode_batch_round_trip() {
+        let metadata = create_metadata();
+        let (instrument_id, price_precision, size_precision) = parse_metadata(&metadata).unwrap();
+        let trades = vec![
+            TradeTick {
+                instrument_id: instrument_id.clone(),
+                price: Price::from_raw(10000, price_precision),
+                size: Quantity::from_raw(100, size_precision),
+                aggressor_side: AggressorSide::Buyer,
+                trade_id: TradeId::new("trade_1"),
+                ts_event: 1,
+                ts_init: 3,
+            },
+            TradeTick {
+                instrument_id,
+                price: Price::from_raw(9900, price_precision),
+                size: Quantity::from_raw(90, size_precision),
+                aggressor_side: AggressorSide::Seller,
+                trade_id: TradeId::new("trade_2"),
+                ts_event: 2,
+                ts_init: 4,
+            },
+        ];
+
+        let record_batch = TradeTick::encode_batch(&metadata, &trades).unwrap();
+        assert_eq!(
+            record_batch.schema(),
+            TradeTick::get_schema(metadata.clone())
+        );
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded: Vec<TradeTick> = TradeTick::decode_batch(&metadata, record_batch)
+            .unwrap()
+            .into_iter()
+            .map(|data| match data {
+                Data::Trade(trade) => trade,
+                _ => panic!("expected a trade tick"),
+            })
+            .collect();
+        assert_eq!(decoded, trades);
+    }
 }
@@ -14,16 +14,29 @@
 // -------------------------------------------------------------------------------------------------
 
 mod implementations;
+mod writer;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
-use datafusion::arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
-use nautilus_model::data::Data;
+use datafusion::arrow::{
+    array::Array,
+    datatypes::{DataType, Schema, SchemaRef},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::correctness::is_valid_string;
+use nautilus_model::{
+    data::{bar::BarType, Data},
+    identifiers::instrument_id::InstrumentId,
+};
 use pyo3::prelude::*;
 
+pub use self::writer::ParquetWriter;
+use crate::{custom::CustomData, error::PersistenceError};
+
 #[repr(C)]
 #[pyclass]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParquetType {
     // Custom = 0,  # First slot reserved for custom data
     OrderBookDelta = 1,
@@ -44,6 +57,195 @@ pub trait DecodeDataFromRecordBatch
 where
     Self: Sized + Into<Data>,
 {
-    fn decode_batch(metadata: &HashMap<String, String>, record_batch: RecordBatch) -> Vec<Data>;
+    /// Decodes the `record_batch` into data, with columns looked up by name.
+    ///
+    /// # Errors
+    ///
+    /// - If a metadata key is missing or invalid.
+    /// - If a column is missing, has an invalid type or contains null values.
+    /// - If a column contains an invalid value for its field.
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, PersistenceError>;
     fn get_schema(metadata: HashMap<String, String>) -> SchemaRef;
+
+    /// Validates that data can be decoded from a file with the `schema`, so an
+    /// invalid file is rejected before any of its records are read.
+    ///
+    /// # Errors
+    ///
+    /// - If a metadata key is missing or invalid.
+    /// - If a field of the schema from `get_schema` is missing or has another type.
+    fn validate_schema(schema: &Schema) -> Result<(), PersistenceError> {
+        validate_fields(&Self::get_schema(schema.metadata().clone()), schema)?;
+        // Decoding an empty batch parses and validates the metadata
+        let empty_batch = RecordBatch::new_empty(Arc::new(schema.clone()));
+        Self::decode_batch(schema.metadata(), empty_batch).map(|_| ())
+    }
+}
+
+/// Decodes record batches into a user-defined data type, registered with a
+/// [`CustomDataRegistry`](crate::custom::CustomDataRegistry) under `TYPE_NAME`.
+pub trait DecodeCustomDataFromRecordBatch
+where
+    Self: Sized + CustomData,
+{
+    const TYPE_NAME: &'static str;
+
+    /// Decodes the `record_batch` into data, with columns looked up by name.
+    ///
+    /// # Errors
+    ///
+    /// If the record batch or its metadata cannot be decoded.
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, PersistenceError>;
+    fn get_schema(metadata: HashMap<String, String>) -> SchemaRef;
+
+    /// Validates that data can be decoded from a file with the `schema`, so an
+    /// invalid file is rejected before any of its records are read.
+    ///
+    /// # Errors
+    ///
+    /// - If the metadata cannot be decoded.
+    /// - If a field of the schema from `get_schema` is missing or has another type.
+    fn validate_schema(schema: &Schema) -> Result<(), PersistenceError> {
+        validate_fields(&Self::get_schema(schema.metadata().clone()), schema)?;
+        // Decoding an empty batch parses and validates the metadata
+        let empty_batch = RecordBatch::new_empty(Arc::new(schema.clone()));
+        Self::decode_batch(schema.metadata(), empty_batch).map(|_| ())
+    }
+}
+
+pub trait EncodeToRecordBatch
+where
+    Self: Sized + DecodeDataFromRecordBatch,
+{
+    /// Encodes the `data` into a record batch with the schema from `get_schema`.
+    ///
+    /// # Errors
+    ///
+    /// If the record batch cannot be created from the encoded columns.
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError>;
+}
+
+/// Checks that each field of the `expected` schema is in the `actual` schema with
+/// the same type. The fields may be in any order.
+///
+/// Fields are not required to match in nullability, as writers such as pyarrow
+/// mark fields nullable by default. Null values are rejected when decoding.
+///
+/// # Errors
+///
+/// If a field is missing or has another type.
+pub fn validate_fields(expected: &Schema, actual: &Schema) -> Result<(), PersistenceError> {
+    for field in expected.fields() {
+        let actual_field = actual
+            .field_with_name(field.name())
+            .map_err(|_| PersistenceError::MissingColumn(field.name().clone()))?;
+        if actual_field.data_type() != field.data_type() {
+            return Err(PersistenceError::InvalidColumnType {
+                column: field.name().clone(),
+                expected: field.data_type().clone(),
+                actual: actual_field.data_type().clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the value of the metadata `key` parsed as `T`.
+///
+/// Identifiers must be parsed with [`parse_metadata_with`] instead, as their
+/// constructors panic on invalid values.
+///
+/// # Errors
+///
+/// If the key is missing or its value cannot be parsed.
+pub fn parse_metadata_value<T: FromStr>(
+    metadata: &HashMap<String, String>,
+    key: &str,
+) -> Result<T, PersistenceError> {
+    parse_metadata_with(metadata, key, |value| value.parse().ok())
+}
+
+/// Returns the value of the metadata `key` parsed by `parse`.
+///
+/// # Errors
+///
+/// If the key is missing or `parse` returns `None` for its value.
+pub fn parse_metadata_with<T>(
+    metadata: &HashMap<String, String>,
+    key: &str,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> Result<T, PersistenceError> {
+    let value = metadata
+        .get(key)
+        .ok_or_else(|| PersistenceError::MissingMetadata(key.to_string()))?;
+    parse(value).ok_or_else(|| PersistenceError::InvalidMetadata {
+        key: key.to_string(),
+        value: value.clone(),
+    })
+}
+
+/// Returns the instrument ID parsed from `value`, or `None` if it is invalid.
+///
+/// The symbol and venue are validated before parsing, as the identifier
+/// constructors panic on invalid strings.
+#[must_use]
+pub fn parse_instrument_id(value: &str) -> Option<InstrumentId> {
+    let (symbol, venue) = value.rsplit_once('.')?;
+    if !(is_valid_string(symbol) && is_valid_string(venue)) {
+        return None;
+    }
+    InstrumentId::from_str(value).ok()
+}
+
+/// Returns the bar type parsed from `value`, or `None` if it is invalid.
+///
+/// The instrument ID is validated as by [`parse_instrument_id`] before parsing.
+#[must_use]
+pub fn parse_bar_type(value: &str) -> Option<BarType> {
+    // The bar specification and aggregation source are the last four parts
+    let instrument_id = value.rsplitn(5, '-').nth(4)?;
+    parse_instrument_id(instrument_id)?;
+    BarType::from_str(value).ok()
+}
+
+/// Returns the column `name` of the `record_batch` as an array of type `A`.
+///
+/// # Errors
+///
+/// - If the column is missing.
+/// - If the column is not of `data_type`.
+/// - If the column contains null values.
+pub fn get_column<'a, A: Array + 'static>(
+    record_batch: &'a RecordBatch,
+    name: &str,
+    data_type: &DataType,
+) -> Result<&'a A, PersistenceError> {
+    let column = record_batch
+        .column_by_name(name)
+        .ok_or_else(|| PersistenceError::MissingColumn(name.to_string()))?;
+    let array = column
+        .as_any()
+        .downcast_ref::<A>()
+        .filter(|_| column.data_type() == data_type)
+        .ok_or_else(|| PersistenceError::InvalidColumnType {
+            column: name.to_string(),
+            expected: data_type.clone(),
+            actual: column.data_type().clone(),
+        })?;
+    if array.null_count() > 0 {
+        return Err(PersistenceError::NullValues {
+            column: name.to_string(),
+            count: array.null_count(),
+        });
+    }
+    Ok(array)
 }
@@ -0,0 +1,201 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, io::Write};
+
+use datafusion::{
+    arrow::{datatypes::SchemaRef, record_batch::RecordBatch},
+    parquet::{
+        arrow::ArrowWriter, errors::ParquetError, file::properties::WriterProperties,
+        format::FileMetaData,
+    },
+};
+use nautilus_model::data::{
+    bar::Bar,
+    book::OrderBookDelta,
+    tick::{QuoteTick, TradeTick},
+    Data,
+};
+
+use super::{DecodeDataFromRecordBatch, EncodeToRecordBatch, ParquetType};
+
+/// Writes chunks of [`Data`] of a single [`ParquetType`] to a parquet file.
+///
+/// The `metadata` is stored with the file schema and must contain the keys
+/// expected by the matching decoder: `instrument_id` (or `bar_type` for bars),
+/// `price_precision` and `size_precision`, plus `book_type` for order book deltas.
+pub struct ParquetWriter<W: Write + Send> {
+    parquet_type: ParquetType,
+    metadata: HashMap<String, String>,
+    writer: ArrowWriter<W>,
+}
+
+impl<W: Write + Send> ParquetWriter<W> {
+    /// Creates a new [`ParquetWriter`] which writes to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// If the parquet file writer cannot be created.
+    pub fn new(
+        writer: W,
+        parquet_type: ParquetType,
+        metadata: HashMap<String, String>,
+        props: Option<WriterProperties>,
+    ) -> Result<Self, ParquetError> {
+        let schema = get_schema(parquet_type, metadata.clone());
+        Ok(Self {
+            parquet_type,
+            metadata,
+            writer: ArrowWriter::try_new(writer, schema, props)?,
+        })
+    }
+
+    /// Encodes and writes the `data` chunk as a record batch.
+    ///
+    /// # Errors
+    ///
+    /// - If `data` contains a variant which does not match the writer type.
+    /// - If the data cannot be encoded or written.
+    pub fn write(&mut self, data: &[Data]) -> Result<(), ParquetError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let record_batch = match self.parquet_type {
+            ParquetType::OrderBookDelta => {
+                encode::<OrderBookDelta>(&self.metadata, data, |d| match d {
+                    Data::Delta(delta) => Some(delta.clone()),
+                    _ => None,
+                })
+            }
+            ParquetType::QuoteTick => encode::<QuoteTick>(&self.metadata, data, |d| match d {
+                Data::Quote(quote) => Some(quote.clone()),
+                _ => None,
+            }),
+            ParquetType::TradeTick => encode::<TradeTick>(&self.metadata, data, |d| match d {
+                Data::Trade(trade) => Some(trade.clone()),
+                _ => None,
+            }),
+            ParquetType::Bar => encode::<Bar>(&self.metadata, data, |d| match d {
+                Data::Bar(bar) => Some(bar.clone()),
+                _ => None,
+            }),
+        }?;
+
+        self.writer.write(&record_batch)
+    }
+
+    /// Flushes any buffered rows to a new row group.
+    ///
+    /// # Errors
+    ///
+    /// If the row group cannot be written.
+    pub fn flush(&mut self) -> Result<(), ParquetError> {
+        self.writer.flush()
+    }
+
+    /// Closes the writer, writing the file footer.
+    ///
+    /// # Errors
+    ///
+    /// If the remaining rows or the footer cannot be written.
+    pub fn close(self) -> Result<FileMetaData, ParquetError> {
+        self.writer.close()
+    }
+}
+
+fn get_schema(parquet_type: ParquetType, metadata: HashMap<String, String>) -> SchemaRef {
+    match parquet_type {
+        ParquetType::OrderBookDelta => OrderBookDelta::get_schema(metadata),
+        ParquetType::QuoteTick => QuoteTick::get_schema(metadata),
+        ParquetType::TradeTick => TradeTick::get_schema(metadata),
+        ParquetType::Bar => Bar::get_schema(metadata),
+    }
+}
+
+fn encode<T: EncodeToRecordBatch>(
+    metadata: &HashMap<String, String>,
+    data: &[Data],
+    extract: fn(&Data) -> Option<T>,
+) -> Result<RecordBatch, ParquetError> {
+    let items = data
+        .iter()
+        .map(|d| {
+            extract(d).ok_or_else(|| {
+                ParquetError::General(format!("invalid data for writer type, was {d:?}"))
+            })
+        })
+        .collect::<Result<Vec<T>, ParquetError>>()?;
+
+    T::encode_batch(metadata, &items).map_err(|e| ParquetError::ArrowError(e.to_string()))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    use super::*;
+
+    fn create_metadata() -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), "AAPL.NASDAQ".to_string());
+        metadata.insert("price_precision".to_string(), "2".to_string());
+        metadata.insert("size_precision".to_string(), "0".to_string());
+        metadata
+    }
+
+    fn quote(ts: u64) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            bid: Price::from_raw(10000, 2),
+            ask: Price::from_raw(10100, 2),
+            bid_size: Quantity::from_raw(100, 0),
+            ask_size: Quantity::from_raw(110, 0),
+            ts_event: ts,
+            ts_init: ts,
+        })
+    }
+
+    #[test]
+    fn test_write_chunks() {
+        let mut writer =
+            ParquetWriter::new(Vec::new(), ParquetType::QuoteTick, create_metadata(), None)
+                .unwrap();
+
+        writer.write(&[quote(1), quote(2)]).unwrap();
+        writer.write(&[]).unwrap();
+        writer.write(&[quote(3)]).unwrap();
+        let file_metadata = writer.close().unwrap();
+
+        assert_eq!(file_metadata.num_rows, 3);
+    }
+
+    #[test]
+    fn test_write_mismatched_data_type() {
+        let mut writer =
+            ParquetWriter::new(Vec::new(), ParquetType::TradeTick, create_metadata(), None)
+                .unwrap();
+
+        assert!(writer.write(&[quote(1)]).is_err());
+    }
+}
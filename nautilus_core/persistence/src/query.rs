@@ -0,0 +1,211 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use datafusion::{
+    error::DataFusionError,
+    parquet::file::{
+        metadata::{ParquetMetaData, RowGroupMetaData},
+        statistics::Statistics,
+    },
+    prelude::{col, lit, DataFrame, Expr},
+};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::identifiers::instrument_id::InstrumentId;
+
+use crate::parquet::{parse_bar_type, parse_instrument_id};
+
+/// Represents a typed filter for the records of a parquet file.
+///
+/// The time range is applied to `ts_init` (inclusive at both ends) as a typed
+/// predicate, so DataFusion can prune parquet row groups using their `ts_init`
+/// statistics instead of scanning the whole file. A file with no row group in the
+/// time range, or whose instrument ID (stored in its schema metadata) does not
+/// match the instrument filter, is skipped entirely.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataQuery {
+    pub start: Option<UnixNanos>,
+    pub end: Option<UnixNanos>,
+    pub instrument_ids: Option<Vec<InstrumentId>>,
+}
+
+impl DataQuery {
+    /// Creates a new [`DataQuery`] instance which matches all records.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the query filtered for records with `ts_init` at or after `start`.
+    #[must_use]
+    pub fn start(mut self, start: UnixNanos) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Returns the query filtered for records with `ts_init` at or before `end`.
+    #[must_use]
+    pub fn end(mut self, end: UnixNanos) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Returns the query filtered for files of the given `instrument_ids`.
+    #[must_use]
+    pub fn instrument_ids(mut self, instrument_ids: Vec<InstrumentId>) -> Self {
+        self.instrument_ids = Some(instrument_ids);
+        self
+    }
+
+    /// Returns the predicate on `ts_init` for the time range, if any.
+    #[must_use]
+    pub fn ts_init_filter(&self) -> Option<Expr> {
+        // Literals must be `UInt64` to match the column type, otherwise the column is
+        // cast and the predicate can no longer be used to prune row groups
+        let start = self.start.map(|start| col("ts_init").gt_eq(lit(start)));
+        let end = self.end.map(|end| col("ts_init").lt_eq(lit(end)));
+        match (start, end) {
+            (Some(start), Some(end)) => Some(start.and(end)),
+            (start, end) => start.or(end),
+        }
+    }
+
+    /// Returns the data frame `df` filtered by the time range and sorted by
+    /// `ts_init` in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// If `df` has no `ts_init` column.
+    pub fn apply(&self, mut df: DataFrame) -> Result<DataFrame, DataFusionError> {
+        if let Some(filter) = self.ts_init_filter() {
+            df = df.filter(filter)?;
+        }
+        df.sort(vec![col("ts_init").sort(true, true)])
+    }
+
+    /// Returns whether any row group of a parquet file with the given footer
+    /// `metadata` may contain records in the time range, based on the `ts_init`
+    /// column statistics.
+    ///
+    /// A row group without `ts_init` statistics is always matched.
+    #[must_use]
+    pub fn matches_row_groups(&self, metadata: &ParquetMetaData) -> bool {
+        if self.start.is_none() && self.end.is_none() {
+            return true;
+        }
+        metadata
+            .row_groups()
+            .iter()
+            .any(|row_group| match ts_init_range(row_group) {
+                Some((min, max)) => {
+                    self.start.map_or(true, |start| max >= start)
+                        && self.end.map_or(true, |end| min <= end)
+                }
+                None => true,
+            })
+    }
+
+    /// Returns whether a file with the given schema `metadata` matches the
+    /// instrument filter.
+    ///
+    /// A file is matched on its `instrument_id` metadata key, or on the instrument
+    /// of its `bar_type` key for bar data. A file with neither key is not matched
+    /// when an instrument filter is set.
+    #[must_use]
+    pub fn matches_metadata(&self, metadata: &HashMap<String, String>) -> bool {
+        match &self.instrument_ids {
+            None => true,
+            Some(instrument_ids) => metadata_instrument_id(metadata)
+                .is_some_and(|instrument_id| instrument_ids.contains(&instrument_id)),
+        }
+    }
+}
+
+fn ts_init_range(row_group: &RowGroupMetaData) -> Option<(UnixNanos, UnixNanos)> {
+    let column = row_group
+        .columns()
+        .iter()
+        .find(|column| column.column_descr().name() == "ts_init")?;
+    // `UInt64` columns are stored as `INT64` with unsigned statistics, so the
+    // values are reinterpreted rather than converted
+    match column.statistics()? {
+        Statistics::Int64(stats) if stats.has_min_max_set() => {
+            Some((*stats.min() as u64, *stats.max() as u64))
+        }
+        _ => None,
+    }
+}
+
+fn metadata_instrument_id(metadata: &HashMap<String, String>) -> Option<InstrumentId> {
+    if let Some(instrument_id) = metadata.get("instrument_id") {
+        return parse_instrument_id(instrument_id);
+    }
+    metadata
+        .get("bar_type")
+        .and_then(|bar_type| parse_bar_type(bar_type))
+        .map(|bar_type| bar_type.instrument_id)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_ts_init_filter() {
+        assert!(DataQuery::new().ts_init_filter().is_none());
+        assert_eq!(
+            DataQuery::new().start(10).ts_init_filter(),
+            Some(col("ts_init").gt_eq(lit(10_u64)))
+        );
+        assert_eq!(
+            DataQuery::new().end(20).ts_init_filter(),
+            Some(col("ts_init").lt_eq(lit(20_u64)))
+        );
+        assert_eq!(
+            DataQuery::new().start(10).end(20).ts_init_filter(),
+            Some(
+                col("ts_init")
+                    .gt_eq(lit(10_u64))
+                    .and(col("ts_init").lt_eq(lit(20_u64)))
+            )
+        );
+    }
+
+    #[test]
+    fn test_matches_metadata() {
+        let query =
+            DataQuery::new().instrument_ids(vec![InstrumentId::from_str("EUR/USD.SIM").unwrap()]);
+        let quotes = HashMap::from([("instrument_id".to_string(), "EUR/USD.SIM".to_string())]);
+        let other = HashMap::from([("instrument_id".to_string(), "USD/JPY.SIM".to_string())]);
+        let bars = HashMap::from([(
+            "bar_type".to_string(),
+            "EUR/USD.SIM-1-MINUTE-BID-EXTERNAL".to_string(),
+        )]);
+        let invalid = HashMap::from([("instrument_id".to_string(), "EUR/USD.".to_string())]);
+
+        assert!(query.matches_metadata(&quotes));
+        assert!(!query.matches_metadata(&invalid));
+        assert!(!query.matches_metadata(&other));
+        assert!(query.matches_metadata(&bars));
+        assert!(!query.matches_metadata(&HashMap::new()));
+        assert!(DataQuery::new().matches_metadata(&HashMap::new()));
+    }
+}
@@ -13,24 +13,38 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::vec::IntoIter;
+use std::{cell::RefCell, fs::File, path::Path, rc::Rc, sync::Arc, vec::IntoIter};
 
 use compare::Compare;
-use datafusion::{error::Result, physical_plan::SendableRecordBatchStream, prelude::*};
-use futures::{executor::block_on, Stream, StreamExt};
-use nautilus_core::cvec::CVec;
-use nautilus_model::data::{
-    bar::Bar,
-    book::OrderBookDelta,
-    tick::{QuoteTick, TradeTick},
-    Data,
+use datafusion::{
+    arrow::datatypes::Schema, error::DataFusionError, parquet::file::footer::parse_metadata,
+    physical_plan::SendableRecordBatchStream, prelude::*,
 };
-use pyo3::{prelude::*, types::PyCapsule};
+use futures::{executor::block_on, future, Stream, StreamExt};
+use nautilus_core::{cvec::CVec, time::UnixNanos};
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        book::OrderBookDelta,
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    identifiers::instrument_id::InstrumentId,
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyCapsule};
 use pyo3_asyncio::tokio::get_runtime;
 
 use crate::{
+    catalog::{discover_files, CatalogDataType, CatalogFile},
+    custom::{CustomData, CustomDataRegistry, QueryData},
+    error::PersistenceError,
     kmerge_batch::{KMerge, PeekElementBatchStream},
-    parquet::{DecodeDataFromRecordBatch, ParquetType},
+    parquet::{
+        parse_instrument_id, DecodeCustomDataFromRecordBatch, DecodeDataFromRecordBatch,
+        ParquetType,
+    },
+    query::DataQuery,
+    validation::{DataValidator, ValidatedQueryResult, ValidationConfig, ValidationMode},
 };
 
 #[derive(Debug, Default)]
@@ -50,6 +64,24 @@ where
     }
 }
 
+impl<S> Compare<PeekElementBatchStream<S, QueryData>> for TsInitComparator
+where
+    S: Stream<Item = IntoIter<QueryData>>,
+{
+    fn compare(
+        &self,
+        l: &PeekElementBatchStream<S, QueryData>,
+        r: &PeekElementBatchStream<S, QueryData>,
+    ) -> std::cmp::Ordering {
+        // Max heap ordering must be reversed
+        l.item.get_ts_init().cmp(&r.item.get_ts_init()).reverse()
+    }
+}
+
+/// Holds the first error of the batch streams of a session, which end at their
+/// first error so the records before it can still be merged.
+type StreamError = Rc<RefCell<Option<PersistenceError>>>;
+
 /// Provides a DataFusion session and registers DataFusion queries.
 ///
 /// The session is used to register data sources and make queries on them. A
@@ -59,7 +91,11 @@ where
 pub struct DataBackendSession {
     session_ctx: SessionContext,
     batch_streams: Vec<Box<dyn Stream<Item = IntoIter<Data>> + Unpin>>,
+    custom_streams: Vec<Box<dyn Stream<Item = IntoIter<Arc<dyn CustomData>>> + Unpin>>,
+    custom_registry: CustomDataRegistry,
+    stream_error: StreamError,
     chunk_size: usize,
+    table_count: usize,
 }
 
 impl DataBackendSession {
@@ -68,7 +104,11 @@ impl DataBackendSession {
         Self {
             session_ctx: SessionContext::default(),
             batch_streams: Vec::default(),
+            custom_streams: Vec::default(),
+            custom_registry: CustomDataRegistry::default(),
+            stream_error: StreamError::default(),
             chunk_size,
+            table_count: 0,
         }
     }
 
@@ -78,16 +118,11 @@ impl DataBackendSession {
         &mut self,
         table_name: &str,
         file_path: &str,
-    ) -> Result<()>
+    ) -> Result<(), PersistenceError>
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
-        let parquet_options = ParquetReadOptions::<'_> {
-            skip_metadata: Some(false),
-            ..Default::default()
-        };
-        self.session_ctx
-            .register_parquet(table_name, file_path, parquet_options)
+        self.register_file(table_name, file_path, T::validate_schema)
             .await?;
 
         let batch_stream = self
@@ -112,16 +147,11 @@ impl DataBackendSession {
         table_name: &str,
         file_path: &str,
         sql_query: &str,
-    ) -> Result<()>
+    ) -> Result<(), PersistenceError>
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
-        let parquet_options = ParquetReadOptions::<'_> {
-            skip_metadata: Some(false),
-            ..Default::default()
-        };
-        self.session_ctx
-            .register_parquet(table_name, file_path, parquet_options)
+        self.register_file(table_name, file_path, T::validate_schema)
             .await?;
 
         let batch_stream = self
@@ -135,23 +165,234 @@ impl DataBackendSession {
         Ok(())
     }
 
+    // Query a file for the records matching `query`, ordered by `ts_init`. The
+    // caller must specify `T` to indicate the kind of data expected from this query.
+    //
+    // The time range is pushed down to the parquet scan so row groups outside it
+    // are not read. A file with no row group in the time range, or for an
+    // instrument not in the query, is skipped without being queried.
+    pub async fn add_file_with_data_query<T>(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        query: &DataQuery,
+    ) -> Result<(), PersistenceError>
+    where
+        T: DecodeDataFromRecordBatch + Into<Data>,
+    {
+        if let Some(batch_stream) = self
+            .execute_data_query(table_name, file_path, query, T::validate_schema)
+            .await?
+        {
+            self.add_batch_stream::<T>(batch_stream);
+        }
+        Ok(())
+    }
+
+    // Query a file of the custom data type registered under `type_name` for the
+    // records matching `query`, ordered by `ts_init`. The records are only
+    // returned by [`Self::get_merged_query_result`].
+    pub async fn add_custom_file_with_data_query(
+        &mut self,
+        type_name: &str,
+        table_name: &str,
+        file_path: &str,
+        query: &DataQuery,
+    ) -> Result<(), PersistenceError> {
+        let data_type = self
+            .custom_registry
+            .get(type_name)
+            .ok_or_else(|| PersistenceError::UnregisteredType(type_name.to_string()))?;
+
+        if let Some(batch_stream) = self
+            .execute_data_query(table_name, file_path, query, data_type.validate)
+            .await?
+        {
+            let transform = batch_stream.map(move |result| {
+                let batch = result?;
+                (data_type.decode)(batch.schema().metadata(), batch)
+            });
+            let stream = capture_error(transform, self.stream_error.clone());
+            self.custom_streams.push(Box::new(stream));
+        }
+        Ok(())
+    }
+
+    // Registers the custom data type `T`, so files of the type can be queried
+    // by its type name.
+    pub fn register_custom_type<T>(&mut self)
+    where
+        T: DecodeCustomDataFromRecordBatch,
+    {
+        self.custom_registry.register::<T>();
+    }
+
+    // Registers the parquet file as a table, validating its schema before any
+    // records are read.
+    async fn register_file(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        validate: fn(&Schema) -> Result<(), PersistenceError>,
+    ) -> Result<DataFrame, PersistenceError> {
+        let parquet_options = ParquetReadOptions::<'_> {
+            skip_metadata: Some(false),
+            ..Default::default()
+        };
+        self.session_ctx
+            .register_parquet(table_name, file_path, parquet_options)
+            .await?;
+
+        let df = self.session_ctx.table(table_name).await?;
+        validate(&Schema::from(df.schema()))?;
+        Ok(df)
+    }
+
+    async fn execute_data_query(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        query: &DataQuery,
+        validate: fn(&Schema) -> Result<(), PersistenceError>,
+    ) -> Result<Option<SendableRecordBatchStream>, PersistenceError> {
+        // The footer is only read for a single file with a time range, a directory
+        // is registered as is and pruned by row group when executed
+        if query.ts_init_filter().is_some() && Path::new(file_path).is_file() {
+            let file = File::open(file_path)?;
+            let metadata = parse_metadata(&file).map_err(DataFusionError::from)?;
+            if !query.matches_row_groups(&metadata) {
+                return Ok(None);
+            }
+        }
+
+        let df = self.register_file(table_name, file_path, validate).await?;
+        if !query.matches_metadata(df.schema().metadata()) {
+            return Ok(None);
+        }
+
+        let batch_stream = query.apply(df)?.execute_stream().await?;
+        Ok(Some(batch_stream))
+    }
+
+    // Query a file of `parquet_type` for the records matching `query`, decoding
+    // them with the type registered for `parquet_type`.
+    pub async fn add_file_of_type(
+        &mut self,
+        parquet_type: ParquetType,
+        table_name: &str,
+        file_path: &str,
+        query: &DataQuery,
+    ) -> Result<(), PersistenceError> {
+        match parquet_type {
+            ParquetType::OrderBookDelta => {
+                self.add_file_with_data_query::<OrderBookDelta>(table_name, file_path, query)
+                    .await
+            }
+            ParquetType::QuoteTick => {
+                self.add_file_with_data_query::<QuoteTick>(table_name, file_path, query)
+                    .await
+            }
+            ParquetType::TradeTick => {
+                self.add_file_with_data_query::<TradeTick>(table_name, file_path, query)
+                    .await
+            }
+            ParquetType::Bar => {
+                self.add_file_with_data_query::<Bar>(table_name, file_path, query)
+                    .await
+            }
+        }
+    }
+
+    // Query the catalog directory at `catalog_path` for the records matching
+    // `query`, optionally only for files of `parquet_type`. Returns the number of
+    // files queried.
+    //
+    // Files are discovered from the catalog layout and decoded by the type of
+    // their data directory. Files outside the instruments or time range of the
+    // query are skipped based on their partition and file name. Without a
+    // `parquet_type`, files of the registered custom data types are also queried,
+    // and are only returned by [`Self::get_merged_query_result`].
+    pub async fn add_catalog_query(
+        &mut self,
+        catalog_path: &str,
+        parquet_type: Option<ParquetType>,
+        query: &DataQuery,
+    ) -> Result<usize, PersistenceError> {
+        let custom_types: Vec<&str> = self.custom_registry.type_names().collect();
+        let files: Vec<CatalogFile> = discover_files(Path::new(catalog_path), &custom_types)?
+            .into_iter()
+            .filter(|file| {
+                let matches_type = match (&file.data_type, parquet_type) {
+                    (_, None) => true,
+                    (CatalogDataType::Data(file_type), Some(parquet_type)) => {
+                        *file_type == parquet_type
+                    }
+                    (CatalogDataType::Custom(_), Some(_)) => false,
+                };
+                matches_type && file.matches_query(query)
+            })
+            .collect();
+
+        for file in &files {
+            // Each file is registered as its own table, so names must be unique
+            // across the session
+            let table_name = format!("catalog_{}", self.table_count);
+            self.table_count += 1;
+            let file_path = file.path.to_string_lossy();
+            match &file.data_type {
+                CatalogDataType::Data(parquet_type) => {
+                    self.add_file_of_type(*parquet_type, &table_name, &file_path, query)
+                        .await?;
+                }
+                CatalogDataType::Custom(type_name) => {
+                    self.add_custom_file_with_data_query(type_name, &table_name, &file_path, query)
+                        .await?;
+                }
+            }
+        }
+        Ok(files.len())
+    }
+
     fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
-        let transform = stream.map(|result| match result {
-            Ok(batch) => T::decode_batch(batch.schema().metadata(), batch).into_iter(),
-            Err(_err) => panic!("Error getting next batch from RecordBatchStream"),
+        // The file schema was validated when registered, so a batch only fails to
+        // decode on invalid values
+        let transform = stream.map(|result| {
+            let batch = result?;
+            T::decode_batch(batch.schema().metadata(), batch)
         });
 
-        self.batch_streams.push(Box::new(transform));
+        let stream = capture_error(transform, self.stream_error.clone());
+        self.batch_streams.push(Box::new(stream));
     }
 
     // Consumes the registered queries and returns a [QueryResult].
     // Passes the output of the query though the a KMerge which sorts the
     // queries in ascending order of `ts_init`.
-    // QueryResult is an iterator that return Vec<Data>.
+    // QueryResult is an iterator that return Vec<Data>, or the first error
+    // reading or decoding a record batch.
+    //
+    // # Panics
+    //
+    // If queries for custom data are registered, which are only returned by
+    // `get_merged_query_result`.
     pub fn get_query_result(&mut self) -> QueryResult<Data> {
+        self.try_get_query_result()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    // Consumes the registered queries and returns a [QueryResult], or an error if
+    // queries for custom data are registered, as they are only returned by
+    // `get_merged_query_result`.
+    pub fn try_get_query_result(&mut self) -> Result<QueryResult<Data>, PersistenceError> {
+        if !self.custom_streams.is_empty() {
+            return Err(PersistenceError::UnmergedCustomData(
+                self.custom_streams.len(),
+            ));
+        }
+
         // TODO: No need to kmerge if there is only one batch stream
         let mut kmerge: KMerge<_, _, _> = KMerge::new(TsInitComparator);
 
@@ -159,21 +400,111 @@ impl DataBackendSession {
             block_on(kmerge.push_stream(batch_stream));
         });
 
-        QueryResult {
-            data: Box::new(kmerge.chunks(self.chunk_size)),
-        }
+        Ok(QueryResult::new(
+            Box::new(kmerge.chunks(self.chunk_size)),
+            std::mem::take(&mut self.stream_error),
+        ))
     }
+
+    // Consumes the registered queries, including the queries for custom data, and
+    // returns a [QueryResult] which merges all of them in ascending order of
+    // `ts_init`.
+    pub fn get_merged_query_result(&mut self) -> QueryResult<QueryData> {
+        let mut kmerge: KMerge<_, _, _> = KMerge::new(TsInitComparator);
+
+        let data_streams = self.batch_streams.drain(..).map(|batch_stream| {
+            let transform = batch_stream.map(|batch| {
+                batch
+                    .map(QueryData::Data)
+                    .collect::<Vec<QueryData>>()
+                    .into_iter()
+            });
+            Box::new(transform) as Box<dyn Stream<Item = IntoIter<QueryData>> + Unpin>
+        });
+        let custom_streams = self.custom_streams.drain(..).map(|batch_stream| {
+            let transform = batch_stream.map(|batch| {
+                batch
+                    .map(QueryData::Custom)
+                    .collect::<Vec<QueryData>>()
+                    .into_iter()
+            });
+            Box::new(transform) as Box<dyn Stream<Item = IntoIter<QueryData>> + Unpin>
+        });
+
+        Iterator::for_each(data_streams.chain(custom_streams), |batch_stream| {
+            block_on(kmerge.push_stream(batch_stream));
+        });
+
+        QueryResult::new(
+            Box::new(kmerge.chunks(self.chunk_size)),
+            std::mem::take(&mut self.stream_error),
+        )
+    }
+}
+
+// Returns the stream of the records of each decoded batch, which ends at the
+// first error after storing it in `stream_error`.
+fn capture_error<S, T>(
+    stream: S,
+    stream_error: StreamError,
+) -> impl Stream<Item = IntoIter<T>> + Unpin
+where
+    S: Stream<Item = Result<Vec<T>, PersistenceError>> + Unpin,
+{
+    stream.scan(stream_error, |stream_error, result| {
+        future::ready(match result {
+            Ok(values) => Some(values.into_iter()),
+            Err(err) => {
+                stream_error.borrow_mut().get_or_insert(err);
+                None
+            }
+        })
+    })
 }
 
+/// Provides an iterator over the chunks of records merged from the queries of
+/// a session, in ascending order of `ts_init`.
+///
+/// If a record batch cannot be read or decoded, the iterator discards the chunk
+/// being merged, returns the error and then ends.
 pub struct QueryResult<T = Data> {
     data: Box<dyn Stream<Item = Vec<T>> + Unpin>,
+    stream_error: StreamError,
+    failed: bool,
 }
 
-impl Iterator for QueryResult {
-    type Item = Vec<Data>;
+impl<T> QueryResult<T> {
+    fn new(data: Box<dyn Stream<Item = Vec<T>> + Unpin>, stream_error: StreamError) -> Self {
+        Self {
+            data,
+            stream_error,
+            failed: false,
+        }
+    }
+}
+
+impl QueryResult<Data> {
+    /// Returns an iterator which runs data quality checks over each chunk
+    /// before returning it.
+    #[must_use]
+    pub fn validated(self, config: ValidationConfig) -> ValidatedQueryResult {
+        ValidatedQueryResult::new(self, config)
+    }
+}
+
+impl<T> Iterator for QueryResult<T> {
+    type Item = Result<Vec<T>, PersistenceError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        block_on(self.data.next())
+        if self.failed {
+            return None;
+        }
+        let chunk = block_on(self.data.next());
+        if let Some(err) = self.stream_error.borrow_mut().take() {
+            self.failed = true;
+            return Some(Err(err));
+        }
+        chunk.map(Ok)
     }
 }
 
@@ -200,37 +531,23 @@ impl DataBackendSession {
         table_name: &str,
         file_path: &str,
         parquet_type: ParquetType,
-    ) {
+    ) -> PyResult<()> {
         let rt = get_runtime();
         let _guard = rt.enter();
 
-        match parquet_type {
+        let result = match parquet_type {
             ParquetType::OrderBookDelta => {
-                match block_on(slf.add_file_default_query::<OrderBookDelta>(table_name, file_path))
-                {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
+                block_on(slf.add_file_default_query::<OrderBookDelta>(table_name, file_path))
             }
             ParquetType::QuoteTick => {
-                match block_on(slf.add_file_default_query::<QuoteTick>(table_name, file_path)) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
+                block_on(slf.add_file_default_query::<QuoteTick>(table_name, file_path))
             }
             ParquetType::TradeTick => {
-                match block_on(slf.add_file_default_query::<TradeTick>(table_name, file_path)) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
-            }
-            ParquetType::Bar => {
-                match block_on(slf.add_file_default_query::<Bar>(table_name, file_path)) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
+                block_on(slf.add_file_default_query::<TradeTick>(table_name, file_path))
             }
-        }
+            ParquetType::Bar => block_on(slf.add_file_default_query::<Bar>(table_name, file_path)),
+        };
+        Ok(result?)
     }
 
     pub fn add_file_with_query(
@@ -239,61 +556,153 @@ impl DataBackendSession {
         file_path: &str,
         sql_query: &str,
         parquet_type: ParquetType,
-    ) {
+    ) -> PyResult<()> {
         let rt = get_runtime();
         let _guard = rt.enter();
 
-        match parquet_type {
-            ParquetType::OrderBookDelta => {
-                match block_on(
-                    slf.add_file_with_custom_query::<OrderBookDelta>(
-                        table_name, file_path, sql_query,
-                    ),
-                ) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
-            }
-            ParquetType::QuoteTick => {
-                match block_on(
-                    slf.add_file_with_custom_query::<QuoteTick>(table_name, file_path, sql_query),
-                ) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
-            }
-            ParquetType::TradeTick => {
-                match block_on(
-                    slf.add_file_with_custom_query::<TradeTick>(table_name, file_path, sql_query),
-                ) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
-            }
+        let result = match parquet_type {
+            ParquetType::OrderBookDelta => block_on(
+                slf.add_file_with_custom_query::<OrderBookDelta>(table_name, file_path, sql_query),
+            ),
+            ParquetType::QuoteTick => block_on(
+                slf.add_file_with_custom_query::<QuoteTick>(table_name, file_path, sql_query),
+            ),
+            ParquetType::TradeTick => block_on(
+                slf.add_file_with_custom_query::<TradeTick>(table_name, file_path, sql_query),
+            ),
             ParquetType::Bar => {
-                match block_on(
-                    slf.add_file_with_custom_query::<Bar>(table_name, file_path, sql_query),
-                ) {
-                    Ok(_) => (),
-                    Err(err) => panic!("Failed new_query with error {err}"),
-                }
+                block_on(slf.add_file_with_custom_query::<Bar>(table_name, file_path, sql_query))
             }
-        }
+        };
+        Ok(result?)
+    }
+
+    #[pyo3(signature=(table_name, file_path, parquet_type, start=None, end=None, instrument_ids=None))]
+    pub fn add_file_filtered(
+        mut slf: PyRefMut<'_, Self>,
+        table_name: &str,
+        file_path: &str,
+        parquet_type: ParquetType,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        instrument_ids: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        let rt = get_runtime();
+        let _guard = rt.enter();
+
+        let query = DataQuery {
+            start,
+            end,
+            instrument_ids: parse_instrument_ids(instrument_ids)?,
+        };
+        Ok(block_on(slf.add_file_of_type(
+            parquet_type,
+            table_name,
+            file_path,
+            &query,
+        ))?)
+    }
+
+    #[pyo3(signature=(catalog_path, parquet_type=None, start=None, end=None, instrument_ids=None))]
+    pub fn add_catalog(
+        mut slf: PyRefMut<'_, Self>,
+        catalog_path: &str,
+        parquet_type: Option<ParquetType>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        instrument_ids: Option<Vec<String>>,
+    ) -> PyResult<usize> {
+        let rt = get_runtime();
+        let _guard = rt.enter();
+
+        let query = DataQuery {
+            start,
+            end,
+            instrument_ids: parse_instrument_ids(instrument_ids)?,
+        };
+        Ok(block_on(slf.add_catalog_query(
+            catalog_path,
+            parquet_type,
+            &query,
+        ))?)
+    }
+
+    #[pyo3(signature=(type_name, table_name, file_path, start=None, end=None, instrument_ids=None))]
+    pub fn add_custom_file(
+        mut slf: PyRefMut<'_, Self>,
+        type_name: &str,
+        table_name: &str,
+        file_path: &str,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        instrument_ids: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        let rt = get_runtime();
+        let _guard = rt.enter();
+
+        let query = DataQuery {
+            start,
+            end,
+            instrument_ids: parse_instrument_ids(instrument_ids)?,
+        };
+        Ok(block_on(slf.add_custom_file_with_data_query(
+            type_name, table_name, file_path, &query,
+        ))?)
+    }
+
+    #[pyo3(signature=(validation_mode=None, max_gap=None, trade_id_window=None))]
+    pub fn to_query_result(
+        mut slf: PyRefMut<'_, Self>,
+        validation_mode: Option<ValidationMode>,
+        max_gap: Option<u64>,
+        trade_id_window: Option<usize>,
+    ) -> PyResult<DataQueryResult> {
+        let rt = get_runtime();
+        let _guard = rt.enter();
+
+        let query_result = slf.try_get_query_result()?;
+        let validator = validation_mode.map(|mode| {
+            let default = ValidationConfig::default();
+            DataValidator::new(ValidationConfig {
+                mode,
+                max_gap,
+                trade_id_window: trade_id_window.unwrap_or(default.trade_id_window),
+                ..default
+            })
+        });
+        Ok(DataQueryResult::new(query_result, validator))
     }
 
     #[must_use]
-    pub fn to_query_result(mut slf: PyRefMut<'_, Self>) -> DataQueryResult {
+    pub fn to_merged_query_result(mut slf: PyRefMut<'_, Self>) -> MergedQueryResult {
         let rt = get_runtime();
         let _guard = rt.enter();
 
-        let query_result = slf.get_query_result();
-        DataQueryResult::new(query_result)
+        MergedQueryResult::new(slf.get_merged_query_result())
     }
 }
 
+fn parse_instrument_ids(
+    instrument_ids: Option<Vec<String>>,
+) -> PyResult<Option<Vec<InstrumentId>>> {
+    instrument_ids
+        .map(|instrument_ids| {
+            instrument_ids
+                .iter()
+                .map(|instrument_id| {
+                    parse_instrument_id(instrument_id).ok_or_else(|| {
+                        PyValueError::new_err(format!("Invalid instrument ID '{instrument_id}'"))
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
 #[pyclass]
 pub struct DataQueryResult {
     result: QueryResult<Data>,
+    validator: Option<DataValidator>,
     chunk: Option<CVec>,
 }
 
@@ -305,16 +714,35 @@ impl DataQueryResult {
     }
 
     /// Each iteration returns a chunk of values read from the parquet file.
-    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<PyObject> {
+    /// In strict validation mode, raises `ValueError` for the first invalid chunk.
+    /// Raises the mapped error if a record batch cannot be read or decoded.
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
         slf.drop_chunk();
 
         let rt = get_runtime();
         let _guard = rt.enter();
 
-        slf.result.next().map(|chunk| {
-            let cvec = chunk.into();
-            Python::with_gil(|py| PyCapsule::new::<CVec>(py, cvec, None).unwrap().into_py(py))
-        })
+        match slf.result.next() {
+            Some(chunk) => {
+                let chunk = chunk?;
+                if let Some(validator) = slf.validator.as_mut() {
+                    validator.validate_chunk(&chunk)?;
+                }
+                let cvec = chunk.into();
+                Ok(Some(Python::with_gil(|py| {
+                    PyCapsule::new::<CVec>(py, cvec, None).unwrap().into_py(py)
+                })))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the summary of the data quality checks run over the chunks
+    /// returned so far, if the query result is validated.
+    fn validation_report(&self) -> Option<String> {
+        self.validator
+            .as_ref()
+            .map(|validator| validator.report().to_string())
     }
 }
 
@@ -322,9 +750,10 @@ impl DataQueryResult {
 unsafe impl Send for DataQueryResult {}
 
 impl DataQueryResult {
-    fn new(result: QueryResult<Data>) -> Self {
+    fn new(result: QueryResult<Data>, validator: Option<DataValidator>) -> Self {
         Self {
             result,
+            validator,
             chunk: None,
         }
     }
@@ -332,10 +761,8 @@ impl DataQueryResult {
     /// it will leak memory. Current chunk is held by the reader,
     /// drop if exists and reset the field.
     fn drop_chunk(&mut self) {
-        if let Some(CVec { ptr, len, cap }) = self.chunk.take() {
-            let data: Vec<Data> =
-                unsafe { Vec::from_raw_parts(ptr.cast::<nautilus_model::data::Data>(), len, cap) };
-            drop(data);
+        if let Some(chunk) = self.chunk.take() {
+            drop_data_chunk(chunk);
         }
     }
 }
@@ -345,3 +772,81 @@ impl Drop for DataQueryResult {
         self.drop_chunk();
     }
 }
+
+fn drop_data_chunk(chunk: CVec) {
+    let CVec { ptr, len, cap } = chunk;
+    let data: Vec<Data> =
+        unsafe { Vec::from_raw_parts(ptr.cast::<nautilus_model::data::Data>(), len, cap) };
+    drop(data);
+}
+
+#[pyclass]
+pub struct MergedQueryResult {
+    result: QueryResult<QueryData>,
+    chunk: Option<CVec>,
+}
+
+#[pymethods]
+impl MergedQueryResult {
+    /// The reader implements an iterator.
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Each iteration returns a chunk of values as a tuple of a capsule of the
+    /// built-in data and a list of the custom data, each in ascending order of
+    /// `ts_init` and both within the time range of the chunk.
+    /// Raises the mapped error if a record batch cannot be read or decoded.
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<(PyObject, PyObject)>> {
+        slf.drop_chunk();
+
+        let rt = get_runtime();
+        let _guard = rt.enter();
+
+        let chunk = match slf.result.next() {
+            Some(chunk) => chunk?,
+            None => return Ok(None),
+        };
+        let mut data = Vec::new();
+        let mut custom = Vec::new();
+        for item in chunk {
+            match item {
+                QueryData::Data(value) => data.push(value),
+                QueryData::Custom(value) => custom.push(value),
+            }
+        }
+        let cvec: CVec = data.into();
+        slf.chunk = Some(cvec);
+        Ok(Some(Python::with_gil(|py| {
+            let capsule = PyCapsule::new::<CVec>(py, cvec, None).unwrap().into_py(py);
+            let custom: Vec<PyObject> = custom.iter().map(|value| value.to_py(py)).collect();
+            (capsule, custom.into_py(py))
+        })))
+    }
+}
+
+// Note: Intended to be used on a single python thread
+unsafe impl Send for MergedQueryResult {}
+
+impl MergedQueryResult {
+    fn new(result: QueryResult<QueryData>) -> Self {
+        Self {
+            result,
+            chunk: None,
+        }
+    }
+
+    /// The built-in data of the current chunk is held by the reader until the
+    /// next iteration, as with [`DataQueryResult`].
+    fn drop_chunk(&mut self) {
+        if let Some(chunk) = self.chunk.take() {
+            drop_data_chunk(chunk);
+        }
+    }
+}
+
+impl Drop for MergedQueryResult {
+    fn drop(&mut self) {
+        self.drop_chunk();
+    }
+}
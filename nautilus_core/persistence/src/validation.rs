@@ -0,0 +1,638 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt::{Display, Formatter},
+};
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::BarType,
+        book::OrderBookDelta,
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    enums::BookAction,
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+use pyo3::prelude::*;
+
+use crate::{error::PersistenceError, session::QueryResult};
+
+/// How a [`DataValidator`] handles data which fails a check.
+#[pyclass]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Fails on the first issue found.
+    Strict,
+    /// Records every issue in the report and passes the data through.
+    #[default]
+    Warn,
+}
+
+/// Configures the checks run by a [`DataValidator`].
+#[derive(Clone, Debug)]
+pub struct ValidationConfig {
+    pub mode: ValidationMode,
+    /// The largest expected interval (nanoseconds) between the `ts_event` of
+    /// consecutive records of an instrument, if time gaps should be flagged.
+    pub max_gap: Option<u64>,
+    /// The maximum number of issues kept in the report, all issues are counted.
+    pub max_issues: usize,
+    /// The number of the most recent trade IDs of an instrument which a trade is
+    /// checked against for duplicates.
+    pub trade_id_window: usize,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            mode: ValidationMode::Warn,
+            max_gap: None,
+            max_issues: 100,
+            trade_id_window: 10_000,
+        }
+    }
+}
+
+/// Represents a data quality check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationCheck {
+    NonMonotonicTsInit,
+    NonMonotonicTsEvent,
+    CrossedQuote,
+    NonPositivePrice,
+    NonPositiveSize,
+    DuplicateTradeId,
+    TimeGap,
+}
+
+impl Display for ValidationCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::NonMonotonicTsInit => "non-monotonic ts_init",
+            Self::NonMonotonicTsEvent => "non-monotonic ts_event",
+            Self::CrossedQuote => "crossed quote",
+            Self::NonPositivePrice => "non-positive price",
+            Self::NonPositiveSize => "non-positive size",
+            Self::DuplicateTradeId => "duplicate trade ID",
+            Self::TimeGap => "time gap",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Represents a record which failed a data quality check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub check: ValidationCheck,
+    /// The position of the record in the validated stream.
+    pub index: usize,
+    pub ts_init: UnixNanos,
+    pub message: String,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at record {} (ts_init={}): {}",
+            self.check, self.index, self.ts_init, self.message
+        )
+    }
+}
+
+/// Provides a summary of the data quality checks run over a stream.
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    pub records: usize,
+    pub counts: BTreeMap<ValidationCheck, usize>,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns the total number of issues found.
+    #[must_use]
+    pub fn issue_count(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns the number of issues found by the `check`.
+    #[must_use]
+    pub fn count(&self, check: ValidationCheck) -> usize {
+        self.counts.get(&check).copied().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.counts.is_empty()
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Validated {} record(s), found {} issue(s)",
+            self.records,
+            self.issue_count()
+        )?;
+        for (check, count) in &self.counts {
+            write!(f, "\n  {check}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+// The records whose `ts_event` ordering and gaps are checked together.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Series {
+    Delta(InstrumentId),
+    Quote(InstrumentId),
+    Trade(InstrumentId),
+    Bar(BarType),
+}
+
+// The most recent trade IDs of an instrument, in the order of their records.
+#[derive(Default)]
+struct TradeIdWindow {
+    ids: HashSet<TradeId>,
+    order: VecDeque<TradeId>,
+}
+
+impl TradeIdWindow {
+    // Adds the `trade_id`, evicting the oldest ID beyond `capacity`, and returns
+    // whether it was not already in the window.
+    fn insert(&mut self, trade_id: &TradeId, capacity: usize) -> bool {
+        let is_new = self.ids.insert(trade_id.clone());
+        if is_new {
+            self.order.push_back(trade_id.clone());
+            if self.order.len() > capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.ids.remove(&oldest);
+                }
+            }
+        }
+        is_new
+    }
+}
+
+/// Runs data quality checks over data in stream order, accumulating a
+/// [`ValidationReport`].
+pub struct DataValidator {
+    config: ValidationConfig,
+    report: ValidationReport,
+    last_ts_init: Option<UnixNanos>,
+    last_ts_event: HashMap<Series, UnixNanos>,
+    trade_ids: HashMap<InstrumentId, TradeIdWindow>,
+}
+
+impl DataValidator {
+    #[must_use]
+    pub fn new(config: ValidationConfig) -> Self {
+        Self {
+            config,
+            report: ValidationReport::default(),
+            last_ts_init: None,
+            last_ts_event: HashMap::new(),
+            trade_ids: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn report(&self) -> &ValidationReport {
+        &self.report
+    }
+
+    #[must_use]
+    pub fn into_report(self) -> ValidationReport {
+        self.report
+    }
+
+    /// Runs the checks over the `chunk`, following the records already validated.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, if a record fails a check.
+    pub fn validate_chunk(&mut self, chunk: &[Data]) -> Result<(), PersistenceError> {
+        chunk.iter().try_for_each(|data| self.validate(data))
+    }
+
+    /// Runs the checks over the `data`, following the records already validated.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, if the record fails a check.
+    pub fn validate(&mut self, data: &Data) -> Result<(), PersistenceError> {
+        let mut issues = Vec::new();
+        let ts_init = data.get_ts_init();
+
+        if let Some(last) = self.last_ts_init.filter(|&last| ts_init < last) {
+            issues.push((
+                ValidationCheck::NonMonotonicTsInit,
+                format!("{ts_init} follows {last}"),
+            ));
+        }
+        self.last_ts_init = Some(ts_init);
+
+        let (series, ts_event) = match data {
+            Data::Delta(delta) => {
+                check_delta(delta, &mut issues);
+                (Series::Delta(delta.instrument_id.clone()), delta.ts_event)
+            }
+            Data::Quote(quote) => {
+                check_quote(quote, &mut issues);
+                (Series::Quote(quote.instrument_id.clone()), quote.ts_event)
+            }
+            Data::Trade(trade) => {
+                self.check_trade(trade, &mut issues);
+                (Series::Trade(trade.instrument_id.clone()), trade.ts_event)
+            }
+            Data::Bar(bar) => {
+                for price in [&bar.open, &bar.high, &bar.low, &bar.close] {
+                    check_price(price, &mut issues);
+                }
+                (Series::Bar(bar.bar_type.clone()), bar.ts_event)
+            }
+        };
+        self.check_ts_event(series, ts_event, &mut issues);
+
+        let index = self.report.records;
+        self.report.records += 1;
+        for (check, message) in issues {
+            let issue = ValidationIssue {
+                check,
+                index,
+                ts_init,
+                message,
+            };
+            if self.config.mode == ValidationMode::Strict {
+                return Err(PersistenceError::Validation(issue));
+            }
+            *self.report.counts.entry(check).or_default() += 1;
+            if self.report.issues.len() < self.config.max_issues {
+                self.report.issues.push(issue);
+            }
+        }
+        Ok(())
+    }
+
+    fn check_trade(&mut self, trade: &TradeTick, issues: &mut Vec<(ValidationCheck, String)>) {
+        check_price(&trade.price, issues);
+        check_size(&trade.size, issues);
+
+        let is_new = self
+            .trade_ids
+            .entry(trade.instrument_id.clone())
+            .or_default()
+            .insert(&trade.trade_id, self.config.trade_id_window);
+        if !is_new {
+            issues.push((
+                ValidationCheck::DuplicateTradeId,
+                format!("{} for {}", trade.trade_id, trade.instrument_id),
+            ));
+        }
+    }
+
+    fn check_ts_event(
+        &mut self,
+        series: Series,
+        ts_event: UnixNanos,
+        issues: &mut Vec<(ValidationCheck, String)>,
+    ) {
+        if let Some(last) = self.last_ts_event.insert(series, ts_event) {
+            if ts_event < last {
+                issues.push((
+                    ValidationCheck::NonMonotonicTsEvent,
+                    format!("{ts_event} follows {last}"),
+                ));
+            } else if let Some(max_gap) = self.config.max_gap {
+                let gap = ts_event - last;
+                if gap > max_gap {
+                    issues.push((
+                        ValidationCheck::TimeGap,
+                        format!("{gap}ns since the previous record, exceeds {max_gap}ns"),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn check_delta(delta: &OrderBookDelta, issues: &mut Vec<(ValidationCheck, String)>) {
+    // Deletes and clears carry no meaningful price or size
+    if matches!(delta.action, BookAction::Add | BookAction::Update) {
+        check_price(&delta.order.price, issues);
+        check_size(&delta.order.size, issues);
+    }
+}
+
+fn check_quote(quote: &QuoteTick, issues: &mut Vec<(ValidationCheck, String)>) {
+    check_price(&quote.bid, issues);
+    check_price(&quote.ask, issues);
+    check_size(&quote.bid_size, issues);
+    check_size(&quote.ask_size, issues);
+
+    if quote.bid > quote.ask {
+        issues.push((
+            ValidationCheck::CrossedQuote,
+            format!("bid {} > ask {}", quote.bid, quote.ask),
+        ));
+    }
+}
+
+fn check_price(price: &Price, issues: &mut Vec<(ValidationCheck, String)>) {
+    if price.raw <= 0 {
+        issues.push((ValidationCheck::NonPositivePrice, format!("price {price}")));
+    }
+}
+
+fn check_size(size: &Quantity, issues: &mut Vec<(ValidationCheck, String)>) {
+    if size.is_zero() {
+        issues.push((ValidationCheck::NonPositiveSize, format!("size {size}")));
+    }
+}
+
+/// Provides an iterator over the chunks of a [`QueryResult`] which validates
+/// each chunk before returning it.
+///
+/// In strict mode the iterator returns the error for the first invalid chunk
+/// and then ends, as it does for an error reading the chunk.
+pub struct ValidatedQueryResult {
+    result: QueryResult<Data>,
+    validator: DataValidator,
+    failed: bool,
+}
+
+impl ValidatedQueryResult {
+    #[must_use]
+    pub fn new(result: QueryResult<Data>, config: ValidationConfig) -> Self {
+        Self {
+            result,
+            validator: DataValidator::new(config),
+            failed: false,
+        }
+    }
+
+    /// Returns the report for the chunks returned so far.
+    #[must_use]
+    pub fn report(&self) -> &ValidationReport {
+        self.validator.report()
+    }
+
+    /// Validates the remaining chunks and returns the report for the whole stream.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, if a record fails a check, or if a record batch cannot be
+    /// read or decoded.
+    pub fn into_report(mut self) -> Result<ValidationReport, PersistenceError> {
+        for chunk in self.by_ref() {
+            chunk?;
+        }
+        Ok(self.validator.into_report())
+    }
+}
+
+impl Iterator for ValidatedQueryResult {
+    type Item = Result<Vec<Data>, PersistenceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self.result.next()?.and_then(|chunk| {
+            self.validator.validate_chunk(&chunk)?;
+            Ok(chunk)
+        });
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        data::book::BookOrder,
+        enums::{AggressorSide, OrderSide},
+    };
+
+    use super::*;
+
+    fn quote(bid: i64, ask: i64, ts_event: UnixNanos, ts_init: UnixNanos) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from_str("EUR/USD.SIM").unwrap(),
+            bid: Price::from_raw(bid, 5),
+            ask: Price::from_raw(ask, 5),
+            bid_size: Quantity::from_raw(100_000, 0),
+            ask_size: Quantity::from_raw(100_000, 0),
+            ts_event,
+            ts_init,
+        })
+    }
+
+    fn trade(trade_id: &str, size: u64, ts_init: UnixNanos) -> Data {
+        Data::Trade(TradeTick {
+            instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+            price: Price::from_raw(10_000, 2),
+            size: Quantity::from_raw(size, 0),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new(trade_id),
+            ts_event: ts_init,
+            ts_init,
+        })
+    }
+
+    fn validate(data: &[Data], config: ValidationConfig) -> ValidationReport {
+        let mut validator = DataValidator::new(config);
+        validator.validate_chunk(data).unwrap();
+        validator.into_report()
+    }
+
+    #[test]
+    fn test_valid_data() {
+        let data = vec![
+            quote(100_000, 100_010, 1, 2),
+            trade("1", 100, 3),
+            quote(100_005, 100_005, 4, 5),
+            trade("2", 100, 6),
+        ];
+
+        let report = validate(&data, ValidationConfig::default());
+
+        assert!(report.is_valid());
+        assert_eq!(report.records, 4);
+        assert_eq!(report.issue_count(), 0);
+    }
+
+    #[test]
+    fn test_non_monotonic_timestamps() {
+        let data = vec![quote(100_000, 100_010, 5, 5), quote(100_000, 100_010, 3, 4)];
+
+        let report = validate(&data, ValidationConfig::default());
+
+        assert_eq!(report.count(ValidationCheck::NonMonotonicTsInit), 1);
+        assert_eq!(report.count(ValidationCheck::NonMonotonicTsEvent), 1);
+        assert_eq!(report.issues[0].index, 1);
+    }
+
+    #[test]
+    fn test_ts_event_checked_per_instrument() {
+        let data = vec![quote(100_000, 100_010, 5, 5), trade("1", 100, 6)];
+
+        let report = validate(&data, ValidationConfig::default());
+
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_invalid_prices_and_sizes() {
+        let data = vec![
+            quote(100_010, 100_000, 1, 1),
+            quote(0, 100_000, 2, 2),
+            trade("1", 0, 3),
+            Data::Delta(OrderBookDelta {
+                instrument_id: InstrumentId::from_str("AAPL.NASDAQ").unwrap(),
+                action: BookAction::Clear,
+                order: BookOrder {
+                    side: OrderSide::NoOrderSide,
+                    price: Price::from_raw(0, 2),
+                    size: Quantity::from_raw(0, 0),
+                    order_id: 0,
+                },
+                flags: 0,
+                sequence: 1,
+                ts_event: 4,
+                ts_init: 4,
+            }),
+        ];
+
+        let report = validate(&data, ValidationConfig::default());
+
+        assert_eq!(report.count(ValidationCheck::CrossedQuote), 1);
+        assert_eq!(report.count(ValidationCheck::NonPositivePrice), 1);
+        assert_eq!(report.count(ValidationCheck::NonPositiveSize), 1);
+        assert_eq!(report.issue_count(), 3);
+    }
+
+    #[test]
+    fn test_duplicate_trade_ids() {
+        let data = vec![trade("1", 100, 1), trade("2", 100, 2), trade("1", 100, 3)];
+
+        let report = validate(&data, ValidationConfig::default());
+
+        assert_eq!(report.count(ValidationCheck::DuplicateTradeId), 1);
+        assert_eq!(report.issues[0].index, 2);
+    }
+
+    #[test]
+    fn test_duplicate_trade_ids_within_window() {
+        let data = vec![
+            trade("1", 100, 1),
+            trade("2", 100, 2),
+            trade("3", 100, 3),
+            trade("1", 100, 4), // Evicted from the window by trade 3
+            trade("3", 100, 5),
+        ];
+        let config = ValidationConfig {
+            trade_id_window: 2,
+            ..Default::default()
+        };
+
+        let report = validate(&data, config);
+
+        assert_eq!(report.count(ValidationCheck::DuplicateTradeId), 1);
+        assert_eq!(report.issues[0].index, 4);
+    }
+
+    #[test]
+    fn test_time_gaps() {
+        let data = vec![
+            quote(100_000, 100_010, 10, 10),
+            quote(100_000, 100_010, 20, 20),
+            quote(100_000, 100_010, 100, 100),
+        ];
+        let config = ValidationConfig {
+            max_gap: Some(50),
+            ..Default::default()
+        };
+
+        let report = validate(&data, config);
+
+        assert_eq!(report.count(ValidationCheck::TimeGap), 1);
+        assert_eq!(report.issues[0].ts_init, 100);
+    }
+
+    #[test]
+    fn test_max_issues_limits_kept_issues() {
+        let data = vec![trade("1", 0, 1), trade("2", 0, 2), trade("3", 0, 3)];
+        let config = ValidationConfig {
+            max_issues: 2,
+            ..Default::default()
+        };
+
+        let report = validate(&data, config);
+
+        assert_eq!(report.count(ValidationCheck::NonPositiveSize), 3);
+        assert_eq!(report.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_strict_mode_fails_on_first_issue() {
+        let data = vec![
+            quote(100_000, 100_010, 1, 1),
+            quote(100_010, 100_000, 2, 2),
+            quote(0, 100_000, 3, 3),
+        ];
+        let mut validator = DataValidator::new(ValidationConfig {
+            mode: ValidationMode::Strict,
+            ..Default::default()
+        });
+
+        let result = validator.validate_chunk(&data);
+
+        assert!(matches!(
+            result,
+            Err(PersistenceError::Validation(ValidationIssue {
+                check: ValidationCheck::CrossedQuote,
+                index: 1,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_report_display() {
+        let data = vec![quote(100_010, 100_000, 2, 2), quote(100_010, 100_000, 1, 1)];
+
+        let report = validate(&data, ValidationConfig::default());
+
+        assert_eq!(
+            report.to_string(),
+            "Validated 2 record(s), found 4 issue(s)\n  \
+             non-monotonic ts_init: 1\n  \
+             non-monotonic ts_event: 1\n  \
+             crossed quote: 2"
+        );
+    }
+}
@@ -13,11 +13,37 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_model::data::{
-    tick::{QuoteTick, TradeTick},
-    Data,
+use std::{any::Any, collections::HashMap, fs::File, path::Path, str::FromStr, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{Int64Array, StringArray, UInt64Array, UInt8Array},
+        datatypes::{DataType, Field, Schema, SchemaRef},
+        record_batch::RecordBatch,
+    },
+    parquet::{arrow::ArrowWriter, file::footer::parse_metadata},
+    physical_plan::{collect, ExecutionPlan},
+    prelude::{ParquetReadOptions, SessionContext},
+};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        tick::{QuoteTick, TradeTick},
+        Data,
+    },
+    identifiers::instrument_id::InstrumentId,
+};
+use nautilus_persistence::{
+    custom::{CustomData, QueryData},
+    error::PersistenceError,
+    parquet::{
+        get_column, DecodeCustomDataFromRecordBatch, DecodeDataFromRecordBatch, ParquetType,
+        ParquetWriter,
+    },
+    query::DataQuery,
+    session::{DataBackendSession, QueryResult},
+    validation::{ValidationCheck, ValidationConfig},
 };
-use nautilus_persistence::session::{DataBackendSession, QueryResult};
 
 // Note: "current_thread" configuration hangs up for some reason
 #[tokio::test(flavor = "multi_thread")]
@@ -30,7 +56,7 @@ async fn test_quote_ticks() {
         .await
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.flatten().collect();
+    let ticks: Vec<Data> = query_result.flat_map(Result::unwrap).collect();
 
     // NOTE: is_sorted_by_key is unstable otherwise use
     // ticks.is_sorted_by_key(|tick| tick.ts_init)
@@ -75,7 +101,7 @@ async fn test_data_ticks() {
         .await
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.flatten().collect();
+    let ticks: Vec<Data> = query_result.flat_map(Result::unwrap).collect();
 
     // NOTE: is_sorted_by_key is unstable otherwise use
     // ticks.is_sorted_by_key(|tick| tick.ts_init)
@@ -94,3 +120,522 @@ async fn test_data_ticks() {
     assert_eq!(ticks.len(), 9600);
     assert!(is_ascending_by_init(&ticks));
 }
+
+const QUOTE_TICK_DATA: &str = "../../tests/test_data/quote_tick_data.parquet";
+
+/// Returns the EUR/USD.SIM quote ticks of the test data, sorted by `ts_init`.
+async fn load_quote_ticks() -> Vec<Data> {
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_default_query::<QuoteTick>("quote_tick", QUOTE_TICK_DATA)
+        .await
+        .unwrap();
+    catalog
+        .get_query_result()
+        .flat_map(Result::unwrap)
+        .collect()
+}
+
+/// Writes the quote `ticks` to a parquet file at `path` with the metadata of the
+/// test data, in row groups of at most `row_group_size` ticks.
+fn write_quote_ticks(path: &Path, ticks: &[Data], row_group_size: usize) {
+    let metadata = HashMap::from([
+        ("instrument_id".to_string(), "EUR/USD.SIM".to_string()),
+        ("price_precision".to_string(), "5".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let mut writer = ParquetWriter::new(
+        File::create(path).unwrap(),
+        ParquetType::QuoteTick,
+        metadata,
+        None,
+    )
+    .unwrap();
+    for chunk in ticks.chunks(row_group_size) {
+        writer.write(chunk).unwrap();
+        writer.flush().unwrap();
+    }
+    writer.close().unwrap();
+}
+
+/// Returns the sum of the metric `name` over all nodes of the executed `plan`.
+fn sum_metric(plan: &Arc<dyn ExecutionPlan>, name: &str) -> usize {
+    let value = plan
+        .metrics()
+        .and_then(|metrics| metrics.sum_by_name(name))
+        .map_or(0, |value| value.as_usize());
+    value
+        + plan
+            .children()
+            .iter()
+            .map(|child| sum_metric(child, name))
+            .sum::<usize>()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quote_ticks_write_round_trip() {
+    let ticks = load_quote_ticks().await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let file_path = temp_dir.path().join("quote_tick_data.parquet");
+    write_quote_ticks(&file_path, &ticks, 1000);
+
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_default_query::<QuoteTick>("quote_tick", file_path.to_str().unwrap())
+        .await
+        .unwrap();
+    let round_trip: Vec<Data> = catalog
+        .get_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    assert_eq!(round_trip.len(), ticks.len());
+    for (expected, actual) in ticks.iter().zip(round_trip.iter()) {
+        match (expected, actual) {
+            (Data::Quote(expected), Data::Quote(actual)) => assert_eq!(expected, actual),
+            _ => panic!("expected quote ticks"),
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quote_ticks_with_time_range_query() {
+    let ticks = load_quote_ticks().await;
+    let start = ticks[1000].get_ts_init();
+    let end = ticks[2000].get_ts_init();
+
+    let query = DataQuery::new().start(start).end(end);
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_with_data_query::<QuoteTick>("quote_tick", QUOTE_TICK_DATA, &query)
+        .await
+        .unwrap();
+    let filtered: Vec<Data> = catalog
+        .get_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    let expected: Vec<u64> = ticks
+        .iter()
+        .map(Data::get_ts_init)
+        .filter(|ts_init| (start..=end).contains(ts_init))
+        .collect();
+    let actual: Vec<u64> = filtered.iter().map(Data::get_ts_init).collect();
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_time_range_query_skips_row_groups() {
+    let ticks = load_quote_ticks().await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let file_path = temp_dir.path().join("quote_tick_data.parquet");
+    write_quote_ticks(&file_path, &ticks, 1000);
+    let file_path = file_path.to_str().unwrap();
+    let footer = parse_metadata(&File::open(file_path).unwrap()).unwrap();
+    assert_eq!(footer.num_row_groups(), 10);
+
+    // The range covers the ticks of the second row group only
+    let query = DataQuery::new()
+        .start(ticks[1000].get_ts_init())
+        .end(ticks[1999].get_ts_init());
+    assert!(query.matches_row_groups(&footer));
+
+    let ctx = SessionContext::new();
+    ctx.register_parquet("quote_tick", file_path, ParquetReadOptions::default())
+        .await
+        .unwrap();
+    let plan = query
+        .apply(ctx.table("quote_tick").await.unwrap())
+        .unwrap()
+        .create_physical_plan()
+        .await
+        .unwrap();
+    let batches = collect(plan.clone(), ctx.task_ctx()).await.unwrap();
+    let rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+
+    // Adjacent row groups may share a boundary `ts_init`, so at most three are read
+    assert!(sum_metric(&plan, "row_groups_pruned") >= 7);
+    assert!(rows >= 1000);
+    assert!(rows < 3000);
+
+    // A file with no row group in the range is not registered, so its schema is
+    // never validated against the mismatched type
+    let query = DataQuery::new().start(ticks[ticks.len() - 1].get_ts_init() + 1);
+    assert!(!query.matches_row_groups(&footer));
+
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_with_data_query::<TradeTick>("trade_tick", file_path, &query)
+        .await
+        .unwrap();
+    let result = catalog
+        .add_file_with_data_query::<TradeTick>("trade_tick_2", file_path, &DataQuery::new())
+        .await;
+
+    assert_eq!(
+        catalog.get_query_result().flat_map(Result::unwrap).count(),
+        0
+    );
+    assert!(matches!(result, Err(PersistenceError::MissingColumn(_))));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quote_ticks_with_instrument_query() {
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_with_data_query::<QuoteTick>(
+            "quote_tick",
+            QUOTE_TICK_DATA,
+            &DataQuery::new().instrument_ids(vec![InstrumentId::from_str("EUR/USD.SIM").unwrap()]),
+        )
+        .await
+        .unwrap();
+    catalog
+        .add_file_with_data_query::<QuoteTick>(
+            "quote_tick_2",
+            QUOTE_TICK_DATA,
+            &DataQuery::new().instrument_ids(vec![InstrumentId::from_str("USD/JPY.SIM").unwrap()]),
+        )
+        .await
+        .unwrap();
+    let ticks: Vec<Data> = catalog
+        .get_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    assert_eq!(ticks.len(), 9500);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_catalog_query_across_files() {
+    let ticks = load_quote_ticks().await;
+
+    // Write the ticks as daily style files in the Python catalog layout
+    let temp_dir = tempfile::tempdir().unwrap();
+    let partition = temp_dir
+        .path()
+        .join("data/quote_tick.parquet/instrument_id=EUR-USD.SIM");
+    std::fs::create_dir_all(&partition).unwrap();
+    let chunks: Vec<&[Data]> = ticks.chunks(ticks.len() / 3 + 1).collect();
+    for chunk in &chunks {
+        let file_name = format!(
+            "{:019}-{:019}-0.parquet",
+            chunk[0].get_ts_init(),
+            chunk[chunk.len() - 1].get_ts_init()
+        );
+        write_quote_ticks(&partition.join(file_name), chunk, chunk.len());
+    }
+
+    let catalog_path = temp_dir.path().to_str().unwrap();
+    let mut catalog = DataBackendSession::new(1000);
+    let count = catalog
+        .add_catalog_query(
+            catalog_path,
+            Some(ParquetType::QuoteTick),
+            &DataQuery::new().instrument_ids(vec![InstrumentId::from_str("EUR/USD.SIM").unwrap()]),
+        )
+        .await
+        .unwrap();
+    let all: Vec<Data> = catalog
+        .get_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    assert_eq!(count, 3);
+    assert_eq!(all.len(), ticks.len());
+
+    // Only the last file overlaps the time range
+    let start = chunks[1][chunks[1].len() - 1].get_ts_init() + 1;
+    let mut catalog = DataBackendSession::new(1000);
+    let count = catalog
+        .add_catalog_query(catalog_path, None, &DataQuery::new().start(start))
+        .await
+        .unwrap();
+    let filtered: Vec<Data> = catalog
+        .get_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    assert_eq!(count, 1);
+    assert_eq!(
+        filtered.len(),
+        ticks
+            .iter()
+            .filter(|tick| tick.get_ts_init() >= start)
+            .count()
+    );
+}
+
+#[derive(Debug)]
+struct FundingRate {
+    rate_bps: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+impl CustomData for FundingRate {
+    fn type_name(&self) -> &'static str {
+        Self::TYPE_NAME
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl DecodeCustomDataFromRecordBatch for FundingRate {
+    const TYPE_NAME: &'static str = "funding_rate";
+
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, PersistenceError> {
+        let rate_bps = get_column::<UInt64Array>(&record_batch, "rate_bps", &DataType::UInt64)?;
+        let ts_event = get_column::<UInt64Array>(&record_batch, "ts_event", &DataType::UInt64)?;
+        let ts_init = get_column::<UInt64Array>(&record_batch, "ts_init", &DataType::UInt64)?;
+        Ok((0..record_batch.num_rows())
+            .map(|i| Self {
+                rate_bps: rate_bps.value(i),
+                ts_event: ts_event.value(i),
+                ts_init: ts_init.value(i),
+            })
+            .collect())
+    }
+
+    fn get_schema(metadata: HashMap<String, String>) -> SchemaRef {
+        let fields = vec![
+            Field::new("rate_bps", DataType::UInt64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+        Schema::new_with_metadata(fields, metadata).into()
+    }
+}
+
+/// Writes a funding rate for every 100th of the `ticks` to a parquet file at
+/// `path`, returning the number of rates written.
+fn write_funding_rates(path: &Path, ticks: &[Data]) -> usize {
+    let ts_init: Vec<u64> = ticks.iter().step_by(100).map(Data::get_ts_init).collect();
+    let count = ts_init.len();
+    let schema = FundingRate::get_schema(HashMap::new());
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(UInt64Array::from_iter_values(0..count as u64)),
+            Arc::new(UInt64Array::from(ts_init.clone())),
+            Arc::new(UInt64Array::from(ts_init)),
+        ],
+    )
+    .unwrap();
+    let mut writer = ArrowWriter::try_new(File::create(path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    count
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_custom_data_merged_with_quote_ticks() {
+    let ticks = load_quote_ticks().await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let funding_path = temp_dir.path().join("funding_rate.parquet");
+    let count = write_funding_rates(&funding_path, &ticks);
+
+    let mut catalog = DataBackendSession::new(1000);
+    catalog.register_custom_type::<FundingRate>();
+    catalog
+        .add_file_with_data_query::<QuoteTick>("quote_tick", QUOTE_TICK_DATA, &DataQuery::new())
+        .await
+        .unwrap();
+    catalog
+        .add_custom_file_with_data_query(
+            FundingRate::TYPE_NAME,
+            "funding_rate",
+            funding_path.to_str().unwrap(),
+            &DataQuery::new(),
+        )
+        .await
+        .unwrap();
+    let merged: Vec<QueryData> = catalog
+        .get_merged_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    let rates: Vec<&FundingRate> = merged
+        .iter()
+        .filter_map(|data| match data {
+            QueryData::Custom(custom) => custom.as_any().downcast_ref::<FundingRate>(),
+            QueryData::Data(_) => None,
+        })
+        .collect();
+    assert_eq!(merged.len(), ticks.len() + count);
+    assert_eq!(rates.len(), count);
+    assert_eq!(rates[1].rate_bps, 1);
+    assert!(merged
+        .windows(2)
+        .all(|pair| pair[0].get_ts_init() <= pair[1].get_ts_init()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_custom_data_excluded_from_query_result() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let funding_path = temp_dir.path().join("funding_rate.parquet");
+    write_funding_rates(&funding_path, &load_quote_ticks().await);
+
+    let mut catalog = DataBackendSession::new(1000);
+    catalog.register_custom_type::<FundingRate>();
+    catalog
+        .add_custom_file_with_data_query(
+            FundingRate::TYPE_NAME,
+            "funding_rate",
+            funding_path.to_str().unwrap(),
+            &DataQuery::new(),
+        )
+        .await
+        .unwrap();
+    let result = catalog.try_get_query_result();
+
+    assert!(matches!(
+        result,
+        Err(PersistenceError::UnmergedCustomData(1))
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_catalog_query_with_custom_data() {
+    let ticks = load_quote_ticks().await;
+
+    // Write the ticks and funding rates in the Python catalog layout
+    let temp_dir = tempfile::tempdir().unwrap();
+    let quotes_dir = temp_dir
+        .path()
+        .join("data/quote_tick.parquet/instrument_id=EUR-USD.SIM");
+    let funding_dir = temp_dir.path().join("data/funding_rate.parquet");
+    std::fs::create_dir_all(&quotes_dir).unwrap();
+    std::fs::create_dir_all(&funding_dir).unwrap();
+    write_quote_ticks(&quotes_dir.join("part-0.parquet"), &ticks, 1000);
+    let count = write_funding_rates(&funding_dir.join("part-0.parquet"), &ticks);
+    let catalog_path = temp_dir.path().to_str().unwrap();
+
+    let mut catalog = DataBackendSession::new(1000);
+    catalog.register_custom_type::<FundingRate>();
+    let files = catalog
+        .add_catalog_query(catalog_path, None, &DataQuery::new())
+        .await
+        .unwrap();
+    let merged: Vec<QueryData> = catalog
+        .get_merged_query_result()
+        .flat_map(Result::unwrap)
+        .collect();
+
+    assert_eq!(files, 2);
+    assert_eq!(merged.len(), ticks.len() + count);
+
+    // Without the registered type the funding rates are not discovered
+    let mut catalog = DataBackendSession::new(1000);
+    let files = catalog
+        .add_catalog_query(catalog_path, None, &DataQuery::new())
+        .await
+        .unwrap();
+
+    assert_eq!(files, 1);
+    assert_eq!(
+        catalog.get_query_result().flat_map(Result::unwrap).count(),
+        ticks.len()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_custom_data_not_registered() {
+    let mut catalog = DataBackendSession::new(1000);
+    let result = catalog
+        .add_custom_file_with_data_query(
+            "funding_rate",
+            "funding_rate",
+            QUOTE_TICK_DATA,
+            &DataQuery::new(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(PersistenceError::UnregisteredType(type_name)) if type_name == "funding_rate"
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_file_with_mismatched_schema() {
+    let mut catalog = DataBackendSession::new(1000);
+    let result = catalog
+        .add_file_default_query::<TradeTick>("trade_ticks", QUOTE_TICK_DATA)
+        .await;
+
+    assert!(matches!(result, Err(PersistenceError::MissingColumn(_))));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_invalid_value_returned_as_error() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let file_path = temp_dir.path().join("trade_ticks.parquet");
+    let metadata = HashMap::from([
+        ("instrument_id".to_string(), "AAPL.NASDAQ".to_string()),
+        ("price_precision".to_string(), "2".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let schema = TradeTick::get_schema(metadata);
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(vec![100, 101])),
+            Arc::new(UInt64Array::from(vec![1, 1])),
+            Arc::new(UInt8Array::from(vec![1, 9])), // 9 is not an aggressor side
+            Arc::new(StringArray::from(vec!["1", "2"])),
+            Arc::new(UInt64Array::from(vec![1, 2])),
+            Arc::new(UInt64Array::from(vec![1, 2])),
+        ],
+    )
+    .unwrap();
+    let mut writer = ArrowWriter::try_new(File::create(&file_path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_default_query::<TradeTick>("trade_ticks", file_path.to_str().unwrap())
+        .await
+        .unwrap();
+    let mut query_result = catalog.get_query_result();
+
+    assert!(matches!(
+        query_result.next(),
+        Some(Err(PersistenceError::InvalidValue { column, .. })) if column == "aggressor_side"
+    ));
+    assert!(query_result.next().is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_quote_ticks_validated() {
+    let mut catalog = DataBackendSession::new(1000);
+    catalog
+        .add_file_default_query::<QuoteTick>("quotes_0005", QUOTE_TICK_DATA)
+        .await
+        .unwrap();
+    let report = catalog
+        .get_query_result()
+        .validated(ValidationConfig::default())
+        .into_report()
+        .unwrap();
+
+    assert_eq!(report.records, 9500);
+    assert_eq!(report.count(ValidationCheck::NonMonotonicTsInit), 0);
+}
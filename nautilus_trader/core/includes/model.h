@@ -317,6 +317,29 @@ typedef enum OptionKind {
     PUT = 2,
 } OptionKind;
 
+/**
+ * The error code returned by the [`OrderBook`] C API operations which can fail,
+ * so that a malformed update is reported to the caller rather than aborting.
+ */
+typedef enum OrderBookErrorCode {
+    /**
+     * The operation succeeded.
+     */
+    OK = 0,
+    /**
+     * The operation is not valid for the book type.
+     */
+    INVALID_BOOK_OPERATION = 1,
+    /**
+     * The operation would break (or found broken) the integrity of the book.
+     */
+    BOOK_INTEGRITY_ERROR = 2,
+    /**
+     * The delta was out of sequence.
+     */
+    SEQUENCE_ERROR = 3,
+} OrderBookErrorCode;
+
 /**
  * The order side for a specific order, or action related to orders.
  */
@@ -1661,6 +1684,12 @@ void synthetic_instrument_change_formula(struct SyntheticInstrument_API *synth,
 struct Price_t synthetic_instrument_calculate(struct SyntheticInstrument_API *synth,
                                               const CVec *inputs_ptr);
 
+/**
+ * Returns the message of the last error returned by an [`OrderBook`] C API
+ * operation on the calling thread (empty if none), as a C string pointer.
+ */
+const char *orderbook_last_error(void);
+
 struct OrderBook_API orderbook_new(struct InstrumentId_t instrument_id, enum BookType book_type);
 
 void orderbook_drop(struct OrderBook_API book);
@@ -1677,20 +1706,20 @@ uint64_t orderbook_ts_last(const struct OrderBook_API *book);
 
 uint64_t orderbook_count(const struct OrderBook_API *book);
 
-void orderbook_add(struct OrderBook_API *book,
-                   struct BookOrder_t order,
-                   uint64_t ts_event,
-                   uint64_t sequence);
+enum OrderBookErrorCode orderbook_add(struct OrderBook_API *book,
+                                      struct BookOrder_t order,
+                                      uint64_t ts_event,
+                                      uint64_t sequence);
 
-void orderbook_update(struct OrderBook_API *book,
-                      struct BookOrder_t order,
-                      uint64_t ts_event,
-                      uint64_t sequence);
+enum OrderBookErrorCode orderbook_update(struct OrderBook_API *book,
+                                         struct BookOrder_t order,
+                                         uint64_t ts_event,
+                                         uint64_t sequence);
 
-void orderbook_delete(struct OrderBook_API *book,
-                      struct BookOrder_t order,
-                      uint64_t ts_event,
-                      uint64_t sequence);
+enum OrderBookErrorCode orderbook_delete(struct OrderBook_API *book,
+                                         struct BookOrder_t order,
+                                         uint64_t ts_event,
+                                         uint64_t sequence);
 
 void orderbook_clear(struct OrderBook_API *book, uint64_t ts_event, uint64_t sequence);
 
@@ -1698,7 +1727,8 @@ void orderbook_clear_bids(struct OrderBook_API *book, uint64_t ts_event, uint64_
 
 void orderbook_clear_asks(struct OrderBook_API *book, uint64_t ts_event, uint64_t sequence);
 
-void orderbook_apply_delta(struct OrderBook_API *book, struct OrderBookDelta_t delta);
+enum OrderBookErrorCode orderbook_apply_delta(struct OrderBook_API *book,
+                                              struct OrderBookDelta_t delta);
 
 uint8_t orderbook_has_bid(struct OrderBook_API *book);
 
@@ -1716,13 +1746,41 @@ double orderbook_spread(struct OrderBook_API *book);
 
 double orderbook_midpoint(struct OrderBook_API *book);
 
-void orderbook_update_quote_tick(struct OrderBook_API *book, const struct QuoteTick_t *tick);
+/**
+ * Returns the order book imbalance of the top `num_levels` levels per side, or NaN
+ * if the book has no orders.
+ */
+double orderbook_imbalance(const struct OrderBook_API *book, uintptr_t num_levels);
+
+/**
+ * Returns the top of book prices weighted by the opposite side sizes, or NaN if
+ * the book has no bid or no ask.
+ */
+double orderbook_microprice(const struct OrderBook_API *book);
+
+/**
+ * Returns the volume weighted average prices of the top `num_levels` levels per
+ * side weighted by the opposite side volumes, or NaN if the book has no bid or no ask.
+ */
+double orderbook_weighted_mid(const struct OrderBook_API *book, uintptr_t num_levels);
+
+/**
+ * Returns the volume weighted average price to fill a market order of `size` on the
+ * given `side`, or NaN if the book cannot fill the entire size.
+ */
+double orderbook_vwap_to_fill(const struct OrderBook_API *book,
+                              enum OrderSide side,
+                              struct Quantity_t size);
+
+enum OrderBookErrorCode orderbook_update_quote_tick(struct OrderBook_API *book,
+                                                    const struct QuoteTick_t *tick);
 
-void orderbook_update_trade_tick(struct OrderBook_API *book, const struct TradeTick_t *tick);
+enum OrderBookErrorCode orderbook_update_trade_tick(struct OrderBook_API *book,
+                                                    const struct TradeTick_t *tick);
 
 CVec orderbook_simulate_fills(const struct OrderBook_API *book, struct BookOrder_t order);
 
-void orderbook_check_integrity(const struct OrderBook_API *book);
+enum OrderBookErrorCode orderbook_check_integrity(const struct OrderBook_API *book);
 
 void vec_fills_drop(CVec v);
 
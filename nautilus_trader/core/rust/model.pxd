@@ -171,6 +171,18 @@ cdef extern from "../includes/model.h":
         # A Put option gives the holder the right, but not the obligation, to sell an underlying asset at a specified strike price within a specified period of time.
         PUT # = 2,
 
+    # The error code returned by the [`OrderBook`] C API operations which can fail,
+    # so that a malformed update is reported to the caller rather than aborting.
+    cpdef enum OrderBookErrorCode:
+        # The operation succeeded.
+        OK # = 0,
+        # The operation is not valid for the book type.
+        INVALID_BOOK_OPERATION # = 1,
+        # The operation would break (or found broken) the integrity of the book.
+        BOOK_INTEGRITY_ERROR # = 2,
+        # The delta was out of sequence.
+        SEQUENCE_ERROR # = 3,
+
     # The order side for a specific order, or action related to orders.
     cpdef enum OrderSide:
         # No order side is specified (only valid in the context of a filter for actions involving orders).
@@ -1163,6 +1175,10 @@ cdef extern from "../includes/model.h":
 
     Price_t synthetic_instrument_calculate(SyntheticInstrument_API *synth, const CVec *inputs_ptr);
 
+    # Returns the message of the last error returned by an [`OrderBook`] C API
+    # operation on the calling thread (empty if none), as a C string pointer.
+    const char *orderbook_last_error();
+
     OrderBook_API orderbook_new(InstrumentId_t instrument_id, BookType book_type);
 
     void orderbook_drop(OrderBook_API book);
@@ -1179,20 +1195,20 @@ cdef extern from "../includes/model.h":
 
     uint64_t orderbook_count(const OrderBook_API *book);
 
-    void orderbook_add(OrderBook_API *book,
-                       BookOrder_t order,
-                       uint64_t ts_event,
-                       uint64_t sequence);
+    OrderBookErrorCode orderbook_add(OrderBook_API *book,
+                                     BookOrder_t order,
+                                     uint64_t ts_event,
+                                     uint64_t sequence);
 
-    void orderbook_update(OrderBook_API *book,
-                          BookOrder_t order,
-                          uint64_t ts_event,
-                          uint64_t sequence);
+    OrderBookErrorCode orderbook_update(OrderBook_API *book,
+                                        BookOrder_t order,
+                                        uint64_t ts_event,
+                                        uint64_t sequence);
 
-    void orderbook_delete(OrderBook_API *book,
-                          BookOrder_t order,
-                          uint64_t ts_event,
-                          uint64_t sequence);
+    OrderBookErrorCode orderbook_delete(OrderBook_API *book,
+                                        BookOrder_t order,
+                                        uint64_t ts_event,
+                                        uint64_t sequence);
 
     void orderbook_clear(OrderBook_API *book, uint64_t ts_event, uint64_t sequence);
 
@@ -1200,7 +1216,7 @@ cdef extern from "../includes/model.h":
 
     void orderbook_clear_asks(OrderBook_API *book, uint64_t ts_event, uint64_t sequence);
 
-    void orderbook_apply_delta(OrderBook_API *book, OrderBookDelta_t delta);
+    OrderBookErrorCode orderbook_apply_delta(OrderBook_API *book, OrderBookDelta_t delta);
 
     uint8_t orderbook_has_bid(OrderBook_API *book);
 
@@ -1218,13 +1234,29 @@ cdef extern from "../includes/model.h":
 
     double orderbook_midpoint(OrderBook_API *book);
 
-    void orderbook_update_quote_tick(OrderBook_API *book, const QuoteTick_t *tick);
+    # Returns the order book imbalance of the top `num_levels` levels per side, or NaN
+    # if the book has no orders.
+    double orderbook_imbalance(const OrderBook_API *book, uintptr_t num_levels);
+
+    # Returns the top of book prices weighted by the opposite side sizes, or NaN if
+    # the book has no bid or no ask.
+    double orderbook_microprice(const OrderBook_API *book);
+
+    # Returns the volume weighted average prices of the top `num_levels` levels per
+    # side weighted by the opposite side volumes, or NaN if the book has no bid or no ask.
+    double orderbook_weighted_mid(const OrderBook_API *book, uintptr_t num_levels);
+
+    # Returns the volume weighted average price to fill a market order of `size` on the
+    # given `side`, or NaN if the book cannot fill the entire size.
+    double orderbook_vwap_to_fill(const OrderBook_API *book, OrderSide side, Quantity_t size);
+
+    OrderBookErrorCode orderbook_update_quote_tick(OrderBook_API *book, const QuoteTick_t *tick);
 
-    void orderbook_update_trade_tick(OrderBook_API *book, const TradeTick_t *tick);
+    OrderBookErrorCode orderbook_update_trade_tick(OrderBook_API *book, const TradeTick_t *tick);
 
     CVec orderbook_simulate_fills(const OrderBook_API *book, BookOrder_t order);
 
-    void orderbook_check_integrity(const OrderBook_API *book);
+    OrderBookErrorCode orderbook_check_integrity(const OrderBook_API *book);
 
     void vec_fills_drop(CVec v);
 
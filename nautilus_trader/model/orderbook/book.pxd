@@ -17,6 +17,7 @@ from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.rust.model cimport OrderBookErrorCode
 from nautilus_trader.core.rust.model cimport OrderBook_API
 from nautilus_trader.model.data.book cimport BookOrder
 from nautilus_trader.model.data.book cimport OrderBookDelta
@@ -24,7 +25,9 @@ from nautilus_trader.model.data.book cimport OrderBookDeltas
 from nautilus_trader.model.data.tick cimport QuoteTick
 from nautilus_trader.model.data.tick cimport TradeTick
 from nautilus_trader.model.enums_c cimport BookType
+from nautilus_trader.model.enums_c cimport OrderSide
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 
 
@@ -49,7 +52,13 @@ cdef class OrderBook(Data):
     cpdef best_ask_size(self)
     cpdef spread(self)
     cpdef midpoint(self)
+    cpdef imbalance(self, int num_levels=*)
+    cpdef microprice(self)
+    cpdef weighted_mid(self, int num_levels=*)
+    cpdef vwap_to_fill(self, OrderSide side, Quantity size)
     cpdef list simulate_fills(self, Order order, uint8_t price_prec, bint is_aggressive)
     cpdef void update_quote_tick(self, QuoteTick tick)
     cpdef void update_trade_tick(self, TradeTick tick)
     cpdef str pprint(self, int num_levels=*)
+
+    cdef void _raise_on_error(self, OrderBookErrorCode code, str operation)
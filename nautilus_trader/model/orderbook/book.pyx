@@ -18,7 +18,9 @@ from operator import itemgetter
 import pandas as pd
 
 from nautilus_trader.model.orderbook.error import BookIntegrityError
+from nautilus_trader.model.orderbook.error import InvalidBookOperation
 
+from libc.math cimport isnan
 from libc.stdint cimport INT64_MAX
 from libc.stdint cimport INT64_MIN
 from libc.stdint cimport int64_t
@@ -29,6 +31,7 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.model cimport BookOrder_t
+from nautilus_trader.core.rust.model cimport OrderBookErrorCode
 from nautilus_trader.core.rust.model cimport Price_t
 from nautilus_trader.core.rust.model cimport Quantity_t
 from nautilus_trader.core.rust.model cimport book_order_from_raw
@@ -50,7 +53,10 @@ from nautilus_trader.core.rust.model cimport orderbook_delta_clone
 from nautilus_trader.core.rust.model cimport orderbook_drop
 from nautilus_trader.core.rust.model cimport orderbook_has_ask
 from nautilus_trader.core.rust.model cimport orderbook_has_bid
+from nautilus_trader.core.rust.model cimport orderbook_imbalance
 from nautilus_trader.core.rust.model cimport orderbook_instrument_id
+from nautilus_trader.core.rust.model cimport orderbook_last_error
+from nautilus_trader.core.rust.model cimport orderbook_microprice
 from nautilus_trader.core.rust.model cimport orderbook_midpoint
 from nautilus_trader.core.rust.model cimport orderbook_new
 from nautilus_trader.core.rust.model cimport orderbook_pprint_to_cstr
@@ -62,6 +68,8 @@ from nautilus_trader.core.rust.model cimport orderbook_ts_last
 from nautilus_trader.core.rust.model cimport orderbook_update
 from nautilus_trader.core.rust.model cimport orderbook_update_quote_tick
 from nautilus_trader.core.rust.model cimport orderbook_update_trade_tick
+from nautilus_trader.core.rust.model cimport orderbook_vwap_to_fill
+from nautilus_trader.core.rust.model cimport orderbook_weighted_mid
 from nautilus_trader.core.rust.model cimport vec_fills_drop
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.model.data.book cimport BookOrder
@@ -208,10 +216,20 @@ cdef class OrderBook(Data):
         sequence : uint64, default 0
             The unique sequence number for the update. If default 0 then will increment the `sequence`.
 
+        Raises
+        ------
+        InvalidBookOperation
+            If the book type is ``L1_TBBO``.
+        BookIntegrityError
+            If `order.side` is ``NO_ORDER_SIDE``.
+
         """
         Condition.not_none(order, "order")
 
-        orderbook_add(&self._mem, order._mem, ts_event, sequence)
+        self._raise_on_error(
+            orderbook_add(&self._mem, order._mem, ts_event, sequence),
+            "add",
+        )
 
     cpdef void update(self, BookOrder order, uint64_t ts_event, uint64_t sequence=0):
         """
@@ -224,10 +242,18 @@ cdef class OrderBook(Data):
         sequence : uint64, default 0
             The unique sequence number for the update. If default 0 then will increment the `sequence`.
 
+        Raises
+        ------
+        BookIntegrityError
+            If `order.side` is ``NO_ORDER_SIDE``.
+
         """
         Condition.not_none(order, "order")
 
-        orderbook_update(&self._mem, order._mem, ts_event, sequence)
+        self._raise_on_error(
+            orderbook_update(&self._mem, order._mem, ts_event, sequence),
+            "update",
+        )
 
     cpdef void delete(self, BookOrder order, uint64_t ts_event, uint64_t sequence=0):
         """
@@ -240,10 +266,18 @@ cdef class OrderBook(Data):
         sequence : uint64, default 0
             The unique sequence number for the update. If default 0 then will increment the `sequence`.
 
+        Raises
+        ------
+        BookIntegrityError
+            If `order.side` is ``NO_ORDER_SIDE``.
+
         """
         Condition.not_none(order, "order")
 
-        orderbook_delete(&self._mem, order._mem, ts_event, sequence)
+        self._raise_on_error(
+            orderbook_delete(&self._mem, order._mem, ts_event, sequence),
+            "delete",
+        )
 
     cpdef void clear(self, uint64_t ts_event, uint64_t sequence=0):
         """
@@ -276,12 +310,19 @@ cdef class OrderBook(Data):
         ------
         ValueError
             If `delta.book_type` is not equal to `self.type`.
+        InvalidBookOperation
+            If the delta is not valid for the book type.
+        BookIntegrityError
+            If `delta.order.side` is ``NO_ORDER_SIDE``.
 
         """
         Condition.not_none(delta, "delta")
 
         # We have to clone the delta because of the heap allocation `instrument_id`
-        orderbook_apply_delta(&self._mem, orderbook_delta_clone(&delta._mem))
+        self._raise_on_error(
+            orderbook_apply_delta(&self._mem, orderbook_delta_clone(&delta._mem)),
+            "apply delta",
+        )
 
     cpdef void apply_deltas(self, OrderBookDeltas deltas):
         """
@@ -320,8 +361,6 @@ cdef class OrderBook(Data):
         """
         Check book integrity.
 
-        For now will panic from Rust and print the error message to stdout.
-
         For all order books:
         - The bid side price should not be greater than the ask side price.
 
@@ -331,7 +370,25 @@ cdef class OrderBook(Data):
             If any check fails.
 
         """
-        orderbook_check_integrity(&self._mem)
+        self._raise_on_error(orderbook_check_integrity(&self._mem), "check integrity")
+
+    cdef void _raise_on_error(self, OrderBookErrorCode code, str operation):
+        if code == OrderBookErrorCode.OK:
+            return
+
+        cdef str message = cstr_to_pystr(orderbook_last_error())
+        if code == OrderBookErrorCode.INVALID_BOOK_OPERATION:
+            raise InvalidBookOperation(
+                f"Cannot {operation} for {book_type_to_str(self.book_type)} book: {message}",
+            )
+        elif code == OrderBookErrorCode.BOOK_INTEGRITY_ERROR:
+            raise BookIntegrityError(
+                f"Failed to {operation} for {self.instrument_id} order book: {message}",
+            )
+        else:
+            raise RuntimeError(  # pragma: no cover (design time error)
+                f"invalid `OrderBookErrorCode`, was {code}",  # pragma: no cover (design time error)
+            )
 
     cpdef best_bid_price(self):
         """
@@ -417,6 +474,103 @@ cdef class OrderBook(Data):
 
         return orderbook_midpoint(&self._mem)
 
+    cpdef imbalance(self, int num_levels=1):
+        """
+        Return the volume imbalance of the top levels, in the range [-1, 1]
+        (if no market exists then returns ``None``).
+
+        A positive value indicates more bid volume than ask volume.
+
+        Parameters
+        ----------
+        num_levels : int, default 1
+            The number of levels per side to include.
+
+        Returns
+        -------
+        double or ``None``
+
+        """
+        if not orderbook_has_bid(&self._mem) or not orderbook_has_ask(&self._mem):
+            return None
+
+        # The Rust function returns NaN if the book has no orders
+        cdef double imbalance = orderbook_imbalance(&self._mem, num_levels)
+        if isnan(imbalance):
+            return None
+
+        return imbalance
+
+    cpdef microprice(self):
+        """
+        Return the top of book prices weighted by the opposite side sizes
+        (if no market exists then returns ``None``).
+
+        Returns
+        -------
+        double or ``None``
+
+        """
+        # The Rust function returns NaN if the book has no bid or no ask
+        cdef double microprice = orderbook_microprice(&self._mem)
+        if isnan(microprice):
+            return None
+
+        return microprice
+
+    cpdef weighted_mid(self, int num_levels=1):
+        """
+        Return the volume weighted average prices of the top levels of each side,
+        weighted by the opposite side volumes (if no market exists then returns ``None``).
+
+        Parameters
+        ----------
+        num_levels : int, default 1
+            The number of levels per side to include.
+
+        Returns
+        -------
+        double or ``None``
+
+        """
+        # The Rust function returns NaN if the book has no bid or no ask
+        cdef double weighted_mid = orderbook_weighted_mid(&self._mem, num_levels)
+        if isnan(weighted_mid):
+            return None
+
+        return weighted_mid
+
+    cpdef vwap_to_fill(self, OrderSide side, Quantity size):
+        """
+        Return the volume weighted average price to fill a market order of the
+        given size (if the book cannot fill the entire size then returns ``None``).
+
+        Parameters
+        ----------
+        side : OrderSide {``BUY``, ``SELL``}
+            The side of the market order.
+        size : Quantity
+            The size of the market order.
+
+        Returns
+        -------
+        double or ``None``
+
+        Raises
+        ------
+        ValueError
+            If `side` is ``NO_ORDER_SIDE``.
+
+        """
+        Condition.not_equal(side, OrderSide.NO_ORDER_SIDE, "side", "NO_ORDER_SIDE")
+        Condition.not_none(size, "size")
+
+        cdef double vwap = orderbook_vwap_to_fill(&self._mem, side, size._mem)
+        if isnan(vwap):
+            return None
+
+        return vwap
+
     cpdef list simulate_fills(self, Order order, uint8_t price_prec, bint is_aggressive):
         """
         Simulate filling the book with the given order.
@@ -474,8 +628,16 @@ cdef class OrderBook(Data):
         tick : QuoteTick
             The quote tick to update with.
 
+        Raises
+        ------
+        InvalidBookOperation
+            If a tick price is not a multiple of the book price increment.
+
         """
-        orderbook_update_quote_tick(&self._mem, &tick._mem)
+        self._raise_on_error(
+            orderbook_update_quote_tick(&self._mem, &tick._mem),
+            "update quote tick",
+        )
 
     cpdef void update_trade_tick(self, TradeTick tick):
         """
@@ -486,8 +648,16 @@ cdef class OrderBook(Data):
         tick : TradeTick
             The trade tick to update with.
 
+        Raises
+        ------
+        InvalidBookOperation
+            If a tick price is not a multiple of the book price increment.
+
         """
-        orderbook_update_trade_tick(&self._mem, &tick._mem)
+        self._raise_on_error(
+            orderbook_update_trade_tick(&self._mem, &tick._mem),
+            "update trade tick",
+        )
 
     cpdef str pprint(self, int num_levels=3):
         """
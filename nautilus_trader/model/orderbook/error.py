@@ -18,3 +18,9 @@ class BookIntegrityError(Exception):
     """
     Represents an error condition where the order books integrity has been lost.
     """
+
+
+class InvalidBookOperation(Exception):
+    """
+    Represents an error condition where an operation is not valid for the order book type.
+    """
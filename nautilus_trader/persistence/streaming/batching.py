@@ -81,6 +81,8 @@ def _generate_batches_rust(
     files: list[str],
     cls: type,
     batch_size: int = 10_000,
+    start_nanos: Optional[int] = None,
+    end_nanos: Optional[int] = None,
 ) -> Generator[list[Union[QuoteTick, TradeTick]], None, None]:
     files = sorted(files, key=lambda x: Path(x).stem)
 
@@ -89,10 +91,12 @@ def _generate_batches_rust(
     session = DataBackendSession(chunk_size=batch_size)
 
     for file in files:
-        session.add_file(
+        session.add_file_filtered(
             "data",
             file,
             py_type_to_parquet_type(cls),
+            start=start_nanos,
+            end=end_nanos,
         )
 
     result = session.to_query_result()
@@ -108,7 +112,13 @@ def generate_batches_rust(
     start_nanos: Optional[int] = None,
     end_nanos: Optional[int] = None,
 ) -> Generator[list[Data], None, None]:
-    batches = _generate_batches_rust(files=files, cls=cls, batch_size=batch_size)
+    batches = _generate_batches_rust(
+        files=files,
+        cls=cls,
+        batch_size=batch_size,
+        start_nanos=start_nanos,
+        end_nanos=end_nanos,
+    )
     yield from _generate_batches_within_time_range(batches, start_nanos, end_nanos)
 
 
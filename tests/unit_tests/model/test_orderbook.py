@@ -26,6 +26,8 @@ from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orderbook import OrderBook
 from nautilus_trader.model.orderbook import OrderBookDelta
 from nautilus_trader.model.orderbook import OrderBookDeltas
+from nautilus_trader.model.orderbook.error import BookIntegrityError
+from nautilus_trader.model.orderbook.error import InvalidBookOperation
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.data import TestDataStubs
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
@@ -160,6 +162,57 @@ class TestOrderBook:
         assert book.spread() == 1.0
         assert book.midpoint() == 10.5
 
+    def test_analytics_when_no_market_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.empty_book.imbalance() is None
+        assert self.empty_book.microprice() is None
+        assert self.empty_book.weighted_mid() is None
+        assert self.empty_book.vwap_to_fill(OrderSide.BUY, Quantity.from_int(1)) is None
+
+    def test_analytics_when_one_sided_returns_none(self):
+        # Arrange
+        self.empty_book.add(
+            BookOrder(
+                price=Price(10.0, 1),
+                size=Quantity(5.0, 0),
+                side=OrderSide.BUY,
+                order_id=0,
+            ),
+            0,
+            0,
+        )
+
+        # Act, Assert
+        assert self.empty_book.imbalance() is None
+        assert self.empty_book.microprice() is None
+        assert self.empty_book.weighted_mid() is None
+
+    def test_imbalance(self):
+        # Arrange, Act, Assert
+        assert self.sample_book.imbalance() == pytest.approx((4.0 - 5.0) / 9.0)
+        assert self.sample_book.imbalance(2) == pytest.approx(-0.5)
+
+    def test_microprice_and_weighted_mid(self):
+        # Arrange
+        expected = (0.83000 * 5.0 + 0.88600 * 4.0) / 9.0
+
+        # Act, Assert
+        assert self.sample_book.microprice() == pytest.approx(expected)
+        assert self.sample_book.weighted_mid(1) == pytest.approx(expected)
+
+    def test_vwap_to_fill(self):
+        # Arrange, Act, Assert
+        assert self.sample_book.vwap_to_fill(
+            OrderSide.BUY,
+            Quantity.from_int(10),
+        ) == pytest.approx(0.88650)
+        assert self.sample_book.vwap_to_fill(OrderSide.SELL, Quantity.from_int(6)) is None
+
+    def test_vwap_to_fill_with_no_order_side_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.sample_book.vwap_to_fill(OrderSide.NO_ORDER_SIDE, Quantity.from_int(1))
+
     def test_repr(self):
         book = OrderBook(
             instrument_id=self.instrument.id,
@@ -233,6 +286,41 @@ class TestOrderBook:
         book.update(order, 0)
         book.delete(order, 0)
 
+    def test_add_l1_raises_invalid_book_operation(self):
+        book = OrderBook(
+            instrument_id=self.instrument.id,
+            book_type=BookType.L1_TBBO,
+        )
+        order = TestDataStubs.order(price=10.0, side=OrderSide.BUY)
+
+        with pytest.raises(InvalidBookOperation, match="cannot add for L1_TBBO book"):
+            book.add(order, 0)
+
+        assert book.count == 0
+
+    def test_apply_delta_with_no_order_side_raises_book_integrity_error(self):
+        delta = OrderBookDelta(
+            instrument_id=TestIdStubs.audusd_id(),
+            action=BookAction.ADD,
+            order=BookOrder(
+                OrderSide.NO_ORDER_SIDE,
+                Price(0.5814, 4),
+                Quantity(672.45, 2),
+                0,
+            ),
+            sequence=1,
+            ts_event=0,
+            ts_init=0,
+        )
+
+        with pytest.raises(BookIntegrityError, match="invalid `NoOrderSide` in book"):
+            self.empty_book.apply_delta(delta)
+
+        assert self.empty_book.count == 0
+
+    def test_check_integrity_empty(self):
+        self.empty_book.check_integrity()
+
     def test_top(self):
         self.empty_book.add(
             BookOrder(
@@ -292,9 +380,6 @@ class TestOrderBook:
         assert self.empty_book.best_ask_price() == 21.0
 
     # TODO: TBD
-    # def test_check_integrity_empty(self):
-    #     self.empty_book.check_integrity()
-
     # def test_check_integrity_shallow(self):
     #     self.empty_book.add(BookOrder(price=10.0, size=5.0, side=OrderSide.SELL))
     #     self.empty_book.check_integrity()
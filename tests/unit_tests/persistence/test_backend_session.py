@@ -0,0 +1,99 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import os
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+from nautilus_trader.core.nautilus_pyo3.persistence import DataBackendSession
+from nautilus_trader.core.nautilus_pyo3.persistence import ParquetType
+from nautilus_trader.persistence.wranglers import list_from_capsule
+from tests import TEST_DATA_DIR
+
+
+QUOTE_TICK_FILE = os.path.join(TEST_DATA_DIR, "quote_tick_eurusd_2019_sim_rust.parquet")
+
+
+class TestDataBackendSession:
+    def test_add_custom_file_with_unregistered_type_raises_value_error(self):
+        # Arrange
+        session = DataBackendSession()
+
+        # Act, Assert
+        with pytest.raises(ValueError, match="funding_rate"):
+            session.add_custom_file("funding_rate", "funding_rate", QUOTE_TICK_FILE)
+
+    def test_merged_query_result_returns_built_in_data(self):
+        # Arrange
+        session = DataBackendSession(chunk_size=1000)
+        session.add_file("quote_tick", QUOTE_TICK_FILE, ParquetType.QuoteTick)
+        expected = [
+            tick for chunk in session.to_query_result() for tick in list_from_capsule(chunk)
+        ]
+
+        session = DataBackendSession(chunk_size=1000)
+        session.add_file("quote_tick", QUOTE_TICK_FILE, ParquetType.QuoteTick)
+
+        # Act
+        ticks = []
+        custom = []
+        for data_chunk, custom_chunk in session.to_merged_query_result():
+            ticks.extend(list_from_capsule(data_chunk))
+            custom.extend(custom_chunk)
+
+        # Assert
+        assert ticks == expected
+        assert custom == []
+
+    def test_query_result_with_invalid_value_raises_value_error(self, tmp_path):
+        # Arrange
+        schema = pa.schema(
+            [
+                pa.field("price", pa.int64(), nullable=False),
+                pa.field("size", pa.uint64(), nullable=False),
+                pa.field("aggressor_side", pa.uint8(), nullable=False),
+                pa.field("trade_id", pa.string(), nullable=False),
+                pa.field("ts_event", pa.uint64(), nullable=False),
+                pa.field("ts_init", pa.uint64(), nullable=False),
+            ],
+            metadata={
+                "instrument_id": "AAPL.NASDAQ",
+                "price_precision": "2",
+                "size_precision": "0",
+            },
+        )
+        table = pa.Table.from_pydict(
+            {
+                "price": [100, 101],
+                "size": [1, 1],
+                "aggressor_side": [1, 9],  # 9 is not an aggressor side
+                "trade_id": ["1", "2"],
+                "ts_event": [1, 2],
+                "ts_init": [1, 2],
+            },
+            schema=schema,
+        )
+        file_path = str(tmp_path / "trade_tick.parquet")
+        pq.write_table(table, file_path)
+
+        session = DataBackendSession()
+        session.add_file("trade_tick", file_path, ParquetType.TradeTick)
+        result = session.to_query_result()
+
+        # Act, Assert
+        with pytest.raises(ValueError, match="aggressor_side"):
+            next(result)